@@ -0,0 +1,158 @@
+use crate::circuit::{QuantumCircuit, QuantumGate};
+
+const CONTROL: &str = "●";
+const SWAP: &str = "×";
+const CROSSING: &str = "┼";
+const WIRE: char = '─';
+const VERTICAL: &str = "│";
+
+/// A gate placed in a column: its lowest and highest qubits and the symbols on its qubits.
+type Placement = (usize, usize, Vec<(usize, String)>);
+
+/// Returns the symbol drawn on each qubit the gate acts on.
+fn symbols(gate: &QuantumGate) -> Vec<(usize, String)> {
+    let control = |q: usize| (q, CONTROL.to_string());
+    match *gate {
+        QuantumGate::Phase(q, k) => vec![(q, format!("P({})", k))],
+        QuantumGate::RZ(q, k) | QuantumGate::RX(q, k) | QuantumGate::RY(q, k) => {
+            vec![(q, format!("{}({})", gate.name(), k))]
+        }
+        QuantumGate::Clifford1(q, idx) => vec![(q, format!("C1({})", idx))],
+        QuantumGate::CX(c, t) => vec![control(c), (t, "X".to_string())],
+        QuantumGate::CZ(a, b) => vec![control(a), control(b)],
+        QuantumGate::CS(c, t) => vec![control(c), (t, "S".to_string())],
+        QuantumGate::CSdg(c, t) => vec![control(c), (t, "Sdg".to_string())],
+        QuantumGate::Swap(a, b) => vec![(a, SWAP.to_string()), (b, SWAP.to_string())],
+        QuantumGate::ISwap(a, b) | QuantumGate::ISwapdg(a, b) => {
+            vec![(a, gate.name().to_string()), (b, gate.name().to_string())]
+        }
+        QuantumGate::CCX(c1, c2, t) => vec![control(c1), control(c2), (t, "X".to_string())],
+        QuantumGate::CCZ(a, b, c) => vec![control(a), control(b), control(c)],
+        QuantumGate::CSwap(c, a, b) => {
+            vec![control(c), (a, SWAP.to_string()), (b, SWAP.to_string())]
+        }
+        _ => gate
+            .qubits()
+            .into_iter()
+            .map(|q| (q, gate.name().to_string()))
+            .collect(),
+    }
+}
+
+/// Pads `label` with `fill` on both sides to `width` characters, centering it.
+fn centered(label: &str, width: usize, fill: char) -> String {
+    let padding = width - label.chars().count();
+    let left = padding / 2;
+    let mut s: String = std::iter::repeat_n(fill, left).collect();
+    s.push_str(label);
+    s.extend(std::iter::repeat_n(fill, padding - left));
+    s
+}
+
+/// Renders the circuit as a text diagram, see [`QuantumCircuit::draw`].
+pub(crate) fn draw(circuit: &QuantumCircuit) -> String {
+    let num_qubits = circuit
+        .gates
+        .iter()
+        .flat_map(|gate| gate.qubits())
+        .map(|q| q + 1)
+        .fold(circuit.num_qubits, usize::max);
+
+    // Place each gate in the earliest column after the gates on the wires it spans, including
+    // the wires crossed by its vertical line.
+    let mut next_column = vec![0; num_qubits];
+    let mut columns: Vec<Vec<Placement>> = Vec::new();
+    for gate in &circuit.gates {
+        let qubits = gate.qubits();
+        let (Some(&low), Some(&high)) = (qubits.iter().min(), qubits.iter().max()) else {
+            continue;
+        };
+        let column = next_column[low..=high].iter().copied().max().unwrap_or(0);
+        next_column[low..=high].fill(column + 1);
+        if column == columns.len() {
+            columns.push(Vec::new());
+        }
+        columns[column].push((low, high, symbols(gate)));
+    }
+
+    let prefixes: Vec<String> = (0..num_qubits).map(|q| format!("q{}: ", q)).collect();
+    let prefix_width = prefixes.iter().map(|p| p.len()).max().unwrap_or(0);
+    let mut wire_rows: Vec<String> = prefixes
+        .iter()
+        .map(|p| format!("{:<width$}", p, width = prefix_width))
+        .collect();
+    let mut link_rows = vec![" ".repeat(prefix_width); num_qubits.saturating_sub(1)];
+
+    for column in &columns {
+        let width = column
+            .iter()
+            .flat_map(|(_, _, symbols)| symbols.iter().map(|(_, s)| s.chars().count()))
+            .max()
+            .unwrap_or(1);
+        let mut cells = vec![None; num_qubits];
+        let mut links = vec![false; num_qubits.saturating_sub(1)];
+        for (low, high, symbols) in column {
+            cells[*low..=*high].fill(Some(CROSSING.to_string()));
+            for (q, symbol) in symbols {
+                cells[*q] = Some(symbol.clone());
+            }
+            links[*low..*high].fill(true);
+        }
+        for (row, cell) in wire_rows.iter_mut().zip(cells) {
+            row.extend([WIRE, WIRE]);
+            row.push_str(&centered(cell.as_deref().unwrap_or(""), width, WIRE));
+        }
+        for (row, link) in link_rows.iter_mut().zip(links) {
+            row.push_str("  ");
+            row.push_str(&centered(if link { VERTICAL } else { "" }, width, ' '));
+        }
+    }
+
+    let mut lines = Vec::with_capacity(2 * num_qubits);
+    for (q, mut row) in wire_rows.into_iter().enumerate() {
+        row.extend([WIRE, WIRE]);
+        lines.push(row);
+        if let Some(link) = link_rows.get(q) {
+            lines.push(link.trim_end().to_string());
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circuit::QuantumCircuit;
+
+    #[test]
+    fn test_draw_bell() {
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        let expected = "\
+q0: ──H──●──
+         │
+q1: ─────X──";
+        assert_eq!(circuit.draw(), expected);
+    }
+
+    #[test]
+    fn test_draw_crossing_and_labels() {
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_cz(2, 0);
+        circuit.apply_rz(1, 3);
+        circuit.apply_tdg(0);
+        let expected = "\
+q0: ──●───Tdg───
+      │
+q1: ──┼──RZ(3)──
+      │
+q2: ──●─────────";
+        assert_eq!(circuit.draw(), expected);
+    }
+
+    #[test]
+    fn test_draw_empty() {
+        let circuit = QuantumCircuit::new(2);
+        assert_eq!(circuit.draw(), "q0: ──\n\nq1: ──");
+    }
+}
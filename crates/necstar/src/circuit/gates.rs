@@ -1,8 +1,10 @@
 use crate::error::{Error, Result};
 use stabilizer_ch_form_rust::circuit::CliffordGate;
 use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents a quantum gate in a quantum circuit.
 pub enum QuantumGate {
     // Clifford gates
@@ -30,15 +32,46 @@ pub enum QuantumGate {
     CZ(usize, usize),
     /// SWAP gate
     Swap(usize, usize),
+    /// iSWAP gate, which swaps the qubits and adds the phase `i` to |01> and |10>
+    ISwap(usize, usize),
+    /// Inverse of the iSWAP gate, which swaps the qubits and adds the phase `-i` to |01> and |10>
+    ISwapdg(usize, usize),
+    // - Enumerated single-qubit Cliffords
+    /// The single-qubit Clifford gate with the index `idx` in `0..24`. `(target, idx)`
+    ///
+    /// The index `idx = 4 c + p` (see [`Clifford1Index`]) selects one of the representatives `I`, `H`, `S`, `HS`, `SH`
+    /// and `HSH` (`c`, in the order of application) of the Cliffords modulo Paulis, followed by
+    /// the Pauli `I`, `X`, `Y` or `Z` (`p`).
+    Clifford1(usize, Clifford1Index),
     // Non-Clifford gates
     // - Single-qubit Non-Cliffords
     /// T gate
     T(usize),
     /// T-dagger gate
     Tdg(usize),
+    // - Rotations by multiples of π/4 (Clifford for even multiples)
+    /// Phase gate `diag(1, e^{i k π/4})`. `(target, k)`
+    Phase(usize, i64),
+    /// Rotation around the Z axis by the angle `k·π/4`, i.e. `exp(-i k π/8 Z)` up to a global
+    /// phase. `(target, k)`
+    RZ(usize, i64),
+    /// Rotation around the X axis by the angle `k·π/4`, i.e. `exp(-i k π/8 X)` up to a global
+    /// phase. `(target, k)`
+    RX(usize, i64),
+    /// Rotation around the Y axis by the angle `k·π/4`, i.e. `exp(-i k π/8 Y)` up to a global
+    /// phase. `(target, k)`
+    RY(usize, i64),
     // - Multi-qubit Non-Cliffords
+    /// Controlled-S (CS) gate `diag(1, 1, 1, i)`
+    CS(usize, usize),
+    /// Controlled-S-dagger gate `diag(1, 1, 1, -i)`
+    CSdg(usize, usize),
     /// Toffoli (CCX) gate
     CCX(usize, usize, usize), // (control1, control2, target)
+    /// Controlled-controlled-Z (CCZ) gate
+    CCZ(usize, usize, usize),
+    /// Fredkin (controlled-SWAP) gate
+    CSwap(usize, usize, usize), // (control, target1, target2)
 }
 
 impl QuantumGate {
@@ -47,9 +80,11 @@ impl QuantumGate {
     /// * `bool` - `true` if the gate is a single-qubit gate, otherwise `false`.
     /// ## Examples
     /// ```rust
-    /// use necstar::circuit::QuantumGate;
+    /// use necstar::circuit::{Clifford1Index, QuantumGate};
     /// let gate = QuantumGate::H(0);
     /// assert!(gate.is_single_qubit_gate());
+    /// let gate = QuantumGate::Clifford1(0, Clifford1Index::new(5).unwrap());
+    /// assert!(gate.is_single_qubit_gate());
     /// let gate = QuantumGate::CX(0, 1);
     /// assert!(!gate.is_single_qubit_gate());
     /// ```
@@ -66,6 +101,11 @@ impl QuantumGate {
                 | QuantumGate::SqrtXdg(_)
                 | QuantumGate::T(_)
                 | QuantumGate::Tdg(_)
+                | QuantumGate::Phase(_, _)
+                | QuantumGate::RZ(_, _)
+                | QuantumGate::RX(_, _)
+                | QuantumGate::RY(_, _)
+                | QuantumGate::Clifford1(_, _)
         )
     }
 
@@ -79,8 +119,18 @@ impl QuantumGate {
     /// assert!(gate.is_clifford());
     /// let gate = QuantumGate::T(0);
     /// assert!(!gate.is_clifford());
+    /// // RZ(π/2) = S up to a global phase
+    /// let gate = QuantumGate::RZ(0, 2);
+    /// assert!(gate.is_clifford());
     /// ```
     pub fn is_clifford(&self) -> bool {
+        if let QuantumGate::Phase(_, k)
+        | QuantumGate::RZ(_, k)
+        | QuantumGate::RX(_, k)
+        | QuantumGate::RY(_, k) = self
+        {
+            return k.rem_euclid(2) == 0;
+        }
         matches!(
             self,
             QuantumGate::H(_)
@@ -94,6 +144,9 @@ impl QuantumGate {
                 | QuantumGate::CX(_, _)
                 | QuantumGate::CZ(_, _)
                 | QuantumGate::Swap(_, _)
+                | QuantumGate::ISwap(_, _)
+                | QuantumGate::ISwapdg(_, _)
+                | QuantumGate::Clifford1(_, _)
         )
     }
 
@@ -185,16 +238,44 @@ impl QuantumGate {
             | QuantumGate::SqrtX(q)
             | QuantumGate::SqrtXdg(q)
             | QuantumGate::T(q)
-            | QuantumGate::Tdg(q) => vec![q],
+            | QuantumGate::Tdg(q)
+            | QuantumGate::Phase(q, _)
+            | QuantumGate::RZ(q, _)
+            | QuantumGate::RX(q, _)
+            | QuantumGate::RY(q, _)
+            | QuantumGate::Clifford1(q, _) => vec![q],
 
             // Two-qubit gates
-            QuantumGate::CX(c, t) | QuantumGate::CZ(c, t) | QuantumGate::Swap(c, t) => vec![c, t],
+            QuantumGate::CX(c, t)
+            | QuantumGate::CZ(c, t)
+            | QuantumGate::Swap(c, t)
+            | QuantumGate::ISwap(c, t)
+            | QuantumGate::ISwapdg(c, t)
+            | QuantumGate::CS(c, t)
+            | QuantumGate::CSdg(c, t) => vec![c, t],
 
             // Three-qubit gates
             QuantumGate::CCX(c1, c2, t) => vec![c1, c2, t],
+            QuantumGate::CCZ(q1, q2, q3) => vec![q1, q2, q3],
+            QuantumGate::CSwap(c, a, b) => vec![c, a, b],
         }
     }
 
+    /// Checks that the gate can act on a circuit of `num_qubits` qubits, i.e. that its qubit
+    /// indices are in bounds and distinct.
+    pub(crate) fn validate(&self, num_qubits: usize) -> Result<()> {
+        let qubits = self.qubits();
+        for (i, &q) in qubits.iter().enumerate() {
+            if q >= num_qubits {
+                return Err(Error::QubitIndexOutOfBounds(q, num_qubits));
+            }
+            if qubits[..i].contains(&q) {
+                return Err(Error::DuplicateQubitIndex(q));
+            }
+        }
+        Ok(())
+    }
+
     /// Display the gate name.
     /// ## Returns
     /// * `&'static str` - The name of the gate as a string slice.
@@ -219,9 +300,20 @@ impl QuantumGate {
             QuantumGate::CX(_, _) => "CX",
             QuantumGate::CZ(_, _) => "CZ",
             QuantumGate::Swap(_, _) => "Swap",
+            QuantumGate::ISwap(_, _) => "ISwap",
+            QuantumGate::ISwapdg(_, _) => "ISwapdg",
+            QuantumGate::Clifford1(_, _) => "Clifford1",
             QuantumGate::T(_) => "T",
             QuantumGate::Tdg(_) => "Tdg",
+            QuantumGate::Phase(_, _) => "Phase",
+            QuantumGate::RZ(_, _) => "RZ",
+            QuantumGate::RX(_, _) => "RX",
+            QuantumGate::RY(_, _) => "RY",
+            QuantumGate::CS(_, _) => "CS",
+            QuantumGate::CSdg(_, _) => "CSdg",
             QuantumGate::CCX(_, _, _) => "CCX",
+            QuantumGate::CCZ(_, _, _) => "CCZ",
+            QuantumGate::CSwap(_, _, _) => "CSwap",
         }
     }
 
@@ -244,19 +336,53 @@ impl QuantumGate {
             QuantumGate::SqrtXdg(q) => format!("sxdg {}[{}];", reg_name, q),
             QuantumGate::T(q) => format!("t {}[{}];", reg_name, q),
             QuantumGate::Tdg(q) => format!("tdg {}[{}];", reg_name, q),
+            QuantumGate::Phase(q, k) => format!("p({}) {}[{}];", qasm_angle(*k), reg_name, q),
+            QuantumGate::RZ(q, k) => format!("rz({}) {}[{}];", qasm_angle(*k), reg_name, q),
+            QuantumGate::RX(q, k) => format!("rx({}) {}[{}];", qasm_angle(*k), reg_name, q),
+            QuantumGate::RY(q, k) => format!("ry({}) {}[{}];", qasm_angle(*k), reg_name, q),
             QuantumGate::CX(c, t) => format!("cx {}[{}], {}[{}];", reg_name, c, reg_name, t),
             QuantumGate::CZ(q1, q2) => format!("cz {}[{}], {}[{}];", reg_name, q1, reg_name, q2),
             QuantumGate::Swap(q1, q2) => {
                 format!("swap {}[{}], {}[{}];", reg_name, q1, reg_name, q2)
             }
+            // OpenQASM 2.0 (qelib1.inc) has no iSWAP and CS, so emit their decompositions.
+            QuantumGate::ISwap(_, _)
+            | QuantumGate::ISwapdg(_, _)
+            | QuantumGate::CS(_, _)
+            | QuantumGate::CSdg(_, _)
+            | QuantumGate::Clifford1(_, _) => self
+                .decompose()
+                .iter()
+                .map(|gate| gate.to_qasm_str(reg_name))
+                .collect::<Vec<_>>()
+                .join("\n"),
             QuantumGate::CCX(c1, c2, t) => format!(
                 "ccx {}[{}], {}[{}], {}[{}];",
                 reg_name, c1, reg_name, c2, reg_name, t
             ),
+            // CCZ = H(q3) CCX(q1, q2, q3) H(q3)
+            QuantumGate::CCZ(q1, q2, q3) => [
+                QuantumGate::H(*q3),
+                QuantumGate::CCX(*q1, *q2, *q3),
+                QuantumGate::H(*q3),
+            ]
+            .iter()
+            .map(|gate| gate.to_qasm_str(reg_name))
+            .collect::<Vec<_>>()
+            .join("\n"),
+            QuantumGate::CSwap(c, a, b) => format!(
+                "cswap {}[{}], {}[{}], {}[{}];",
+                reg_name, c, reg_name, a, reg_name, b
+            ),
         }
     }
 
     pub(crate) fn shift_indices(&mut self, offset: usize) {
+        self.map_indices(|q| q + offset);
+    }
+
+    /// Replaces every qubit index `q` of the gate by `f(q)`.
+    pub(crate) fn map_indices(&mut self, f: impl Fn(usize) -> usize) {
         match self {
             // Single-qubit gates
             QuantumGate::H(q)
@@ -268,19 +394,32 @@ impl QuantumGate {
             | QuantumGate::SqrtX(q)
             | QuantumGate::SqrtXdg(q)
             | QuantumGate::T(q)
-            | QuantumGate::Tdg(q) => {
-                *q += offset;
+            | QuantumGate::Tdg(q)
+            | QuantumGate::Phase(q, _)
+            | QuantumGate::RZ(q, _)
+            | QuantumGate::RX(q, _)
+            | QuantumGate::RY(q, _)
+            | QuantumGate::Clifford1(q, _) => {
+                *q = f(*q);
             }
             // Two-qubit gates
-            QuantumGate::CX(c, t) | QuantumGate::CZ(c, t) | QuantumGate::Swap(c, t) => {
-                *c += offset;
-                *t += offset;
+            QuantumGate::CX(c, t)
+            | QuantumGate::CZ(c, t)
+            | QuantumGate::Swap(c, t)
+            | QuantumGate::ISwap(c, t)
+            | QuantumGate::ISwapdg(c, t)
+            | QuantumGate::CS(c, t)
+            | QuantumGate::CSdg(c, t) => {
+                *c = f(*c);
+                *t = f(*t);
             }
             // Three-qubit gates
-            QuantumGate::CCX(c1, c2, t) => {
-                *c1 += offset;
-                *c2 += offset;
-                *t += offset;
+            QuantumGate::CCX(c1, c2, t)
+            | QuantumGate::CCZ(c1, c2, t)
+            | QuantumGate::CSwap(c1, c2, t) => {
+                *c1 = f(*c1);
+                *c2 = f(*c2);
+                *t = f(*t);
             }
         }
     }
@@ -291,6 +430,202 @@ impl QuantumGate {
         new_gate
     }
 
+    /// Decomposes the gate into the primitive gate set (Clifford gates, `T`, `Tdg`, `CCX` and
+    /// `CCZ`).
+    ///
+    /// Primitive gates are returned as they are. `ISwap` and `ISwapdg` are expanded into `S`,
+    /// `Sdg`, `H` and `CX` gates, `CS` and `CSdg` into `T`, `Tdg` and `CX` gates, and `CSwap`
+    /// into a `CCX` conjugated by `CX` gates. `Clifford1` is expanded into its canonical sequence
+    /// of `H`, `S` and Pauli gates. Phase gates and rotations by `k·π/4` are reduced modulo `2π`
+    /// and expanded into the minimal sequence of `Z`, `S`, `Sdg`, `T` and `Tdg` gates (conjugated
+    /// by `H` and `S` for `RX` and `RY`). The decomposition is exact for phase gates and exact up
+    /// to a global phase for rotations.
+    ///
+    /// ## Returns
+    /// * `Vec<QuantumGate>` - The gates to apply in order.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::circuit::QuantumGate;
+    /// assert_eq!(QuantumGate::RZ(0, 2).decompose(), vec![QuantumGate::S(0)]);
+    /// assert_eq!(QuantumGate::RZ(0, -1).decompose(), vec![QuantumGate::Tdg(0)]);
+    /// assert_eq!(QuantumGate::RZ(0, 8).decompose(), vec![]);
+    /// assert_eq!(QuantumGate::H(0).decompose(), vec![QuantumGate::H(0)]);
+    /// ```
+    pub fn decompose(&self) -> Vec<QuantumGate> {
+        match *self {
+            QuantumGate::Phase(q, k) | QuantumGate::RZ(q, k) => z_rotation_gates(q, k),
+            QuantumGate::RX(q, k) => {
+                let rz = z_rotation_gates(q, k);
+                if rz.is_empty() {
+                    return rz;
+                }
+                // RX(θ) = H RZ(θ) H
+                let mut gates = vec![QuantumGate::H(q)];
+                gates.extend(rz);
+                gates.push(QuantumGate::H(q));
+                gates
+            }
+            QuantumGate::RY(q, k) => {
+                let rz = z_rotation_gates(q, k);
+                if rz.is_empty() {
+                    return rz;
+                }
+                // RY(θ) = S H RZ(θ) H Sdg
+                let mut gates = vec![QuantumGate::Sdg(q), QuantumGate::H(q)];
+                gates.extend(rz);
+                gates.push(QuantumGate::H(q));
+                gates.push(QuantumGate::S(q));
+                gates
+            }
+            QuantumGate::ISwap(a, b) => vec![
+                QuantumGate::S(a),
+                QuantumGate::S(b),
+                QuantumGate::H(a),
+                QuantumGate::CX(a, b),
+                QuantumGate::CX(b, a),
+                QuantumGate::H(b),
+            ],
+            QuantumGate::ISwapdg(a, b) => vec![
+                QuantumGate::H(b),
+                QuantumGate::CX(b, a),
+                QuantumGate::CX(a, b),
+                QuantumGate::H(a),
+                QuantumGate::Sdg(b),
+                QuantumGate::Sdg(a),
+            ],
+            QuantumGate::Clifford1(q, idx) => clifford1_gates(q, idx),
+            // CS(a, b) = e^{iπ/4 (a + b - a⊕b)} = i^{ab}
+            QuantumGate::CS(a, b) => vec![
+                QuantumGate::T(a),
+                QuantumGate::T(b),
+                QuantumGate::CX(a, b),
+                QuantumGate::Tdg(b),
+                QuantumGate::CX(a, b),
+            ],
+            QuantumGate::CSdg(a, b) => vec![
+                QuantumGate::Tdg(a),
+                QuantumGate::Tdg(b),
+                QuantumGate::CX(a, b),
+                QuantumGate::T(b),
+                QuantumGate::CX(a, b),
+            ],
+            // CSWAP(c, a, b) = CX(b, a) CCX(c, a, b) CX(b, a)
+            QuantumGate::CSwap(c, a, b) => vec![
+                QuantumGate::CX(b, a),
+                QuantumGate::CCX(c, a, b),
+                QuantumGate::CX(b, a),
+            ],
+            _ => vec![self.clone()],
+        }
+    }
+
+    /// Returns the inverse of the gate.
+    ///
+    /// Self-inverse gates are returned as they are, and the other gates are mapped to their
+    /// adjoint counterparts (e.g. `S` to `Sdg`, `RZ(q, k)` to `RZ(q, -k)`).
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::circuit::QuantumGate;
+    /// assert_eq!(QuantumGate::S(0).inverse(), QuantumGate::Sdg(0));
+    /// assert_eq!(QuantumGate::CX(0, 1).inverse(), QuantumGate::CX(0, 1));
+    /// assert_eq!(QuantumGate::RZ(0, 3).inverse(), QuantumGate::RZ(0, -3));
+    /// ```
+    pub fn inverse(&self) -> QuantumGate {
+        match *self {
+            QuantumGate::S(q) => QuantumGate::Sdg(q),
+            QuantumGate::Sdg(q) => QuantumGate::S(q),
+            QuantumGate::SqrtX(q) => QuantumGate::SqrtXdg(q),
+            QuantumGate::SqrtXdg(q) => QuantumGate::SqrtX(q),
+            QuantumGate::T(q) => QuantumGate::Tdg(q),
+            QuantumGate::Tdg(q) => QuantumGate::T(q),
+            QuantumGate::ISwap(a, b) => QuantumGate::ISwapdg(a, b),
+            QuantumGate::ISwapdg(a, b) => QuantumGate::ISwap(a, b),
+            QuantumGate::CS(a, b) => QuantumGate::CSdg(a, b),
+            QuantumGate::CSdg(a, b) => QuantumGate::CS(a, b),
+            QuantumGate::Clifford1(q, idx) => {
+                QuantumGate::Clifford1(q, Clifford1Index(CLIFFORD1_INVERSES[idx.0 as usize]))
+            }
+            QuantumGate::Phase(q, k) => QuantumGate::Phase(q, -k),
+            QuantumGate::RZ(q, k) => QuantumGate::RZ(q, -k),
+            QuantumGate::RX(q, k) => QuantumGate::RX(q, -k),
+            QuantumGate::RY(q, k) => QuantumGate::RY(q, -k),
+            QuantumGate::H(_)
+            | QuantumGate::X(_)
+            | QuantumGate::Y(_)
+            | QuantumGate::Z(_)
+            | QuantumGate::CX(_, _)
+            | QuantumGate::CZ(_, _)
+            | QuantumGate::Swap(_, _)
+            | QuantumGate::CCX(_, _, _)
+            | QuantumGate::CCZ(_, _, _)
+            | QuantumGate::CSwap(_, _, _) => self.clone(),
+        }
+    }
+
+    /// Returns the gates implementing the gate controlled on the qubit `control`.
+    ///
+    /// Supported gates are `X`, `Y`, `Z`, `H`, `S`, `Sdg`, `CX`, `CZ` and `Swap`. Gates with a
+    /// native controlled counterpart are mapped to it (e.g. `X` to `CX`, `CX` to `CCX`), and `Y`
+    /// and `H` are expanded into `CX` conjugated by single-qubit gates on the target.
+    ///
+    /// Three-qubit gates such as `CCX` are not supported: every circuit of the supported gate set
+    /// on four qubits has determinant 1, while `C³X` has determinant -1, so it has no exact
+    /// decomposition without an ancilla qubit.
+    ///
+    /// ## Arguments
+    /// * `control` - The control qubit index.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the gates to apply in order, or an
+    /// [`Error`](crate::error::Error) if the gate acts on `control`
+    /// ([`DuplicateQubitIndex`](crate::error::Error::DuplicateQubitIndex)) or has no supported
+    /// controlled version ([`GateNotControllable`](crate::error::Error::GateNotControllable)).
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::circuit::QuantumGate;
+    /// assert_eq!(QuantumGate::X(0).controlled(1).unwrap(), vec![QuantumGate::CX(1, 0)]);
+    /// assert_eq!(
+    ///     QuantumGate::CX(0, 1).controlled(2).unwrap(),
+    ///     vec![QuantumGate::CCX(2, 0, 1)]
+    /// );
+    /// assert!(QuantumGate::T(0).controlled(1).is_err());
+    /// ```
+    pub fn controlled(&self, control: usize) -> Result<Vec<QuantumGate>> {
+        if self.qubits().contains(&control) {
+            return Err(Error::DuplicateQubitIndex(control));
+        }
+        let c = control;
+        match *self {
+            QuantumGate::X(q) => Ok(vec![QuantumGate::CX(c, q)]),
+            // CY = S CX Sdg on the target
+            QuantumGate::Y(q) => Ok(vec![
+                QuantumGate::Sdg(q),
+                QuantumGate::CX(c, q),
+                QuantumGate::S(q),
+            ]),
+            QuantumGate::Z(q) => Ok(vec![QuantumGate::CZ(c, q)]),
+            // CH = Sdg H Tdg CX T H S on the target
+            QuantumGate::H(q) => Ok(vec![
+                QuantumGate::S(q),
+                QuantumGate::H(q),
+                QuantumGate::T(q),
+                QuantumGate::CX(c, q),
+                QuantumGate::Tdg(q),
+                QuantumGate::H(q),
+                QuantumGate::Sdg(q),
+            ]),
+            QuantumGate::S(q) => Ok(vec![QuantumGate::CS(c, q)]),
+            QuantumGate::Sdg(q) => Ok(vec![QuantumGate::CSdg(c, q)]),
+            QuantumGate::CX(a, b) => Ok(vec![QuantumGate::CCX(c, a, b)]),
+            QuantumGate::CZ(a, b) => Ok(vec![QuantumGate::CCZ(c, a, b)]),
+            QuantumGate::Swap(a, b) => Ok(vec![QuantumGate::CSwap(c, a, b)]),
+            _ => Err(Error::GateNotControllable(self.to_string())),
+        }
+    }
+
     pub(crate) fn to_clifford_gate(&self) -> Result<CliffordGate> {
         match self {
             QuantumGate::H(q) => Ok(CliffordGate::H(*q)),
@@ -309,6 +644,123 @@ impl QuantumGate {
     }
 }
 
+/// Returns the minimal sequence of `Z`, `S`, `Sdg`, `T` and `Tdg` gates implementing the
+/// phase gate `diag(1, e^{i k π/4})`, i.e. the Z rotation by `k·π/4` up to a global phase.
+fn z_rotation_gates(q: usize, k: i64) -> Vec<QuantumGate> {
+    match k.rem_euclid(8) {
+        0 => vec![],
+        1 => vec![QuantumGate::T(q)],
+        2 => vec![QuantumGate::S(q)],
+        3 => vec![QuantumGate::S(q), QuantumGate::T(q)],
+        4 => vec![QuantumGate::Z(q)],
+        5 => vec![QuantumGate::Z(q), QuantumGate::T(q)],
+        6 => vec![QuantumGate::Sdg(q)],
+        7 => vec![QuantumGate::Tdg(q)],
+        _ => unreachable!(),
+    }
+}
+
+/// The number of single-qubit Clifford gates up to a global phase.
+pub(crate) const NUM_CLIFFORD1: u8 = 24;
+
+/// The index of a single-qubit Clifford gate up to a global phase, which is guaranteed to be in
+/// `0..24`. See [`QuantumGate::Clifford1`] for the enumeration.
+///
+/// ## Examples
+/// ```rust
+/// use necstar::circuit::Clifford1Index;
+/// let idx = Clifford1Index::new(17).unwrap();
+/// assert_eq!(idx.get(), 17);
+/// assert!(Clifford1Index::new(24).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
+pub struct Clifford1Index(u8);
+
+impl Clifford1Index {
+    /// Creates the index `idx`, or returns [`Error::InvalidClifford1Index`] if `idx` is not
+    /// less than 24.
+    pub fn new(idx: u8) -> Result<Self> {
+        if idx >= NUM_CLIFFORD1 {
+            return Err(Error::InvalidClifford1Index(idx));
+        }
+        Ok(Clifford1Index(idx))
+    }
+
+    /// Returns the index as an integer in `0..24`.
+    pub fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for Clifford1Index {
+    type Error = Error;
+
+    fn try_from(idx: u8) -> Result<Self> {
+        Clifford1Index::new(idx)
+    }
+}
+
+impl From<Clifford1Index> for u8 {
+    fn from(idx: Clifford1Index) -> Self {
+        idx.0
+    }
+}
+
+impl fmt::Display for Clifford1Index {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+type Gate1Fn = fn(usize) -> QuantumGate;
+
+/// Representatives of the single-qubit Cliffords modulo Paulis, in the order of application.
+const CLIFFORD1_REPRESENTATIVES: [&[Gate1Fn]; 6] = [
+    &[],
+    &[QuantumGate::H],
+    &[QuantumGate::S],
+    &[QuantumGate::H, QuantumGate::S],
+    &[QuantumGate::S, QuantumGate::H],
+    &[QuantumGate::H, QuantumGate::S, QuantumGate::H],
+];
+
+const CLIFFORD1_PAULIS: [Option<Gate1Fn>; 4] = [
+    None,
+    Some(QuantumGate::X),
+    Some(QuantumGate::Y),
+    Some(QuantumGate::Z),
+];
+
+/// `CLIFFORD1_INVERSES[idx]` is the index of the inverse of the single-qubit Clifford `idx`.
+const CLIFFORD1_INVERSES: [u8; NUM_CLIFFORD1 as usize] = [
+    0, 1, 2, 3, 4, 7, 6, 5, 11, 9, 10, 8, 17, 19, 18, 16, 15, 12, 14, 13, 21, 20, 22, 23,
+];
+
+/// Returns the canonical sequence of gates implementing the single-qubit Clifford `idx`.
+fn clifford1_gates(q: usize, idx: Clifford1Index) -> Vec<QuantumGate> {
+    let (c, p) = (idx.0 as usize / 4, idx.0 as usize % 4);
+    CLIFFORD1_REPRESENTATIVES[c]
+        .iter()
+        .chain(CLIFFORD1_PAULIS[p].iter())
+        .map(|gate| gate(q))
+        .collect()
+}
+
+/// Formats the angle `k·π/4` as a QASM expression.
+fn qasm_angle(k: i64) -> String {
+    match k {
+        0 => "0".to_string(),
+        1 => "pi/4".to_string(),
+        -1 => "-pi/4".to_string(),
+        _ => format!("{}*pi/4", k),
+    }
+}
+
 impl From<CliffordGate> for QuantumGate {
     fn from(clifford_gate: CliffordGate) -> Self {
         match clifford_gate {
@@ -340,10 +792,235 @@ impl fmt::Display for QuantumGate {
             QuantumGate::SqrtXdg(q) => write!(f, "SqrtXdg({})", q),
             QuantumGate::T(q) => write!(f, "T({})", q),
             QuantumGate::Tdg(q) => write!(f, "Tdg({})", q),
+            QuantumGate::Phase(q, k) => write!(f, "Phase({}, {})", q, k),
+            QuantumGate::RZ(q, k) => write!(f, "RZ({}, {})", q, k),
+            QuantumGate::RX(q, k) => write!(f, "RX({}, {})", q, k),
+            QuantumGate::RY(q, k) => write!(f, "RY({}, {})", q, k),
             QuantumGate::CX(c, t) => write!(f, "CX({}, {})", c, t),
             QuantumGate::CZ(c, t) => write!(f, "CZ({}, {})", c, t),
             QuantumGate::Swap(q1, q2) => write!(f, "Swap({}, {})", q1, q2),
+            QuantumGate::ISwap(q1, q2) => write!(f, "ISwap({}, {})", q1, q2),
+            QuantumGate::ISwapdg(q1, q2) => write!(f, "ISwapdg({}, {})", q1, q2),
+            QuantumGate::Clifford1(q, idx) => write!(f, "Clifford1({}, {})", q, idx),
+            QuantumGate::CS(q1, q2) => write!(f, "CS({}, {})", q1, q2),
+            QuantumGate::CSdg(q1, q2) => write!(f, "CSdg({}, {})", q1, q2),
             QuantumGate::CCX(c1, c2, t) => write!(f, "CCX({}, {}, {})", c1, c2, t),
+            QuantumGate::CCZ(q1, q2, q3) => write!(f, "CCZ({}, {}, {})", q1, q2, q3),
+            QuantumGate::CSwap(c, a, b) => write!(f, "CSwap({}, {}, {})", c, a, b),
+        }
+    }
+}
+
+impl FromStr for QuantumGate {
+    type Err = Error;
+
+    /// Parses a single gate either in the format of the [`Display`](fmt::Display) implementation,
+    /// e.g. `"CX(0, 1)"` or `"RZ(2, 3)"`, or as a name followed by whitespace-separated
+    /// arguments, e.g. `"cx 0 1"` or `"rz 2 3"`.
+    ///
+    /// Gate names are case-insensitive, and the QASM names `sx`, `sxdg`, `cnot`, `p`, `cswap` and
+    /// `fredkin` are accepted as aliases. The arguments are the qubit indices followed by the
+    /// multiple `k` of π/4 for rotations or the index for `Clifford1`. Qubit indices are not
+    /// checked against any circuit.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let name_end = s
+            .find(|c: char| c == '(' || c.is_whitespace())
+            .unwrap_or(s.len());
+        let (name, rest) = s.split_at(name_end);
+        let rest = rest.trim();
+        let args: Vec<&str> = if rest.starts_with('(') {
+            let Some(inner) = rest
+                .strip_prefix('(')
+                .and_then(|inner| inner.strip_suffix(')'))
+            else {
+                return Err(Error::GateParsingError(format!(
+                    "missing closing parenthesis in '{}'",
+                    s
+                )));
+            };
+            if inner.trim().is_empty() {
+                Vec::new()
+            } else {
+                inner.split(',').map(str::trim).collect()
+            }
+        } else {
+            rest.split_whitespace().collect()
+        };
+
+        let qubit = |i: usize| -> Result<usize> {
+            args[i].parse().map_err(|_| {
+                Error::GateParsingError(format!("invalid qubit index '{}' in '{}'", args[i], s))
+            })
+        };
+        let multiple = |i: usize| -> Result<i64> {
+            args[i].parse().map_err(|_| {
+                Error::GateParsingError(format!(
+                    "invalid multiple of pi/4 '{}' in '{}'",
+                    args[i], s
+                ))
+            })
+        };
+
+        let lower = name.to_ascii_lowercase();
+        let arity = match lower.as_str() {
+            "h" | "x" | "y" | "z" | "s" | "sdg" | "sqrtx" | "sx" | "sqrtxdg" | "sxdg" | "t"
+            | "tdg" => 1,
+            "cx" | "cnot" | "cz" | "swap" | "iswap" | "iswapdg" | "cs" | "csdg" | "phase" | "p"
+            | "rz" | "rx" | "ry" | "clifford1" => 2,
+            "ccx" | "ccz" | "cswap" | "fredkin" => 3,
+            _ => {
+                return Err(Error::GateParsingError(format!(
+                    "unknown gate name '{}'",
+                    name
+                )));
+            }
+        };
+        if args.len() != arity {
+            return Err(Error::GateParsingError(format!(
+                "gate '{}' takes {} arguments, got {} in '{}'",
+                name,
+                arity,
+                args.len(),
+                s
+            )));
+        }
+
+        let gate = match lower.as_str() {
+            "h" => QuantumGate::H(qubit(0)?),
+            "x" => QuantumGate::X(qubit(0)?),
+            "y" => QuantumGate::Y(qubit(0)?),
+            "z" => QuantumGate::Z(qubit(0)?),
+            "s" => QuantumGate::S(qubit(0)?),
+            "sdg" => QuantumGate::Sdg(qubit(0)?),
+            "sqrtx" | "sx" => QuantumGate::SqrtX(qubit(0)?),
+            "sqrtxdg" | "sxdg" => QuantumGate::SqrtXdg(qubit(0)?),
+            "t" => QuantumGate::T(qubit(0)?),
+            "tdg" => QuantumGate::Tdg(qubit(0)?),
+            "cx" | "cnot" => QuantumGate::CX(qubit(0)?, qubit(1)?),
+            "cz" => QuantumGate::CZ(qubit(0)?, qubit(1)?),
+            "swap" => QuantumGate::Swap(qubit(0)?, qubit(1)?),
+            "iswap" => QuantumGate::ISwap(qubit(0)?, qubit(1)?),
+            "iswapdg" => QuantumGate::ISwapdg(qubit(0)?, qubit(1)?),
+            "cs" => QuantumGate::CS(qubit(0)?, qubit(1)?),
+            "csdg" => QuantumGate::CSdg(qubit(0)?, qubit(1)?),
+            "phase" | "p" => QuantumGate::Phase(qubit(0)?, multiple(1)?),
+            "rz" => QuantumGate::RZ(qubit(0)?, multiple(1)?),
+            "rx" => QuantumGate::RX(qubit(0)?, multiple(1)?),
+            "ry" => QuantumGate::RY(qubit(0)?, multiple(1)?),
+            "clifford1" => {
+                let idx: u8 = args[1].parse().map_err(|_| {
+                    Error::GateParsingError(format!(
+                        "invalid Clifford index '{}' in '{}'",
+                        args[1], s
+                    ))
+                })?;
+                QuantumGate::Clifford1(qubit(0)?, Clifford1Index::new(idx)?)
+            }
+            "ccx" => QuantumGate::CCX(qubit(0)?, qubit(1)?, qubit(2)?),
+            "ccz" => QuantumGate::CCZ(qubit(0)?, qubit(1)?, qubit(2)?),
+            _ => QuantumGate::CSwap(qubit(0)?, qubit(1)?, qubit(2)?),
+        };
+        Ok(gate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let cases = [
+            ("h 0", QuantumGate::H(0)),
+            ("cx 0 1", QuantumGate::CX(0, 1)),
+            ("t 2", QuantumGate::T(2)),
+            ("ccx 0 1 2", QuantumGate::CCX(0, 1, 2)),
+            ("  SX   3 ", QuantumGate::SqrtX(3)),
+            ("cnot 4 1", QuantumGate::CX(4, 1)),
+            ("p 1 -3", QuantumGate::Phase(1, -3)),
+            ("rx(0,5)", QuantumGate::RX(0, 5)),
+            (
+                "Clifford1 2 23",
+                QuantumGate::Clifford1(2, Clifford1Index::new(23).unwrap()),
+            ),
+            ("fredkin 0 1 2", QuantumGate::CSwap(0, 1, 2)),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(s.parse::<QuantumGate>().unwrap(), expected, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_from_str_display_round_trip() {
+        let gates = [
+            QuantumGate::H(0),
+            QuantumGate::X(1),
+            QuantumGate::Y(2),
+            QuantumGate::Z(3),
+            QuantumGate::S(4),
+            QuantumGate::Sdg(5),
+            QuantumGate::SqrtX(6),
+            QuantumGate::SqrtXdg(7),
+            QuantumGate::T(8),
+            QuantumGate::Tdg(9),
+            QuantumGate::Phase(0, 3),
+            QuantumGate::RZ(1, -2),
+            QuantumGate::RX(2, 7),
+            QuantumGate::RY(3, 1),
+            QuantumGate::CX(0, 1),
+            QuantumGate::CZ(2, 3),
+            QuantumGate::Swap(4, 5),
+            QuantumGate::ISwap(6, 7),
+            QuantumGate::ISwapdg(8, 9),
+            QuantumGate::Clifford1(10, Clifford1Index::new(17).unwrap()),
+            QuantumGate::CS(11, 12),
+            QuantumGate::CSdg(13, 14),
+            QuantumGate::CCX(0, 1, 2),
+            QuantumGate::CCZ(3, 4, 5),
+            QuantumGate::CSwap(6, 7, 8),
+        ];
+        for gate in gates {
+            let s = format!("{}", gate);
+            assert_eq!(s.parse::<QuantumGate>().unwrap(), gate, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_clifford1_index() {
+        for idx in 0..24 {
+            assert_eq!(Clifford1Index::new(idx).unwrap().get(), idx);
+        }
+        for idx in [24, 30, u8::MAX] {
+            assert!(matches!(
+                Clifford1Index::try_from(idx),
+                Err(Error::InvalidClifford1Index(i)) if i == idx
+            ));
+        }
+        // Every index has an inverse in range, and inverting twice gives the gate back.
+        for idx in 0..24 {
+            let gate = QuantumGate::Clifford1(0, Clifford1Index::new(idx).unwrap());
+            assert_eq!(gate.inverse().inverse(), gate);
+            assert!(!gate.decompose().is_empty() || idx == 0);
+        }
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        for s in [
+            "foo 0", "", "h", "h 0 1", "cx 0", "ccx 0 1", "CX(0, 1", "h a", "rz 0 x",
+        ] {
+            assert!(
+                matches!(s.parse::<QuantumGate>(), Err(Error::GateParsingError(_))),
+                "{}",
+                s
+            );
         }
+        assert!(matches!(
+            "clifford1 0 24".parse::<QuantumGate>(),
+            Err(Error::InvalidClifford1Index(24))
+        ));
+        let message = "cx 0".parse::<QuantumGate>().unwrap_err().to_string();
+        assert!(message.contains("takes 2 arguments, got 1"));
     }
 }
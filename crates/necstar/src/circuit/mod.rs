@@ -1,11 +1,18 @@
+mod draw;
 mod gates;
 mod parser;
+mod pauli_conjugation;
 mod random_clifford;
+pub(crate) mod random_clifford_t;
+#[cfg(feature = "serde")]
+mod serialization;
 
-pub use gates::QuantumGate;
+pub use gates::{Clifford1Index, QuantumGate};
 
-use crate::error::Result;
-use std::{fmt, path::Path};
+use crate::error::{Error, Result};
+use crate::types::PauliString;
+use stabilizer_ch_form_rust::circuit::CliffordCircuit;
+use std::{collections::HashMap, fmt, path::Path};
 
 /// Represents a quantum circuit as a sequence of quantum gates.
 ///
@@ -50,9 +57,23 @@ use std::{fmt, path::Path};
 /// // Get the stabilizer rank χ
 /// println!("Stabilizer rank: {}", state.stabilizer_rank());
 /// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "serialization::SerializedCircuit")
+)]
 pub struct QuantumCircuit {
     pub num_qubits: usize,
     pub gates: Vec<QuantumGate>,
+    /// The `(qubit, clbit)` pairs of the measurements read from a QASM program, in program
+    /// order. They are not simulated; transformations other than [`simplify`](Self::simplify)
+    /// do not carry them over.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub measurements: Vec<(usize, usize)>,
 }
 
 impl QuantumCircuit {
@@ -61,6 +82,7 @@ impl QuantumCircuit {
         Self {
             num_qubits,
             gates: Vec::new(),
+            measurements: Vec::new(),
         }
     }
 
@@ -102,6 +124,57 @@ impl QuantumCircuit {
         self.gates.extend_from_slice(gates);
     }
 
+    /// Apply a quantum gate to the circuit after checking that its qubit indices are less than
+    /// `num_qubits` and distinct.
+    ///
+    /// Unlike [`apply_gate`](Self::apply_gate), an invalid gate is reported here instead of
+    /// during the compilation.
+    ///
+    /// ## Arguments
+    /// * `gate` - The quantum gate to apply.
+    /// ## Example
+    /// ```rust
+    /// use necstar::circuit::QuantumGate;
+    /// use necstar::error::Error;
+    /// use necstar::prelude::QuantumCircuit;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.try_apply_gate(QuantumGate::CX(0, 1)).unwrap();
+    /// assert!(matches!(
+    ///     circuit.try_apply_gate(QuantumGate::H(5)),
+    ///     Err(Error::QubitIndexOutOfBounds(5, 2))
+    /// ));
+    /// assert!(matches!(
+    ///     circuit.try_apply_gate(QuantumGate::CZ(1, 1)),
+    ///     Err(Error::DuplicateQubitIndex(1))
+    /// ));
+    /// assert_eq!(circuit.gates, vec![QuantumGate::CX(0, 1)]);
+    /// ```
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` if the gate was applied, or an
+    /// [`Error`](crate::error::Error) if it is invalid for the circuit.
+    pub fn try_apply_gate(&mut self, gate: QuantumGate) -> Result<()> {
+        gate.validate(self.num_qubits)?;
+        self.gates.push(gate);
+        Ok(())
+    }
+
+    /// Apply a sequence of quantum gates to the circuit after validating each of them as in
+    /// [`try_apply_gate`](Self::try_apply_gate). If any gate is invalid, the circuit is left
+    /// unchanged.
+    ///
+    /// ## Arguments
+    /// * `gates` - A slice of quantum gates to apply.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` if the gates were applied, or an
+    /// [`Error`](crate::error::Error) for the first invalid gate.
+    pub fn try_apply_gates(&mut self, gates: &[QuantumGate]) -> Result<()> {
+        for gate in gates {
+            gate.validate(self.num_qubits)?;
+        }
+        self.gates.extend_from_slice(gates);
+        Ok(())
+    }
+
     /// Apply a Hadamard gate to the target qubit.
     /// ## Arguments
     /// * `target` - The target qubit index.
@@ -198,6 +271,102 @@ impl QuantumCircuit {
         self.apply_gate(QuantumGate::Tdg(target));
     }
 
+    /// Apply an iSWAP gate to the specified qubits.
+    ///
+    /// iSWAP is a Clifford gate that swaps the two qubits and multiplies |01> and |10> by `i`.
+    /// It is decomposed into `S`, `H` and `CX` gates during compilation.
+    /// ## Arguments
+    /// * `qarg1` - The first qubit index.
+    /// * `qarg2` - The second qubit index.
+    pub fn apply_iswap(&mut self, qarg1: usize, qarg2: usize) {
+        self.apply_gate(QuantumGate::ISwap(qarg1, qarg2));
+    }
+
+    /// Apply the inverse of an iSWAP gate to the specified qubits.
+    /// ## Arguments
+    /// * `qarg1` - The first qubit index.
+    /// * `qarg2` - The second qubit index.
+    pub fn apply_iswapdg(&mut self, qarg1: usize, qarg2: usize) {
+        self.apply_gate(QuantumGate::ISwapdg(qarg1, qarg2));
+    }
+
+    /// Apply the single-qubit Clifford gate with the index `idx` in `0..24`.
+    ///
+    /// See [`QuantumGate::Clifford1`] for the enumeration.
+    /// ## Arguments
+    /// * `target` - The target qubit index.
+    /// * `idx` - The index of the single-qubit Clifford gate.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is [`Error::InvalidClifford1Index`] if `idx` is not less than 24.
+    pub fn apply_clifford1(&mut self, target: usize, idx: u8) -> Result<()> {
+        self.apply_gate(QuantumGate::Clifford1(target, Clifford1Index::new(idx)?));
+        Ok(())
+    }
+
+    /// Apply a phase gate `diag(1, e^{i k π/4})`.
+    ///
+    /// The gate is decomposed exactly into the minimal sequence of `Z`, `S`, `Sdg`, `T` and
+    /// `Tdg` gates during compilation, e.g. `k = 1` is `T` and `k = 4` is `Z`.
+    /// `k ≡ 0 (mod 8)` is the identity and adds no gates after decomposition.
+    /// ## Arguments
+    /// * `target` - The target qubit index.
+    /// * `k` - The phase angle in units of `π/4`.
+    pub fn apply_phase(&mut self, target: usize, k: i64) {
+        self.apply_gate(QuantumGate::Phase(target, k));
+    }
+
+    /// Apply a rotation around the Z axis by the angle `k·π/4`.
+    ///
+    /// The rotation is equal to `exp(-i k π/8 Z)` up to a global phase, and is decomposed into
+    /// the minimal sequence of `Z`, `S`, `Sdg`, `T` and `Tdg` gates during compilation
+    /// (e.g. `k = 1` → `T`, `k = 2` → `S`, `k = 7` → `Tdg`). See [`QuantumGate::decompose`].
+    /// ## Arguments
+    /// * `target` - The target qubit index.
+    /// * `k` - The rotation angle in units of `π/4`.
+    pub fn apply_rz(&mut self, target: usize, k: i64) {
+        self.apply_gate(QuantumGate::RZ(target, k));
+    }
+
+    /// Apply a rotation around the X axis by the angle `k·π/4`.
+    ///
+    /// The rotation is equal to `exp(-i k π/8 X) = H RZ(k·π/4) H` up to a global phase.
+    /// ## Arguments
+    /// * `target` - The target qubit index.
+    /// * `k` - The rotation angle in units of `π/4`.
+    pub fn apply_rx(&mut self, target: usize, k: i64) {
+        self.apply_gate(QuantumGate::RX(target, k));
+    }
+
+    /// Apply a rotation around the Y axis by the angle `k·π/4`.
+    ///
+    /// The rotation is equal to `exp(-i k π/8 Y) = S H RZ(k·π/4) H Sdg` up to a global phase.
+    /// ## Arguments
+    /// * `target` - The target qubit index.
+    /// * `k` - The rotation angle in units of `π/4`.
+    pub fn apply_ry(&mut self, target: usize, k: i64) {
+        self.apply_gate(QuantumGate::RY(target, k));
+    }
+
+    /// Apply a controlled-S (CS) gate `diag(1, 1, 1, i)` to the specified qubits.
+    ///
+    /// Note that CS is not a Clifford gate. It is decomposed into `T`, `Tdg` and `CX` gates
+    /// during compilation, i.e. it costs three T-type gates.
+    /// ## Arguments
+    /// * `qarg1` - The first qubit index.
+    /// * `qarg2` - The second qubit index.
+    pub fn apply_cs(&mut self, qarg1: usize, qarg2: usize) {
+        self.apply_gate(QuantumGate::CS(qarg1, qarg2));
+    }
+
+    /// Apply a controlled-S-dagger gate `diag(1, 1, 1, -i)` to the specified qubits.
+    /// ## Arguments
+    /// * `qarg1` - The first qubit index.
+    /// * `qarg2` - The second qubit index.
+    pub fn apply_csdg(&mut self, qarg1: usize, qarg2: usize) {
+        self.apply_gate(QuantumGate::CSdg(qarg1, qarg2));
+    }
+
     /// Apply a Toffoli (CCX) gate with the specified control and target qubits.
     /// ## Arguments
     /// * `control1` - The first control qubit index.
@@ -207,6 +376,28 @@ impl QuantumCircuit {
         self.apply_gate(QuantumGate::CCX(control1, control2, target));
     }
 
+    /// Apply a controlled-controlled-Z (CCZ) gate on the specified qubits.
+    /// The gate is symmetric in its three qubits.
+    /// ## Arguments
+    /// * `qarg1` - The first qubit index.
+    /// * `qarg2` - The second qubit index.
+    /// * `qarg3` - The third qubit index.
+    pub fn apply_ccz(&mut self, qarg1: usize, qarg2: usize, qarg3: usize) {
+        self.apply_gate(QuantumGate::CCZ(qarg1, qarg2, qarg3));
+    }
+
+    /// Apply a Fredkin (controlled-SWAP) gate, which swaps the two target qubits if the
+    /// control qubit is |1>.
+    ///
+    /// It is decomposed into a Toffoli gate and two `CX` gates during compilation.
+    /// ## Arguments
+    /// * `control` - The control qubit index.
+    /// * `target1` - The first target qubit index.
+    /// * `target2` - The second target qubit index.
+    pub fn apply_cswap(&mut self, control: usize, target1: usize, target2: usize) {
+        self.apply_gate(QuantumGate::CSwap(control, target1, target2));
+    }
+
     /// Appends the gates from another [`QuantumCircuit`] to this one.
     ///
     /// ## Arguments
@@ -229,6 +420,233 @@ impl QuantumCircuit {
         self.gates.extend_from_slice(&other.gates);
     }
 
+    /// Appends the gates from another [`QuantumCircuit`] to this one, mapping qubit `j` of
+    /// `other` to qubit `qubits[j]` of this circuit.
+    ///
+    /// The recorded measurements of `other` are not copied. If the mapping is invalid, the
+    /// circuit is left unchanged.
+    ///
+    /// ## Arguments
+    /// - `other`: A reference to another [`QuantumCircuit`] whose gates will be appended.
+    /// - `qubits`: The qubits of this circuit that the qubits of `other` are mapped to.
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// let mut bell = QuantumCircuit::new(2);
+    /// bell.apply_h(0);
+    /// bell.apply_cx(0, 1);
+    /// circuit.compose(&bell, &[2, 0]).unwrap();
+    /// assert_eq!(circuit.gates, vec![QuantumGate::H(2), QuantumGate::CX(2, 0)]);
+    /// ```
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` if the gates were appended, or an
+    /// [`Error`](crate::error::Error) if `qubits.len()` differs from `other.num_qubits`
+    /// ([`QubitCountMismatch`](crate::error::Error::QubitCountMismatch)), an index is out of
+    /// bounds ([`QubitIndexOutOfBounds`](crate::error::Error::QubitIndexOutOfBounds)) or an
+    /// index is repeated ([`DuplicateQubitIndex`](crate::error::Error::DuplicateQubitIndex)).
+    pub fn compose(&mut self, other: &QuantumCircuit, qubits: &[usize]) -> Result<()> {
+        if qubits.len() != other.num_qubits {
+            return Err(Error::QubitCountMismatch {
+                operation: "compose",
+                left: qubits.len(),
+                right: other.num_qubits,
+            });
+        }
+        let mut seen = vec![false; self.num_qubits];
+        for &q in qubits {
+            if q >= self.num_qubits {
+                return Err(Error::QubitIndexOutOfBounds(q, self.num_qubits));
+            }
+            if seen[q] {
+                return Err(Error::DuplicateQubitIndex(q));
+            }
+            seen[q] = true;
+        }
+        for gate in &other.gates {
+            gate.validate(other.num_qubits)?;
+        }
+        self.gates.extend(other.gates.iter().map(|gate| {
+            let mut gate = gate.clone();
+            gate.map_indices(|q| qubits[q]);
+            gate
+        }));
+        Ok(())
+    }
+
+    /// Creates the circuit that repeats the gates of this circuit `n` times, i.e. `U^n` for the
+    /// unitary `U` of the circuit. For `n = 0` the circuit has no gates.
+    ///
+    /// As for [`inverse`](Self::inverse), the recorded measurements are not copied.
+    ///
+    /// ## Arguments
+    /// * `n` - The number of repetitions.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let squared = circuit.power(2);
+    /// assert_eq!(squared.num_qubits, 2);
+    /// assert_eq!(
+    ///     squared.gates,
+    ///     vec![QuantumGate::H(0), QuantumGate::CX(0, 1), QuantumGate::H(0), QuantumGate::CX(0, 1)]
+    /// );
+    /// assert!(circuit.power(0).gates.is_empty());
+    /// ```
+    pub fn power(&self, n: usize) -> QuantumCircuit {
+        QuantumCircuit {
+            num_qubits: self.num_qubits,
+            gates: self
+                .gates
+                .iter()
+                .cycle()
+                .take(self.gates.len() * n)
+                .cloned()
+                .collect(),
+            measurements: Vec::new(),
+        }
+    }
+
+    /// Creates the circuit that applies this circuit controlled on the qubit `control`, replacing
+    /// each gate by its controlled version as in [`QuantumGate::controlled`].
+    ///
+    /// If `control` is not a qubit of this circuit, the new circuit is extended to
+    /// `control + 1` qubits. As for [`inverse`](Self::inverse), the recorded measurements are not
+    /// copied.
+    ///
+    /// ## Arguments
+    /// * `control` - The control qubit index.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_x(0);
+    /// circuit.apply_cz(0, 1);
+    /// let controlled = circuit.control(2).unwrap();
+    /// assert_eq!(controlled.num_qubits, 3);
+    /// assert_eq!(
+    ///     controlled.gates,
+    ///     vec![QuantumGate::CX(2, 0), QuantumGate::CCZ(2, 0, 1)]
+    /// );
+    /// ```
+    /// ## Returns
+    /// A [`Result`] containing the controlled circuit, or an [`Error`](crate::error::Error) if
+    /// a gate acts on `control` or has no supported controlled version.
+    pub fn control(&self, control: usize) -> Result<QuantumCircuit> {
+        let mut gates = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            gates.extend(gate.controlled(control)?);
+        }
+        Ok(QuantumCircuit {
+            num_qubits: self.num_qubits.max(control + 1),
+            gates,
+            measurements: Vec::new(),
+        })
+    }
+
+    /// Creates the inverse of this circuit, which applies the inverses of the gates in the
+    /// reverse order.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(1);
+    /// let inverse = circuit.inverse();
+    /// assert_eq!(inverse.num_qubits, 2);
+    /// assert_eq!(inverse.gates, vec![QuantumGate::Tdg(1), QuantumGate::H(0)]);
+    /// ```
+    pub fn inverse(&self) -> QuantumCircuit {
+        QuantumCircuit {
+            num_qubits: self.num_qubits,
+            gates: self.gates.iter().rev().map(QuantumGate::inverse).collect(),
+            measurements: Vec::new(),
+        }
+    }
+
+    /// Conjugates a Pauli operator by the circuit in the Heisenberg picture, i.e. computes
+    /// `U† P U` for the unitary `U` of the circuit, without simulating any state.
+    ///
+    /// Since `U` is Clifford, `U† P U` is again a Pauli operator up to a sign.
+    ///
+    /// ## Arguments
+    /// * `pauli_string` - The Pauli operator `P`. It must act only on qubits of the circuit.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the dense [`PauliString`] of `num_qubits` qubits and the sign
+    /// (`1` or `-1`) such that `U† P U = sign · pauli`, or an [`Error`](crate::error::Error) if
+    /// the circuit contains a non-Clifford gate.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_cx(0, 1);
+    /// let (pauli, sign) = circuit
+    ///     .conjugate_pauli(&PauliString::from_str("Z1").unwrap())
+    ///     .unwrap();
+    /// assert_eq!(pauli.to_string(), "ZZ");
+    /// assert_eq!(sign, 1);
+    /// ```
+    pub fn conjugate_pauli(&self, pauli_string: &PauliString) -> Result<(PauliString, i8)> {
+        pauli_conjugation::conjugate_pauli(self, pauli_string)
+    }
+
+    /// Converts the circuit into a [`CliffordCircuit`] of the CH-form crate.
+    ///
+    /// This allows a Clifford-only circuit to be simulated with a single
+    /// [`StabilizerCHForm`](stabilizer_ch_form_rust::StabilizerCHForm) without the overhead of
+    /// the stabilizer decomposition. Measurements are not part of a [`CliffordCircuit`] and are
+    /// dropped.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the [`CliffordCircuit`] on `num_qubits` qubits, or
+    /// [`Error::GateNotClifford`] for the first gate that is not Clifford (e.g. `T` or `CCX`),
+    /// or an [`Error`] if a gate cannot act on the circuit (e.g. an out-of-range qubit index).
+    /// Clifford gates without a counterpart in [`CliffordCircuit`] (e.g. `ISwap`, `Clifford1`
+    /// or `RZ` with even `k`) are expanded with [`QuantumGate::decompose`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use stabilizer_ch_form_rust::StabilizerCHForm;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let clifford = circuit.to_clifford_circuit().unwrap();
+    /// let state = StabilizerCHForm::from_clifford_circuit(&clifford).unwrap();
+    /// assert_eq!(state.num_qubits(), 2);
+    ///
+    /// circuit.apply_t(1);
+    /// assert!(circuit.to_clifford_circuit().is_err());
+    /// ```
+    pub fn to_clifford_circuit(&self) -> Result<CliffordCircuit> {
+        let mut clifford = CliffordCircuit::new(self.num_qubits);
+        for gate in &self.gates {
+            gate.validate(self.num_qubits)?;
+            if !gate.is_clifford() {
+                return Err(Error::GateNotClifford(gate.name().to_string()));
+            }
+            for sub_gate in gate.decompose() {
+                clifford.gates.push(sub_gate.to_clifford_gate()?);
+            }
+        }
+        Ok(clifford)
+    }
+
     /// Creates a new circuit by taking the tensor product of this circuit and another.
     ///
     /// The new circuit will have `self.num_qubits() + other.num_qubits()` qubits.
@@ -267,6 +685,205 @@ impl QuantumCircuit {
         new_circuit
     }
 
+    /// Assigns each gate to a layer, i.e. the earliest time step at which it can be executed.
+    ///
+    /// A gate is placed one layer after the latest gate acting on any of its qubits, so gates
+    /// in the same layer act on disjoint qubits.
+    ///
+    /// ## Returns
+    /// The layer index of each gate, in the order of [`QuantumCircuit::gates`].
+    fn layer_indices(&self) -> Vec<usize> {
+        // The next free layer of each qubit
+        let mut next_layer: Vec<usize> = vec![0; self.num_qubits];
+        let mut layer_indices = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let qubits = gate.qubits();
+            let max_qubit = qubits.iter().copied().max().unwrap_or(0);
+            if max_qubit >= next_layer.len() {
+                next_layer.resize(max_qubit + 1, 0);
+            }
+            let layer = qubits.iter().map(|&q| next_layer[q]).max().unwrap_or(0);
+            for &q in &qubits {
+                next_layer[q] = layer + 1;
+            }
+            layer_indices.push(layer);
+        }
+        layer_indices
+    }
+
+    /// Groups the gates into layers of gates acting on disjoint qubits.
+    ///
+    /// Each gate is placed in the earliest layer after all preceding gates acting on the same
+    /// qubits, so executing the layers in order is equivalent to executing the circuit.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// circuit.apply_h(0);
+    /// circuit.apply_h(1);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_x(2);
+    /// let layers = circuit.layers();
+    /// assert_eq!(layers.len(), 2);
+    /// assert_eq!(layers[0], vec![&QuantumGate::H(0), &QuantumGate::H(1), &QuantumGate::X(2)]);
+    /// assert_eq!(layers[1], vec![&QuantumGate::CX(0, 1)]);
+    /// ```
+    pub fn layers(&self) -> Vec<Vec<&QuantumGate>> {
+        let mut layers: Vec<Vec<&QuantumGate>> = Vec::new();
+        for (layer, gate) in self.iter_with_layers() {
+            if layer == layers.len() {
+                layers.push(Vec::new());
+            }
+            layers[layer].push(gate);
+        }
+        layers
+    }
+
+    /// Returns the depth of the circuit, i.e. the number of [`layers`](Self::layers).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_t(1);
+    /// assert_eq!(circuit.depth(), 3);
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.layer_indices()
+            .into_iter()
+            .max()
+            .map_or(0, |layer| layer + 1)
+    }
+
+    /// Renders the circuit as a text diagram with one wire per qubit.
+    ///
+    /// Gates are placed in columns as in [`layers`](Self::layers), except that a multi-qubit
+    /// gate also occupies the wires crossed by its vertical line. Single-qubit gates are drawn
+    /// by name, controls as `●`, swapped qubits as `×` and the targets of controlled gates by
+    /// the name of the applied gate.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// println!("{}", circuit.draw());
+    /// // q0: ──H──●──
+    /// //          │
+    /// // q1: ─────X──
+    /// ```
+    pub fn draw(&self) -> String {
+        draw::draw(self)
+    }
+
+    /// Iterates over the gates in circuit order, each tagged with its layer index as assigned by
+    /// [`layers`](Self::layers).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_h(1);
+    /// circuit.apply_cx(0, 1);
+    /// let tagged: Vec<_> = circuit.iter_with_layers().collect();
+    /// assert_eq!(
+    ///     tagged,
+    ///     vec![(0, &QuantumGate::H(0)), (0, &QuantumGate::H(1)), (1, &QuantumGate::CX(0, 1))]
+    /// );
+    /// ```
+    pub fn iter_with_layers(&self) -> impl Iterator<Item = (usize, &QuantumGate)> {
+        self.layer_indices().into_iter().zip(self.gates.iter())
+    }
+
+    /// Returns the number of T-type gates in the circuit.
+    ///
+    /// Composite gates are counted by their decomposition (see [`QuantumGate::decompose`]),
+    /// e.g. `RZ(q, 1)` contributes one T-type gate and `CS` three.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_t(0);
+    /// circuit.apply_tdg(1);
+    /// circuit.apply_rz(0, 2);
+    /// circuit.apply_rz(1, 3);
+    /// assert_eq!(circuit.t_count(), 3);
+    /// ```
+    pub fn t_count(&self) -> usize {
+        self.gates
+            .iter()
+            .flat_map(QuantumGate::decompose)
+            .filter(QuantumGate::is_t_type_gate)
+            .count()
+    }
+
+    /// Counts the gates in the circuit by their [`name`](QuantumGate::name).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_h(1);
+    /// let counts = circuit.count_ops();
+    /// assert_eq!(counts["H"], 2);
+    /// assert_eq!(counts["CX"], 1);
+    /// assert_eq!(counts.len(), 2);
+    /// ```
+    pub fn count_ops(&self) -> HashMap<&'static str, usize> {
+        let mut counts = HashMap::new();
+        for gate in &self.gates {
+            *counts.entry(gate.name()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Creates a simplified copy of the circuit by applying local rewrite rules until no rule
+    /// applies anymore.
+    ///
+    /// The rules act on pairs of gates with no gate in between acting on their qubits:
+    /// - a gate followed by its inverse is removed (e.g. `X·X`, `H·H`, `CX·CX`, `S·Sdg`),
+    /// - `S·S` and `Sdg·Sdg` are merged into `Z`, `T·T` into `S` and `Tdg·Tdg` into `Sdg`,
+    ///
+    /// and identity gates (e.g. `RZ(q, 8)`) are dropped. The resulting circuit implements
+    /// exactly the same unitary, with possibly fewer T-type gates.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(1);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(1);
+    /// assert_eq!(circuit.simplify().gates, vec![QuantumGate::S(1)]);
+    /// ```
+    pub fn simplify(&self) -> QuantumCircuit {
+        let mut gates = self.gates.clone();
+        loop {
+            let simplified = simplify_pass(&gates);
+            if simplified == gates {
+                break;
+            }
+            gates = simplified;
+        }
+        QuantumCircuit {
+            num_qubits: self.num_qubits,
+            gates,
+            measurements: self.measurements.clone(),
+        }
+    }
+
     /// Generates a uniformly random n-qubit Clifford circuit.
     ///
     /// This function implements the O(n^2) algorithm described in the paper to sample a Clifford
@@ -290,14 +907,135 @@ impl QuantumCircuit {
         random_clifford::random_clifford(n, seed)
     }
 
+    /// Generates a random Clifford+T circuit.
+    ///
+    /// The circuit consists of `clifford_count` Clifford gates drawn uniformly from
+    /// `H`, `X`, `Y`, `Z`, `S`, `Sdg`, `SqrtX`, `SqrtXdg` (and `CX`, `CZ`, `Swap` for two or more
+    /// qubits) and `t_count` gates drawn from `T` and `Tdg`, shuffled into a random order and
+    /// acting on random qubits.
+    ///
+    /// ## Arguments
+    /// * `n_qubits` - The number of qubits. Must be greater than 0.
+    /// * `clifford_count` - The number of Clifford gates.
+    /// * `t_count` - The number of T-type gates.
+    /// * `seed` - An optional seed for the random number generator for reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
+    /// ## Returns
+    /// A [`QuantumCircuit`] object representing the random Clifford+T circuit.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// let circuit1 = QuantumCircuit::random_clifford_t(4, 20, 5, Some([7; 32]));
+    /// let circuit2 = QuantumCircuit::random_clifford_t(4, 20, 5, Some([7; 32]));
+    /// assert_eq!(circuit1.gates, circuit2.gates);
+    /// assert_eq!(circuit1.gates.len(), 25);
+    /// assert_eq!(circuit1.t_count(), 5);
+    /// ```
+    pub fn random_clifford_t(
+        n_qubits: usize,
+        clifford_count: usize,
+        t_count: usize,
+        seed: Option<[u8; 32]>,
+    ) -> QuantumCircuit {
+        random_clifford_t::random_clifford_t(n_qubits, clifford_count, t_count, seed)
+    }
+
+    /// Generates a circuit preparing the graph state of the given graph.
+    ///
+    /// The circuit applies `H` to every qubit, followed by `CZ` on each edge, so that the resulting
+    /// state is `Π_{(a,b) ∈ E} CZ_{ab} |+>^n`.
+    ///
+    /// ## Arguments
+    /// * `adjacency` - The edges of the graph as pairs of qubit indices.
+    /// * `n` - The number of qubits (vertices).
+    ///
+    /// ## Returns
+    /// A [`QuantumCircuit`] preparing the graph state.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    /// use necstar::circuit::QuantumGate;
+    /// // 3-qubit linear cluster state
+    /// let circuit = QuantumCircuit::graph_state(&[(0, 1), (1, 2)], 3);
+    /// assert_eq!(circuit.gates.len(), 5);
+    /// assert_eq!(circuit.gates[3], QuantumGate::CZ(0, 1));
+    /// assert_eq!(circuit.gates[4], QuantumGate::CZ(1, 2));
+    /// ```
+    pub fn graph_state(adjacency: &[(usize, usize)], n: usize) -> QuantumCircuit {
+        let mut circuit = QuantumCircuit::new(n);
+        for q in 0..n {
+            circuit.apply_h(q);
+        }
+        for &(a, b) in adjacency {
+            circuit.apply_cz(a, b);
+        }
+        circuit
+    }
+
     /// Parses an OpenQASM 2.0 string into a [`QuantumCircuit`].
     ///
+    /// The rotations `rz`, `rx`, `ry`, `p` and `u1` are supported for angles that are multiples
+    /// of π/4, written as arithmetic expressions of numbers and `pi` (e.g. `-3*pi/4`), and
+    /// mapped to [`QuantumGate::RZ`], [`QuantumGate::RX`], [`QuantumGate::RY`] and
+    /// [`QuantumGate::Phase`]. Measurements (`measure q[0] -> c[0];`) are collected into
+    /// [`measurements`](Self::measurements), with the bits of several classical registers
+    /// numbered consecutively in the order of declaration, and barriers are ignored. Other
+    /// unsupported statements and angles result in a
+    /// [`QasmParsingError`](crate::error::Error::QasmParsingError).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::circuit::QuantumCircuit;
+    ///
+    /// let qasm = "OPENQASM 2.0;
+    /// qreg q[2];
+    /// creg c[2];
+    /// h q[0];
+    /// cx q[0], q[1];
+    /// barrier q;
+    /// measure q[1] -> c[0];";
+    /// let circuit = QuantumCircuit::from_qasm_str(qasm).unwrap();
+    /// assert_eq!(circuit.gates.len(), 2);
+    /// assert_eq!(circuit.measurements, vec![(1, 0)]);
+    /// ```
+    ///
     /// ## Arguments
     /// * `qasm_str` - A string slice containing the OpenQASM 2.0 circuit description.
     pub fn from_qasm_str(qasm_str: &str) -> Result<Self> {
         parser::from_qasm_str(qasm_str)
     }
 
+    /// Parses an OpenQASM 3.0 string into a [`QuantumCircuit`].
+    ///
+    /// Only the basic subset is supported: a single `qubit[n]` declaration and the standard
+    /// gates (`h`, `x`, `y`, `z`, `s`, `sdg`, `sx`, `sxdg`, `t`, `tdg`, `cx`, `cz`, `swap`,
+    /// `ccx`, `cswap`) on indexed qubits, one statement per line. Measurements
+    /// (`c[0] = measure q[0];`) are collected into [`measurements`](Self::measurements) and
+    /// barriers are ignored, while control flow, `gate` definitions and other classical
+    /// constructs result in a [`QasmParsingError`](crate::error::Error::QasmParsingError).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::circuit::{QuantumCircuit, QuantumGate};
+    ///
+    /// let qasm = "OPENQASM 3.0;
+    /// include \"stdgates.inc\";
+    /// qubit[2] q;
+    /// h q[0];
+    /// cx q[0], q[1];";
+    /// let circuit = QuantumCircuit::from_qasm3_str(qasm).unwrap();
+    /// assert_eq!(circuit.gates, vec![QuantumGate::H(0), QuantumGate::CX(0, 1)]);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qasm_str` - A string slice containing the OpenQASM 3.0 circuit description.
+    pub fn from_qasm3_str(qasm_str: &str) -> Result<Self> {
+        parser::from_qasm3_str(qasm_str)
+    }
+
     /// Parses an OpenQASM 2.0 file into a [`QuantumCircuit`]
     ///
     /// ## Arguments
@@ -322,6 +1060,91 @@ impl QuantumCircuit {
     pub fn to_qasm_file<P: AsRef<Path>>(&self, path: P, reg_name: &str) -> Result<()> {
         parser::to_qasm_file(self, path, reg_name)
     }
+
+    /// Serializes the circuit to a JSON string. Requires the `serde` feature.
+    ///
+    /// The JSON object holds `num_qubits` and the ordered list `gates`, where each gate is an
+    /// object with the variant name of [`QuantumGate`] as its only key and the arguments as its
+    /// value (a single integer or an array), e.g.
+    /// `{"num_qubits":2,"gates":[{"H":0},{"CX":[0,1]},{"RZ":[1,3]}]}`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::QuantumCircuit;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_rz(1, 3);
+    ///
+    /// let json = circuit.to_json().unwrap();
+    /// assert_eq!(json, r#"{"num_qubits":2,"gates":[{"H":0},{"CX":[0,1]},{"RZ":[1,3]}]}"#);
+    /// assert_eq!(QuantumCircuit::from_json(&json).unwrap(), circuit);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserializes a circuit from a JSON string in the format of
+    /// [`to_json`](Self::to_json). Requires the `serde` feature.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the circuit, or an [`Error`](crate::error::Error) if the JSON is
+    /// malformed or a gate acts on a qubit not less than `num_qubits`.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Applies the rewrite rules of [`QuantumCircuit::simplify`] in a single sweep over the gates.
+// avoid let-chains for compatibility with older Rust toolchains
+#[allow(clippy::collapsible_if)]
+fn simplify_pass(gates: &[QuantumGate]) -> Vec<QuantumGate> {
+    let mut simplified: Vec<QuantumGate> = Vec::with_capacity(gates.len());
+    for gate in gates {
+        if gate.decompose().is_empty() {
+            continue;
+        }
+        let qubits = gate.qubits();
+        // The last gate acting on any of the qubits of `gate`
+        let previous = simplified
+            .iter()
+            .rposition(|other| other.qubits().iter().any(|q| qubits.contains(q)));
+        if let Some(idx) = previous {
+            if let Some(merged) = merge_gates(&simplified[idx], gate) {
+                match merged {
+                    Some(merged) => simplified[idx] = merged,
+                    None => {
+                        simplified.remove(idx);
+                    }
+                }
+                continue;
+            }
+        }
+        simplified.push(gate.clone());
+    }
+    simplified
+}
+
+/// Merges `first` followed by `second` into a single gate, or into the identity (`Some(None)`).
+/// Returns `None` if no rewrite rule applies.
+fn merge_gates(first: &QuantumGate, second: &QuantumGate) -> Option<Option<QuantumGate>> {
+    if first.qubits() != second.qubits() {
+        return None;
+    }
+    if first.inverse() == *second {
+        return Some(None);
+    }
+    match (first, second) {
+        (QuantumGate::S(q), QuantumGate::S(_)) | (QuantumGate::Sdg(q), QuantumGate::Sdg(_)) => {
+            Some(Some(QuantumGate::Z(*q)))
+        }
+        (QuantumGate::T(q), QuantumGate::T(_)) => Some(Some(QuantumGate::S(*q))),
+        (QuantumGate::Tdg(q), QuantumGate::Tdg(_)) => Some(Some(QuantumGate::Sdg(*q))),
+        _ => None,
+    }
 }
 
 impl fmt::Display for QuantumCircuit {
@@ -342,6 +1165,56 @@ impl fmt::Display for QuantumCircuit {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::random_circuit_with_t_gate;
+
+    #[test]
+    fn test_try_apply_gate() {
+        use crate::error::Error;
+
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.try_apply_gate(QuantumGate::H(2)).unwrap();
+        circuit.try_apply_gate(QuantumGate::CCX(0, 1, 2)).unwrap();
+        circuit
+            .try_apply_gate(QuantumGate::Clifford1(0, Clifford1Index::new(23).unwrap()))
+            .unwrap();
+
+        // Out-of-bounds indices of one-, two- and three-qubit gates
+        for gate in [
+            QuantumGate::H(3),
+            QuantumGate::CX(0, 3),
+            QuantumGate::CCZ(3, 0, 1),
+        ] {
+            assert!(matches!(
+                circuit.try_apply_gate(gate),
+                Err(Error::QubitIndexOutOfBounds(3, 3))
+            ));
+        }
+        // Duplicate indices of two- and three-qubit gates
+        for (gate, qubit) in [
+            (QuantumGate::CZ(1, 1), 1),
+            (QuantumGate::CCX(0, 2, 0), 0),
+            (QuantumGate::CSwap(0, 2, 2), 2),
+        ] {
+            match circuit.try_apply_gate(gate) {
+                Err(Error::DuplicateQubitIndex(q)) => assert_eq!(q, qubit),
+                other => panic!("Expected DuplicateQubitIndex, got {:?}", other),
+            }
+        }
+        assert!(matches!(
+            circuit.apply_clifford1(0, 24),
+            Err(Error::InvalidClifford1Index(24))
+        ));
+        assert_eq!(circuit.gates.len(), 3);
+
+        // try_apply_gates leaves the circuit unchanged on error
+        let result = circuit.try_apply_gates(&[QuantumGate::T(0), QuantumGate::Swap(2, 5)]);
+        assert!(matches!(result, Err(Error::QubitIndexOutOfBounds(5, 3))));
+        assert_eq!(circuit.gates.len(), 3);
+        circuit
+            .try_apply_gates(&[QuantumGate::T(0), QuantumGate::Swap(2, 1)])
+            .unwrap();
+        assert_eq!(circuit.gates.len(), 5);
+    }
 
     #[test]
     fn test_append_circuit() {
@@ -360,6 +1233,131 @@ mod tests {
         assert_eq!(circuit1.gates[2], QuantumGate::T(0));
     }
 
+    #[test]
+    fn test_iter_with_layers() {
+        let circuit = random_circuit_with_t_gate(5, 40, 10, Some(42));
+        let tagged: Vec<(usize, &QuantumGate)> = circuit.iter_with_layers().collect();
+
+        // Every gate appears exactly once, in circuit order
+        assert_eq!(tagged.len(), circuit.gates.len());
+        for ((_, gate), expected) in tagged.iter().zip(circuit.gates.iter()) {
+            assert!(std::ptr::eq(*gate, expected));
+        }
+
+        let max_layer = tagged.iter().map(|(layer, _)| *layer).max().unwrap();
+        assert_eq!(max_layer, circuit.depth() - 1);
+
+        // Gates in the same layer act on disjoint qubits
+        let layers = circuit.layers();
+        assert_eq!(layers.len(), circuit.depth());
+        assert_eq!(
+            layers.iter().map(|layer| layer.len()).sum::<usize>(),
+            circuit.gates.len()
+        );
+        for layer in &layers {
+            let mut qubits: Vec<usize> = layer.iter().flat_map(|gate| gate.qubits()).collect();
+            let total = qubits.len();
+            qubits.sort_unstable();
+            qubits.dedup();
+            assert_eq!(qubits.len(), total);
+        }
+
+        assert_eq!(QuantumCircuit::new(3).depth(), 0);
+        assert_eq!(QuantumCircuit::new(3).iter_with_layers().count(), 0);
+    }
+
+    #[test]
+    fn test_simplify() {
+        use crate::state::QuantumState;
+
+        // T·T -> S
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_t(0);
+        let simplified = circuit.simplify();
+        assert_eq!(circuit.t_count(), 2);
+        assert_eq!(simplified.t_count(), 0);
+        assert_eq!(simplified.gates, vec![QuantumGate::H(0), QuantumGate::S(0)]);
+        let expected = QuantumState::from_circuit(&circuit).unwrap();
+        let state = QuantumState::from_circuit(&simplified).unwrap();
+        assert!(state.approx_eq_with_phase(&expected, 1e-8).unwrap());
+
+        // T·T·T -> S·T
+        circuit.apply_t(0);
+        let simplified = circuit.simplify();
+        assert_eq!(circuit.t_count(), 3);
+        assert_eq!(simplified.t_count(), 1);
+        let expected = QuantumState::from_circuit(&circuit).unwrap();
+        let state = QuantumState::from_circuit(&simplified).unwrap();
+        assert!(state.approx_eq_with_phase(&expected, 1e-8).unwrap());
+
+        // Cancellations cascade, and S^4 reduces to the identity over several passes.
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_cx(1, 2);
+        circuit.apply_x(0);
+        circuit.apply_x(0);
+        circuit.apply_cx(1, 2);
+        circuit.apply_h(0);
+        for _ in 0..4 {
+            circuit.apply_s(2);
+        }
+        circuit.apply_rz(1, 8);
+        circuit.apply_z(1);
+        circuit.apply_z(1);
+        assert!(circuit.simplify().gates.is_empty());
+        assert_eq!(circuit.simplify().num_qubits, 3);
+
+        // Gates in between on a shared qubit block the rewrite.
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_t(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_t(0);
+        assert_eq!(circuit.simplify().gates, circuit.gates);
+
+        // Random circuits keep their statevector.
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(3, 40, 12, Some(seed));
+            let simplified = circuit.simplify();
+            assert!(simplified.t_count() <= circuit.t_count());
+            let expected = QuantumState::from_circuit(&circuit).unwrap();
+            let state = QuantumState::from_circuit(&simplified).unwrap();
+            assert!(state.approx_eq_with_phase(&expected, 1e-8).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_circuit_metrics() {
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_t(1);
+        circuit.apply_cx(0, 1);
+        assert_eq!(circuit.depth(), 4);
+        assert_eq!(circuit.t_count(), 1);
+        let counts = circuit.count_ops();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["H"], 1);
+        assert_eq!(counts["CX"], 2);
+        assert_eq!(counts["T"], 1);
+
+        // Multi-qubit gates occupy all their qubits in a layer.
+        let mut circuit = QuantumCircuit::new(4);
+        circuit.apply_ccx(0, 1, 2);
+        circuit.apply_h(3);
+        circuit.apply_tdg(0);
+        circuit.apply_cs(2, 3);
+        circuit.apply_rz(1, 1);
+        assert_eq!(circuit.depth(), 2);
+        assert_eq!(circuit.t_count(), 5);
+
+        let empty = QuantumCircuit::new(3);
+        assert_eq!(empty.depth(), 0);
+        assert_eq!(empty.t_count(), 0);
+        assert!(empty.count_ops().is_empty());
+    }
+
     #[test]
     fn test_tensor_circuit() {
         let mut circuit1 = QuantumCircuit::new(2);
@@ -377,6 +1375,452 @@ mod tests {
         assert_eq!(tensor_circuit.gates[2], QuantumGate::T(4));
     }
 
+    #[test]
+    fn test_rotation_gates() {
+        use crate::state::QuantumState;
+        use num_complex::Complex64;
+
+        // Prepares T H |0> as a generic input state, then applies the rotation.
+        let prepare = |gate: QuantumGate| {
+            let mut circuit = QuantumCircuit::new(1);
+            circuit.apply_h(0);
+            circuit.apply_t(0);
+            circuit.apply_gate(gate);
+            QuantumState::from_circuit(&circuit)
+                .unwrap()
+                .to_statevector()
+                .unwrap()
+        };
+        let amp = 1.0 / 2f64.sqrt();
+        let input = [
+            Complex64::new(amp, 0.0),
+            Complex64::from_polar(amp, std::f64::consts::FRAC_PI_4),
+        ];
+
+        let i = Complex64::new(0.0, 1.0);
+        for k in -8..=16 {
+            // exp(-iθ/2 P) = cos(θ/2) I - i sin(θ/2) P with θ = kπ/4
+            let half = k as f64 * std::f64::consts::PI / 8.0;
+            let (c, s) = (
+                Complex64::new(half.cos(), 0.0),
+                Complex64::new(half.sin(), 0.0),
+            );
+            let matrices = [
+                (
+                    QuantumGate::RZ(0, k),
+                    [[c - i * s, 0.0.into()], [0.0.into(), c + i * s]],
+                ),
+                (QuantumGate::RX(0, k), [[c, -i * s], [-i * s, c]]),
+                (QuantumGate::RY(0, k), [[c, -s], [s, c]]),
+            ];
+            for (gate, m) in matrices {
+                let expected = [
+                    m[0][0] * input[0] + m[0][1] * input[1],
+                    m[1][0] * input[0] + m[1][1] * input[1],
+                ];
+                let actual = prepare(gate.clone());
+                assert_eq!(gate.is_clifford(), k % 2 == 0);
+
+                // Equal up to a global phase
+                let overlap: Complex64 = expected
+                    .iter()
+                    .zip(actual.iter())
+                    .map(|(e, a)| e.conj() * a)
+                    .sum();
+                assert!(
+                    (overlap.norm() - 1.0).abs() < 1e-10,
+                    "{} differs from the rotation matrix",
+                    gate
+                );
+            }
+        }
+
+        // Minimal decompositions
+        assert_eq!(QuantumGate::RZ(0, 1).decompose(), vec![QuantumGate::T(0)]);
+        assert_eq!(QuantumGate::RZ(0, 2).decompose(), vec![QuantumGate::S(0)]);
+        assert_eq!(QuantumGate::RZ(0, 4).decompose(), vec![QuantumGate::Z(0)]);
+        assert_eq!(QuantumGate::RZ(0, 7).decompose(), vec![QuantumGate::Tdg(0)]);
+        assert_eq!(
+            QuantumGate::RZ(0, -2).decompose(),
+            vec![QuantumGate::Sdg(0)]
+        );
+        assert!(QuantumGate::RX(0, 16).decompose().is_empty());
+
+        // Clifford rotations can be applied to a state directly
+        let mut state = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
+        state.apply_gate(&QuantumGate::RY(0, 2)).unwrap();
+        let sv = state.to_statevector().unwrap();
+        assert!((sv[0].norm() - amp).abs() < 1e-10 && (sv[1].norm() - amp).abs() < 1e-10);
+        assert!(state.apply_gate(&QuantumGate::RZ(0, 1)).is_err());
+    }
+
+    #[test]
+    fn test_phase_gate() {
+        use crate::state::QuantumState;
+        use crate::test_utils::assert_eq_complex_array1;
+
+        let statevector = |gates: &[QuantumGate]| {
+            let mut circuit = QuantumCircuit::new(2);
+            circuit.apply_h(0);
+            circuit.apply_cx(0, 1);
+            circuit.apply_sqrt_x(1);
+            circuit.apply_gates(gates);
+            QuantumState::from_circuit(&circuit)
+                .unwrap()
+                .to_statevector()
+                .unwrap()
+        };
+
+        // Phase(q, 1) equals T exactly, including the global phase.
+        assert_eq_complex_array1(
+            &statevector(&[QuantumGate::Phase(1, 1)]),
+            &statevector(&[QuantumGate::T(1)]),
+        );
+        // Phase(q, k) = T^k
+        for k in -9..=17i64 {
+            let t_gates = vec![QuantumGate::T(0); k.rem_euclid(8) as usize];
+            assert_eq_complex_array1(
+                &statevector(&[QuantumGate::Phase(0, k)]),
+                &statevector(&t_gates),
+            );
+        }
+
+        // k ≡ 0: identity, k ≡ 4: Z
+        assert!(QuantumGate::Phase(0, 8).decompose().is_empty());
+        assert!(QuantumGate::Phase(0, 0).is_clifford());
+        assert_eq!(
+            QuantumGate::Phase(0, -4).decompose(),
+            vec![QuantumGate::Z(0)]
+        );
+        assert!(QuantumGate::Phase(0, 4).is_clifford());
+        assert!(!QuantumGate::Phase(0, 3).is_clifford());
+    }
+
+    #[test]
+    fn test_iswap_gate() {
+        use crate::state::QuantumState;
+        use crate::test_utils::assert_eq_complex_array1;
+        use ndarray::Array1;
+        use num_complex::Complex64;
+
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+        // iSWAP in the computational basis (|q1 q0>, little endian)
+        let iswap = [
+            [one, zero, zero, zero],
+            [zero, zero, i, zero],
+            [zero, i, zero, zero],
+            [zero, zero, zero, one],
+        ];
+
+        // Bell-like input with non-trivial relative phases
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_s(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_h(1);
+        circuit.apply_t(1);
+        let input = QuantumState::from_circuit(&circuit)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        let expected: Array1<Complex64> = (0..4)
+            .map(|row| (0..4).map(|col| iswap[row][col] * input[col]).sum())
+            .collect();
+
+        for (a, b) in [(0, 1), (1, 0)] {
+            let mut circuit_iswap = QuantumCircuit::new(2);
+            circuit_iswap.apply_gates(&circuit.gates);
+            circuit_iswap.apply_iswap(a, b);
+            assert!(circuit_iswap.gates.last().unwrap().is_clifford());
+            let state = QuantumState::from_circuit(&circuit_iswap).unwrap();
+            assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+        }
+
+        // Direct application to a state
+        let mut state = QuantumState::from_circuit(&circuit).unwrap();
+        state.apply_gate(&QuantumGate::ISwap(0, 1)).unwrap();
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_clifford1_gates() {
+        use crate::state::QuantumState;
+
+        // The state (U ⊗ I)|Bell> determines U up to a global phase.
+        let choi_state = |indices: &[u8]| {
+            let mut circuit = QuantumCircuit::new(2);
+            circuit.apply_h(0);
+            circuit.apply_cx(0, 1);
+            for &idx in indices {
+                circuit.apply_clifford1(0, idx).unwrap();
+            }
+            QuantumState::from_circuit(&circuit).unwrap()
+        };
+
+        // All 24 gates are Clifford and distinct up to a global phase.
+        let states: Vec<QuantumState> = (0..24).map(|idx| choi_state(&[idx])).collect();
+        for idx in 0..24 {
+            assert!(QuantumGate::Clifford1(0, Clifford1Index::new(idx).unwrap()).is_clifford());
+            for other in 0..idx {
+                assert!(
+                    !states[idx as usize]
+                        .approx_eq(&states[other as usize], 1e-8)
+                        .unwrap()
+                );
+            }
+        }
+        assert!(
+            states[0]
+                .approx_eq_with_phase(&choi_state(&[]), 1e-8)
+                .unwrap()
+        );
+
+        // Every gate has an inverse in the enumeration which brings |0> back.
+        let identity = choi_state(&[]);
+        for idx in 0..24 {
+            let inverse = (0..24)
+                .find(|&inv| choi_state(&[idx, inv]).approx_eq(&identity, 1e-8).unwrap())
+                .unwrap();
+            let mut circuit = QuantumCircuit::new(1);
+            circuit.apply_clifford1(0, idx).unwrap();
+            circuit.apply_clifford1(0, inverse).unwrap();
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let zero = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
+            assert!(state.approx_eq(&zero, 1e-8).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_conjugate_pauli() {
+        use crate::error::Error;
+        use crate::state::QuantumState;
+        use std::str::FromStr;
+
+        let conjugate = |circuit: &QuantumCircuit, pauli: &str| {
+            let (pauli, sign) = circuit
+                .conjugate_pauli(&PauliString::from_str(pauli).unwrap())
+                .unwrap();
+            (pauli.to_string(), sign)
+        };
+
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        assert_eq!(conjugate(&circuit, "Z"), ("X".to_string(), 1));
+        assert_eq!(conjugate(&circuit, "Y"), ("Y".to_string(), -1));
+
+        // CX(0, 1): Z on the target picks up Z on the control, X on the control spreads to the
+        // target, and Z on the control is unchanged.
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_cx(0, 1);
+        assert_eq!(conjugate(&circuit, "Z1"), ("ZZ".to_string(), 1));
+        assert_eq!(conjugate(&circuit, "X0"), ("XX".to_string(), 1));
+        assert_eq!(conjugate(&circuit, "Z0"), ("IZ".to_string(), 1));
+
+        // S† X S = -Y
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_s(0);
+        assert_eq!(conjugate(&circuit, "X"), ("Y".to_string(), -1));
+
+        // <ψ|U† P U|ψ> = <Uψ|P|Uψ> for random Clifford circuits and states
+        for seed in 0..5u8 {
+            let prep = QuantumCircuit::random_clifford(3, Some([seed; 32]));
+            let mut circuit = QuantumCircuit::random_clifford(3, Some([seed + 100; 32]));
+            circuit.apply_iswap(0, 2);
+            circuit.apply_clifford1(1, 13).unwrap();
+            circuit.apply_rx(2, 2);
+            let mut full = QuantumCircuit::new(3);
+            full.append(&prep);
+            full.append(&circuit);
+            let state = QuantumState::from_circuit(&prep).unwrap();
+            let evolved = QuantumState::from_circuit(&full).unwrap();
+            for pauli in ["XYZ", "ZIZ", "Y1", "XXI"] {
+                let pauli = PauliString::from_str(pauli).unwrap();
+                let (conjugated, sign) = circuit.conjugate_pauli(&pauli).unwrap();
+                let expected = evolved.exp_value(&pauli).unwrap();
+                let found = sign as f64 * state.exp_value(&conjugated).unwrap();
+                assert!((expected - found).abs() < 1e-10);
+            }
+        }
+
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_t(1);
+        assert!(matches!(
+            circuit.conjugate_pauli(&PauliString::from_str("Z0").unwrap()),
+            Err(Error::GateNotClifford(_))
+        ));
+    }
+
+    #[test]
+    fn test_inverse() {
+        use crate::state::QuantumState;
+        use crate::test_utils::assert_eq_complex_array1;
+        use ndarray::Array1;
+        use num_complex::Complex64;
+
+        let mut zero = Array1::zeros(1 << 4);
+        zero[0] = Complex64::new(1.0, 0.0);
+        for seed in 0..5 {
+            let mut circuit = random_circuit_with_t_gate(4, 30, 6, Some(seed));
+            let inverse = circuit.inverse();
+            assert_eq!(inverse.num_qubits, 4);
+            assert_eq!(inverse.gates.len(), circuit.gates.len());
+            circuit.append(&inverse);
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            assert_eq_complex_array1(&state.to_statevector().unwrap(), &zero);
+        }
+
+        // Every kind of gate
+        let gates = vec![
+            QuantumGate::H(0),
+            QuantumGate::S(1),
+            QuantumGate::SqrtX(2),
+            QuantumGate::SqrtXdg(3),
+            QuantumGate::T(0),
+            QuantumGate::CX(0, 1),
+            QuantumGate::ISwap(1, 2),
+            QuantumGate::Clifford1(3, Clifford1Index::new(13).unwrap()),
+            QuantumGate::Phase(2, 3),
+            QuantumGate::RZ(0, 5),
+            QuantumGate::RX(1, -1),
+            QuantumGate::RY(2, 3),
+            QuantumGate::CS(0, 3),
+            QuantumGate::CCX(0, 1, 2),
+            QuantumGate::CCZ(1, 2, 3),
+            QuantumGate::CSwap(3, 0, 2),
+        ];
+        let mut circuit = QuantumCircuit::new(4);
+        for q in 0..4 {
+            circuit.apply_h(q);
+        }
+        circuit.apply_gates(&gates);
+        for gate in &gates {
+            assert_eq!(gate.inverse().inverse(), *gate);
+        }
+        let input = QuantumState::from_circuit(&circuit).unwrap();
+        circuit.append(&circuit.inverse());
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let zero = QuantumState::from_circuit(&QuantumCircuit::new(4)).unwrap();
+        assert!(!input.approx_eq(&zero, 1e-8).unwrap());
+        assert!(state.approx_eq(&zero, 1e-8).unwrap());
+    }
+
+    #[test]
+    fn test_cs_gate() {
+        use crate::state::QuantumState;
+        use crate::test_utils::assert_eq_complex_array1;
+        use ndarray::Array1;
+        use num_complex::Complex64;
+
+        let gate = QuantumGate::CS(0, 1);
+        assert!(!gate.is_clifford());
+        assert_eq!(gate.qubits(), vec![0, 1]);
+
+        // CS |++> = (|00> + |01> + |10> + i|11>) / 2
+        for (a, b) in [(0, 1), (1, 0)] {
+            let mut circuit = QuantumCircuit::new(2);
+            circuit.apply_h(0);
+            circuit.apply_h(1);
+            circuit.apply_cs(a, b);
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let expected = Array1::from(vec![
+                Complex64::new(0.5, 0.0),
+                Complex64::new(0.5, 0.0),
+                Complex64::new(0.5, 0.0),
+                Complex64::new(0.0, 0.5),
+            ]);
+            assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+        }
+
+        // |11> picks up the phase i
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_x(0);
+        circuit.apply_x(1);
+        circuit.apply_cs(0, 1);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let mut expected = Array1::zeros(4);
+        expected[3] = Complex64::new(0.0, 1.0);
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_cswap_gate() {
+        use crate::state::QuantumState;
+        use crate::test_utils::assert_eq_complex_array1;
+        use ndarray::Array1;
+        use num_complex::Complex64;
+
+        assert_eq!(
+            QuantumGate::CSwap(2, 0, 1).decompose(),
+            vec![
+                QuantumGate::CX(1, 0),
+                QuantumGate::CCX(2, 0, 1),
+                QuantumGate::CX(1, 0),
+            ]
+        );
+
+        for control in [false, true] {
+            // Targets: qubit 0 in |1>, qubit 1 in T|+>; control: qubit 2
+            let mut circuit = QuantumCircuit::new(3);
+            circuit.apply_x(0);
+            circuit.apply_h(1);
+            circuit.apply_t(1);
+            if control {
+                circuit.apply_x(2);
+            }
+            let input = QuantumState::from_circuit(&circuit)
+                .unwrap()
+                .to_statevector()
+                .unwrap();
+            // Swapping qubits 0 and 1 permutes the basis states accordingly.
+            let expected: Array1<Complex64> = (0..8)
+                .map(|i: usize| {
+                    if control {
+                        let (b0, b1) = (i & 1, (i >> 1) & 1);
+                        input[(i & 0b100) | (b0 << 1) | b1]
+                    } else {
+                        input[i]
+                    }
+                })
+                .collect();
+
+            circuit.apply_cswap(2, 0, 1);
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_graph_state() {
+        use crate::state::QuantumState;
+        use crate::test_utils::assert_eq_complex_array1;
+
+        let circuit = QuantumCircuit::graph_state(&[(0, 1)], 2);
+        assert_eq!(
+            circuit.gates,
+            vec![QuantumGate::H(0), QuantumGate::H(1), QuantumGate::CZ(0, 1)]
+        );
+
+        // The 2-qubit graph state equals a Bell state up to H on qubit 1.
+        let mut graph_state = QuantumState::graph_state(&[(0, 1)], 2).unwrap();
+        assert_eq!(graph_state.stabilizer_rank(), 1);
+        graph_state.apply_h(1).unwrap();
+
+        let mut bell_circuit = QuantumCircuit::new(2);
+        bell_circuit.apply_h(0);
+        bell_circuit.apply_cx(0, 1);
+        let bell_state = QuantumState::from_circuit(&bell_circuit).unwrap();
+
+        assert_eq_complex_array1(
+            &graph_state.to_statevector().unwrap(),
+            &bell_state.to_statevector().unwrap(),
+        );
+
+        assert!(QuantumState::graph_state(&[(0, 2)], 2).is_err());
+    }
+
     #[test]
     fn test_quantum_circuit_display() {
         let mut circuit = QuantumCircuit::new(2);
@@ -388,4 +1832,268 @@ mod tests {
         let expected_str = "QuantumCircuit(num_qubits=2) [X(0), CZ(0, 1), Tdg(1)]";
         assert_eq!(display_str, expected_str);
     }
+
+    #[test]
+    fn test_power() {
+        use crate::state::QuantumState;
+
+        // (T ⊗ Z)^2 = S ⊗ I and (T ⊗ Z)^8 = I
+        let mut t = QuantumCircuit::new(2);
+        t.apply_t(0);
+        t.apply_z(1);
+        assert_eq!(t.power(2).gates.len(), 2 * t.gates.len());
+        assert_eq!(t.power(1).gates, t.gates);
+        let empty = t.power(0);
+        assert_eq!(empty.num_qubits, 2);
+        assert!(empty.gates.is_empty());
+
+        let mut prep = QuantumCircuit::new(2);
+        prep.apply_h(0);
+        prep.apply_h(1);
+        let statevector = |power: usize| {
+            let mut circuit = QuantumCircuit::new(2);
+            circuit.append(&prep);
+            circuit.append(&t.power(power));
+            QuantumState::from_circuit(&circuit)
+                .unwrap()
+                .to_statevector()
+                .unwrap()
+        };
+
+        let mut s = QuantumCircuit::new(2);
+        s.append(&prep);
+        s.apply_s(0);
+        let expected_s = QuantumState::from_circuit(&s)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        for (a, b) in statevector(2).iter().zip(expected_s.iter()) {
+            assert!((a - b).norm() < 1e-10);
+        }
+        let expected_identity = QuantumState::from_circuit(&prep)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        for power in [0, 8, 16] {
+            for (a, b) in statevector(power).iter().zip(expected_identity.iter()) {
+                assert!((a - b).norm() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compose() {
+        use crate::state::QuantumState;
+
+        let mut cx = QuantumCircuit::new(2);
+        cx.apply_cx(0, 1);
+
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_x(2);
+        circuit.compose(&cx, &[2, 0]).unwrap();
+        assert_eq!(
+            circuit.gates,
+            vec![QuantumGate::X(2), QuantumGate::CX(2, 0)]
+        );
+        // |100> -> |101>
+        let statevector = QuantumState::from_circuit(&circuit)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        assert!((statevector[0b101].norm() - 1.0).abs() < 1e-10);
+
+        assert!(matches!(
+            circuit.compose(&cx, &[2]),
+            Err(Error::QubitCountMismatch {
+                operation: "compose",
+                left: 1,
+                right: 2
+            })
+        ));
+        assert!(matches!(
+            circuit.compose(&cx, &[0, 3]),
+            Err(Error::QubitIndexOutOfBounds(3, 3))
+        ));
+        assert!(matches!(
+            circuit.compose(&cx, &[1, 1]),
+            Err(Error::DuplicateQubitIndex(1))
+        ));
+        assert_eq!(circuit.gates.len(), 2);
+    }
+
+    #[test]
+    fn test_control() {
+        use crate::state::QuantumState;
+
+        let statevector = |circuit: &QuantumCircuit| {
+            QuantumState::from_circuit(circuit)
+                .unwrap()
+                .to_statevector()
+                .unwrap()
+        };
+        // Runs `body` after preparing the basis state `input` on `num_qubits` qubits.
+        let on_input = |num_qubits: usize, input: usize, body: &QuantumCircuit| {
+            let mut circuit = QuantumCircuit::new(num_qubits);
+            for q in 0..num_qubits {
+                if (input >> q) & 1 == 1 {
+                    circuit.apply_x(q);
+                }
+            }
+            circuit.append(body);
+            statevector(&circuit)
+        };
+
+        let mut x = QuantumCircuit::new(2);
+        x.apply_x(0);
+        let controlled = x.control(1).unwrap();
+        assert_eq!(controlled.gates, vec![QuantumGate::CX(1, 0)]);
+        let mut cx = QuantumCircuit::new(2);
+        cx.apply_cx(1, 0);
+        for input in 0..4 {
+            let actual = on_input(2, input, &controlled);
+            let expected = on_input(2, input, &cx);
+            for (a, b) in actual.iter().zip(expected.iter()) {
+                assert!((a - b).norm() < 1e-10);
+            }
+        }
+
+        // A 1-qubit circuit is extended to hold the control.
+        let mut single = QuantumCircuit::new(1);
+        single.apply_h(0);
+        single.apply_y(0);
+        single.apply_s(0);
+        let controlled = single.control(1).unwrap();
+        assert_eq!(controlled.num_qubits, 2);
+        for input in 0..4 {
+            let actual = on_input(2, input, &controlled);
+            let mut expected_body = QuantumCircuit::new(2);
+            if input >> 1 == 1 {
+                expected_body.append(&single);
+            }
+            let expected = on_input(2, input, &expected_body);
+            for (a, b) in actual.iter().zip(expected.iter()) {
+                assert!((a - b).norm() < 1e-10);
+            }
+        }
+
+        assert!(matches!(x.control(0), Err(Error::DuplicateQubitIndex(0))));
+        let mut t = QuantumCircuit::new(1);
+        t.apply_t(0);
+        assert!(matches!(t.control(1), Err(Error::GateNotControllable(_))));
+        let mut ccx = QuantumCircuit::new(3);
+        ccx.apply_ccx(0, 1, 2);
+        assert!(matches!(ccx.control(3), Err(Error::GateNotControllable(_))));
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let build = |last: QuantumGate| {
+            let mut circuit = QuantumCircuit::new(3);
+            circuit.apply_h(0);
+            circuit.apply_cx(0, 1);
+            circuit.apply_gate(QuantumGate::RZ(2, 3));
+            circuit.apply_gate(last);
+            circuit
+        };
+        let a = build(QuantumGate::T(2));
+        let b = build(QuantumGate::T(2));
+        let c = build(QuantumGate::Tdg(2));
+
+        let hasher = RandomState::new();
+        assert_eq!(a, b);
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+        assert_ne!(a, c);
+        assert_ne!(hasher.hash_one(&a), hasher.hash_one(&c));
+
+        let mut cache = HashMap::new();
+        cache.insert(a, 1);
+        assert_eq!(cache.get(&b), Some(&1));
+        assert_eq!(cache.get(&c), None);
+    }
+
+    #[test]
+    fn test_to_clifford_circuit() {
+        use stabilizer_ch_form_rust::StabilizerCHForm;
+        use stabilizer_ch_form_rust::circuit::CliffordGate;
+
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_sqrt_xdg(2);
+        circuit.apply_swap(1, 2);
+        circuit.apply_cz(0, 2);
+        let clifford = circuit.to_clifford_circuit().unwrap();
+        assert_eq!(clifford.num_qubits, 3);
+        assert_eq!(
+            clifford.gates,
+            vec![
+                CliffordGate::H(0),
+                CliffordGate::CX(0, 1),
+                CliffordGate::SqrtXdg(2),
+                CliffordGate::Swap(1, 2),
+                CliffordGate::CZ(0, 2),
+            ]
+        );
+
+        let expected = crate::state::QuantumState::from_circuit(&circuit)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        let actual = StabilizerCHForm::from_clifford_circuit(&clifford)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        for (a, b) in actual.iter().zip(expected.iter()) {
+            assert!((a - b).norm() < 1e-10);
+        }
+
+        // Clifford gates which are expanded into basic ones
+        let mut circuit = QuantumCircuit::new(3);
+        for q in 0..3 {
+            circuit.apply_h(q);
+        }
+        circuit.apply_iswap(0, 1);
+        circuit.apply_iswapdg(2, 0);
+        circuit.apply_clifford1(1, 17).unwrap();
+        circuit.apply_rz(2, 2);
+        circuit.apply_rx(0, 4);
+        circuit.apply_ry(1, -2);
+        circuit.apply_phase(2, 6);
+        circuit.apply_cx(1, 2);
+        let clifford = circuit.to_clifford_circuit().unwrap();
+        let expected = crate::state::QuantumState::from_circuit(&circuit)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        let actual = StabilizerCHForm::from_clifford_circuit(&clifford)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        for (a, b) in actual.iter().zip(expected.iter()) {
+            assert!((a - b).norm() < 1e-10);
+        }
+
+        circuit.apply_t(1);
+        circuit.apply_ccx(0, 1, 2);
+        assert!(matches!(
+            circuit.to_clifford_circuit(),
+            Err(Error::GateNotClifford(name)) if name == "T"
+        ));
+        // The error names the gate of the circuit, not a gate of its decomposition.
+        for (gate, expected) in [
+            (QuantumGate::RZ(0, 1), "RZ"),
+            (QuantumGate::Phase(0, 3), "Phase"),
+            (QuantumGate::CS(0, 1), "CS"),
+        ] {
+            let mut circuit = QuantumCircuit::new(2);
+            circuit.apply_h(0);
+            circuit.apply_gate(gate);
+            assert!(matches!(
+                circuit.to_clifford_circuit(),
+                Err(Error::GateNotClifford(name)) if name == expected
+            ));
+        }
+    }
 }
@@ -7,58 +7,153 @@ use std::fs;
 use std::io::Write;
 use std::path::Path;
 
+type Gate1Fn = fn(usize) -> QuantumGate;
+type Gate2Fn = fn(usize, usize) -> QuantumGate;
+type Gate3Fn = fn(usize, usize, usize) -> QuantumGate;
+type RotationFn = fn(usize, i64) -> QuantumGate;
+
+lazy_static::lazy_static! {
+    static ref QREG_RE: Regex = Regex::new(r"qreg\s+([a-zA-Z][a-zA-Z0-9_]*)\s*\[\s*(\d+)\s*\]\s*;").unwrap();
+    static ref QUBIT_RE: Regex = Regex::new(r"^qubit\s*(?:\[\s*(\d+)\s*\])?\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*;$").unwrap();
+    static ref BIT_RE: Regex = Regex::new(r"^bit\s*(?:\[\s*(\d+)\s*\])?\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*;$").unwrap();
+    static ref CREG_RE: Regex = Regex::new(r"^creg\s+([a-zA-Z_][a-zA-Z0-9_]*)\s*\[\s*(\d+)\s*\]\s*;$").unwrap();
+    // measure q[0] -> c[0];
+    static ref MEASURE_RE: Regex = Regex::new(r"^measure\s+[a-zA-Z_][a-zA-Z0-9_]*\s*(?:\[\s*(\d+)\s*\])?\s*->\s*([a-zA-Z_][a-zA-Z0-9_]*)\s*(?:\[\s*(\d+)\s*\])?\s*;$").unwrap();
+    // c[0] = measure q[0];
+    static ref MEASURE_ASSIGN_RE: Regex = Regex::new(r"^([a-zA-Z_][a-zA-Z0-9_]*)\s*(?:\[\s*(\d+)\s*\])?\s*=\s*measure\s+[a-zA-Z_][a-zA-Z0-9_]*\s*(?:\[\s*(\d+)\s*\])?\s*;$").unwrap();
+    static ref ROTATION_RE: Regex = Regex::new(r"^([a-z_0-9]+)\s*\((.*)\)\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;$").unwrap();
+    static ref GATE1_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+    static ref GATE2_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+    static ref GATE3_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
+
+    static ref SINGLE_QUBIT_GATES: HashMap<&'static str, Gate1Fn> = {
+        let mut m = HashMap::new();
+        m.insert("h", QuantumGate::H as fn(usize) -> QuantumGate);
+        m.insert("x", QuantumGate::X as fn(usize) -> QuantumGate);
+        m.insert("y", QuantumGate::Y as fn(usize) -> QuantumGate);
+        m.insert("z", QuantumGate::Z as fn(usize) -> QuantumGate);
+        m.insert("s", QuantumGate::S as fn(usize) -> QuantumGate);
+        m.insert("sdg", QuantumGate::Sdg as fn(usize) -> QuantumGate);
+        m.insert("sx", QuantumGate::SqrtX as fn(usize) -> QuantumGate);
+        m.insert("sxdg", QuantumGate::SqrtXdg as fn(usize) -> QuantumGate);
+        m.insert("t", QuantumGate::T as Gate1Fn);
+        m.insert("tdg", QuantumGate::Tdg as fn(usize) -> QuantumGate);
+        m
+    };
+
+    /// Single-qubit gates parametrized by an angle, which must be a multiple of π/4
+    static ref ROTATION_GATES: HashMap<&'static str, RotationFn> = {
+        let mut m = HashMap::new();
+        m.insert("rz", QuantumGate::RZ as RotationFn);
+        m.insert("rx", QuantumGate::RX as RotationFn);
+        m.insert("ry", QuantumGate::RY as RotationFn);
+        m.insert("p", QuantumGate::Phase as RotationFn);
+        m.insert("u1", QuantumGate::Phase as RotationFn);
+        m
+    };
+
+    static ref TWO_QUBIT_GATES: HashMap<&'static str, Gate2Fn> = {
+        let mut m = HashMap::new();
+        m.insert("cx", QuantumGate::CX as fn(usize, usize) -> QuantumGate);
+        m.insert("cz", QuantumGate::CZ as fn(usize, usize) -> QuantumGate);
+        m.insert("swap", QuantumGate::Swap as fn(usize, usize) -> QuantumGate);
+        m
+    };
+
+    static ref THREE_QUBIT_GATES: HashMap<&'static str, Gate3Fn> = {
+        let mut m = HashMap::new();
+        m.insert("ccx", QuantumGate::CCX as fn(usize, usize, usize) -> QuantumGate);
+        m.insert("cswap", QuantumGate::CSwap as fn(usize, usize, usize) -> QuantumGate);
+        m
+    };
+}
+
+/// OpenQASM 3.0 keywords of classical and control-flow constructs the parser does not support.
+const UNSUPPORTED_QASM3_KEYWORDS: [&str; 14] = [
+    "if", "else", "for", "while", "switch", "gate", "def", "defcal", "cal", "box", "reset",
+    "input", "output", "const",
+];
+
+/// The classical registers declared so far, whose bits are numbered consecutively in the order
+/// of declaration.
+#[derive(Default)]
+struct ClassicalRegisters {
+    /// The offset and size of each register
+    registers: HashMap<String, (usize, usize)>,
+    num_clbits: usize,
+}
+
+impl ClassicalRegisters {
+    fn declare(&mut self, name: &str, size: usize) -> Result<()> {
+        if self.registers.contains_key(name) {
+            return Err(Error::QasmParsingError(format!(
+                "Classical register '{}' is declared more than once.",
+                name
+            )));
+        }
+        self.registers
+            .insert(name.to_string(), (self.num_clbits, size));
+        self.num_clbits += size;
+        Ok(())
+    }
+
+    /// Resolves the measurement targets of a measurement statement to `(qubit, clbit)` pairs.
+    /// Without indices, the whole quantum register is measured into the classical register.
+    fn measurements(
+        &self,
+        qubit: Option<usize>,
+        register: &str,
+        clbit: Option<usize>,
+        num_qubits: Option<usize>,
+        line: &str,
+    ) -> Result<Vec<(usize, usize)>> {
+        let num_qubits = num_qubits.ok_or_else(|| {
+            Error::QasmParsingError(format!("Measurement before qubit declaration: {}", line))
+        })?;
+        let &(offset, size) = self.registers.get(register).ok_or_else(|| {
+            Error::QasmParsingError(format!(
+                "Undeclared classical register '{}' in line: {}",
+                register, line
+            ))
+        })?;
+        match (qubit, clbit) {
+            (Some(qubit), Some(clbit)) if qubit < num_qubits && clbit < size => {
+                Ok(vec![(qubit, offset + clbit)])
+            }
+            (None, None) if size == num_qubits => {
+                Ok((0..num_qubits).map(|q| (q, offset + q)).collect())
+            }
+            _ => Err(Error::QasmParsingError(format!(
+                "Invalid measurement targets in line: {}",
+                line
+            ))),
+        }
+    }
+}
+
+fn parse_optional_index(index: Option<regex::Match<'_>>, line: &str) -> Result<Option<usize>> {
+    index
+        .map(|index| parse_qubit_index(index.as_str(), line))
+        .transpose()
+}
+
 /// Parses an OpenQASM 2.0 string into a [`QuantumCircuit`].
 ///
+/// Measurements are collected into [`QuantumCircuit::measurements`] and barriers are ignored.
+///
 /// ## Arguments
 /// * `qasm_str` - A string slice containing the OpenQASM 2.0 circuit description.
 ///
 /// ## Returns
-/// A [`Result`] containing the parsed [`QuantumCircuit`] or a [`String`] error message.
+/// A [`Result`] containing the parsed [`QuantumCircuit`], or a
+/// [`QasmParsingError`](Error::QasmParsingError) for malformed or unsupported statements.
 pub(crate) fn from_qasm_str(qasm_str: &str) -> Result<QuantumCircuit> {
-    type Gate1Fn = fn(usize) -> QuantumGate;
-    type Gate2Fn = fn(usize, usize) -> QuantumGate;
-    type Gate3Fn = fn(usize, usize, usize) -> QuantumGate;
-
-    lazy_static::lazy_static! {
-        static ref QREG_RE: Regex = Regex::new(r"qreg\s+([a-zA-Z][a-zA-Z0-9_]*)\s*\[\s*(\d+)\s*\]\s*;").unwrap();
-        static ref GATE1_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
-        static ref GATE2_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
-        static ref GATE3_RE: Regex = Regex::new(r"([a-z_]+)\s+([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\],\s*([a-zA-Z][a-zA-Z0-9_]*)\[(\d+)\]\s*;").unwrap();
-
-        static ref SINGLE_QUBIT_GATES: HashMap<&'static str, Gate1Fn> = {
-            let mut m = HashMap::new();
-            m.insert("h", QuantumGate::H as fn(usize) -> QuantumGate);
-            m.insert("x", QuantumGate::X as fn(usize) -> QuantumGate);
-            m.insert("y", QuantumGate::Y as fn(usize) -> QuantumGate);
-            m.insert("z", QuantumGate::Z as fn(usize) -> QuantumGate);
-            m.insert("s", QuantumGate::S as fn(usize) -> QuantumGate);
-            m.insert("sdg", QuantumGate::Sdg as fn(usize) -> QuantumGate);
-            m.insert("sx", QuantumGate::SqrtX as fn(usize) -> QuantumGate);
-            m.insert("sxdg", QuantumGate::SqrtXdg as fn(usize) -> QuantumGate);
-            m.insert("t", QuantumGate::T as Gate1Fn);
-            m.insert("tdg", QuantumGate::Tdg as fn(usize) -> QuantumGate);
-            m
-        };
-
-        static ref TWO_QUBIT_GATES: HashMap<&'static str, Gate2Fn> = {
-            let mut m = HashMap::new();
-            m.insert("cx", QuantumGate::CX as fn(usize, usize) -> QuantumGate);
-            m.insert("cz", QuantumGate::CZ as fn(usize, usize) -> QuantumGate);
-            m.insert("swap", QuantumGate::Swap as fn(usize, usize) -> QuantumGate);
-            m
-        };
-
-        static ref THREE_QUBIT_GATES: HashMap<&'static str, Gate3Fn> = {
-            let mut m = HashMap::new();
-            m.insert("ccx", QuantumGate::CCX as fn(usize, usize, usize) -> QuantumGate);
-            m
-        };
-    }
-
     let mut num_qubits: Option<usize> = None;
     let mut gates = Vec::new();
+    let mut cregs = ClassicalRegisters::default();
+    let mut measurements = Vec::new();
 
-    for (line_num, line_content) in qasm_str.lines().enumerate() {
+    for line_content in qasm_str.lines() {
         let line = line_content.trim();
         if line.is_empty() || line.starts_with("//") {
             continue;
@@ -81,107 +176,377 @@ pub(crate) fn from_qasm_str(qasm_str: &str) -> Result<QuantumCircuit> {
             continue;
         }
 
-        if line.starts_with("measure") {
-            eprintln!(
-                "[Warning] Line {}: `measure` operation is ignored by the parser.",
-                line_num + 1
-            );
+        if let Some(caps) = CREG_RE.captures(line) {
+            let size = caps[2].parse::<usize>().map_err(|e| {
+                Error::QasmParsingError(format!("Invalid creg size in line: '{}' ({})", line, e))
+            })?;
+            cregs.declare(&caps[1], size)?;
             continue;
         }
 
-        let mut matched = false;
+        if let Some(caps) = MEASURE_RE.captures(line) {
+            measurements.extend(cregs.measurements(
+                parse_optional_index(caps.get(1), line)?,
+                &caps[2],
+                parse_optional_index(caps.get(3), line)?,
+                num_qubits,
+                line,
+            )?);
+            continue;
+        }
 
-        // Check for 3-qubit gates first (most specific)
-        if let Some(caps) = GATE3_RE.captures(line) {
-            let gate_name = &caps[1];
-            if let Some(gate_fn) = THREE_QUBIT_GATES.get(gate_name) {
-                let q1 = caps[3].parse::<usize>().map_err(|e| {
-                    Error::QasmParsingError(format!(
-                        "Invalid qubit index in line: '{}' ({})",
-                        line, e
-                    ))
-                })?;
-                let q2 = caps[5].parse::<usize>().map_err(|e| {
+        if is_barrier(line) {
+            continue;
+        }
+
+        match parse_gate(line)? {
+            Some(gate) => gates.push(gate),
+            None => {
+                return Err(Error::QasmParsingError(format!(
+                    "Unrecognized or malformed line: {}",
+                    line
+                )));
+            }
+        }
+    }
+
+    if let Some(n) = num_qubits {
+        Ok(QuantumCircuit {
+            num_qubits: n,
+            gates,
+            measurements,
+        })
+    } else {
+        Err(Error::QasmParsingError(
+            "qreg declaration not found in QASM string.".to_string(),
+        ))
+    }
+}
+
+/// Parses an OpenQASM 3.0 string into a [`QuantumCircuit`].
+///
+/// Supports a single `qubit[n] q;` (or `qubit q;`) declaration and applications of the
+/// standard gates on indexed qubits, one statement per line. Measurements are collected into
+/// [`QuantumCircuit::measurements`] and barriers are ignored, while classical and control-flow
+/// constructs such as `if`, `for` and `gate` definitions are rejected.
+///
+/// ## Arguments
+/// * `qasm_str` - A string slice containing the OpenQASM 3.0 circuit description.
+///
+/// ## Returns
+/// A [`Result`] containing the parsed [`QuantumCircuit`], or a
+/// [`QasmParsingError`](Error::QasmParsingError) for malformed or unsupported statements.
+pub(crate) fn from_qasm3_str(qasm_str: &str) -> Result<QuantumCircuit> {
+    let mut num_qubits: Option<usize> = None;
+    let mut gates = Vec::new();
+    let mut cregs = ClassicalRegisters::default();
+    let mut measurements = Vec::new();
+
+    for (line_num, line_content) in qasm_str.lines().enumerate() {
+        let line = line_content.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+
+        if let Some(version) = line.strip_prefix("OPENQASM") {
+            let version = version.trim().trim_end_matches(';').trim();
+            if version != "3" && !version.starts_with("3.") {
+                return Err(Error::QasmParsingError(format!(
+                    "Expected OpenQASM 3, found version '{}'; use `from_qasm_str` for OpenQASM 2.0.",
+                    version
+                )));
+            }
+            continue;
+        }
+        if line.starts_with("include") {
+            continue;
+        }
+
+        if let Some(caps) = BIT_RE.captures(line) {
+            let size = match caps.get(1) {
+                Some(size) => size.as_str().parse::<usize>().map_err(|e| {
                     Error::QasmParsingError(format!(
-                        "Invalid qubit index in line: '{}' ({})",
+                        "Invalid bit register size in line: '{}' ({})",
                         line, e
                     ))
-                })?;
-                let q3 = caps[7].parse::<usize>().map_err(|e| {
+                })?,
+                None => 1,
+            };
+            cregs.declare(&caps[2], size)?;
+            continue;
+        }
+
+        if let Some(caps) = QUBIT_RE.captures(line) {
+            if num_qubits.is_some() {
+                return Err(Error::QasmParsingError(
+                    "Multiple qubit declarations are not supported.".to_string(),
+                ));
+            }
+            let size = match caps.get(1) {
+                Some(size) => size.as_str().parse::<usize>().map_err(|e| {
                     Error::QasmParsingError(format!(
-                        "Invalid qubit index in line: '{}' ({})",
+                        "Invalid qubit register size in line: '{}' ({})",
                         line, e
                     ))
-                })?;
-                gates.push(gate_fn(q1, q2, q3));
-                matched = true;
-            }
+                })?,
+                None => 1,
+            };
+            num_qubits = Some(size);
+            continue;
         }
 
-        // Check for 2-qubit gates if not matched
-        #[allow(clippy::collapsible_if)]
-        // avoid let-chains for compatibility with older Rust toolchains
-        if !matched {
-            if let Some(caps) = GATE2_RE.captures(line) {
-                let gate_name = &caps[1];
-                if let Some(gate_fn) = TWO_QUBIT_GATES.get(gate_name) {
-                    let q1 = caps[3].parse::<usize>().map_err(|e| {
-                        Error::QasmParsingError(format!(
-                            "Invalid qubit index in line: '{}' ({})",
-                            line, e
-                        ))
-                    })?;
-                    let q2 = caps[5].parse::<usize>().map_err(|e| {
-                        Error::QasmParsingError(format!(
-                            "Invalid qubit index in line: '{}' ({})",
-                            line, e
-                        ))
-                    })?;
-                    gates.push(gate_fn(q1, q2));
-                    matched = true;
-                }
-            }
+        if let Some(caps) = MEASURE_RE.captures(line) {
+            measurements.extend(cregs.measurements(
+                parse_optional_index(caps.get(1), line)?,
+                &caps[2],
+                parse_optional_index(caps.get(3), line)?,
+                num_qubits,
+                line,
+            )?);
+            continue;
+        }
+        if let Some(caps) = MEASURE_ASSIGN_RE.captures(line) {
+            measurements.extend(cregs.measurements(
+                parse_optional_index(caps.get(3), line)?,
+                &caps[1],
+                parse_optional_index(caps.get(2), line)?,
+                num_qubits,
+                line,
+            )?);
+            continue;
         }
 
-        // Check for 1-qubit gates if not matched
-        #[allow(clippy::collapsible_if)]
-        // avoid let-chains for compatibility with older Rust toolchains
-        if !matched {
-            if let Some(caps) = GATE1_RE.captures(line) {
-                let gate_name = &caps[1];
-                if let Some(gate_fn) = SINGLE_QUBIT_GATES.get(gate_name) {
-                    let qarg = caps[3].parse::<usize>().map_err(|e| {
-                        Error::QasmParsingError(format!(
-                            "Invalid qubit index in line: '{}' ({})",
-                            line, e
-                        ))
-                    })?;
-                    gates.push(gate_fn(qarg));
-                    matched = true;
-                }
-            }
+        if is_barrier(line) {
+            continue;
         }
 
-        if !matched {
+        let keyword = line
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .next()
+            .unwrap_or_default();
+        if UNSUPPORTED_QASM3_KEYWORDS.contains(&keyword) {
             return Err(Error::QasmParsingError(format!(
-                "Unrecognized or malformed line: {}",
+                "Unsupported OpenQASM 3 statement `{}` in line {}: {}",
+                keyword,
+                line_num + 1,
                 line
             )));
         }
+
+        match parse_gate(line)? {
+            Some(gate) => gates.push(gate),
+            None => {
+                return Err(Error::QasmParsingError(format!(
+                    "Unrecognized or malformed line: {}",
+                    line
+                )));
+            }
+        }
     }
 
     if let Some(n) = num_qubits {
         Ok(QuantumCircuit {
             num_qubits: n,
             gates,
+            measurements,
         })
     } else {
         Err(Error::QasmParsingError(
-            "qreg declaration not found in QASM string.".to_string(),
+            "qubit declaration not found in QASM string.".to_string(),
         ))
     }
 }
 
+fn is_barrier(line: &str) -> bool {
+    line.strip_prefix("barrier")
+        .is_some_and(|rest| rest.starts_with(char::is_whitespace) || rest.starts_with(';'))
+}
+
+/// Parses a gate application such as `cx q[0], q[1];`.
+///
+/// ## Returns
+/// A [`Result`] containing the parsed [`QuantumGate`], or `None` if the line is not an
+/// application of a supported gate.
+// avoid let-chains for compatibility with older Rust toolchains
+#[allow(clippy::collapsible_if)]
+fn parse_gate(line: &str) -> Result<Option<QuantumGate>> {
+    // Check for 3-qubit gates first (most specific)
+    if let Some(caps) = GATE3_RE.captures(line) {
+        if let Some(gate_fn) = THREE_QUBIT_GATES.get(&caps[1]) {
+            return Ok(Some(gate_fn(
+                parse_qubit_index(&caps[3], line)?,
+                parse_qubit_index(&caps[5], line)?,
+                parse_qubit_index(&caps[7], line)?,
+            )));
+        }
+    }
+
+    if let Some(caps) = GATE2_RE.captures(line) {
+        if let Some(gate_fn) = TWO_QUBIT_GATES.get(&caps[1]) {
+            return Ok(Some(gate_fn(
+                parse_qubit_index(&caps[3], line)?,
+                parse_qubit_index(&caps[5], line)?,
+            )));
+        }
+    }
+
+    if let Some(caps) = ROTATION_RE.captures(line) {
+        if let Some(gate_fn) = ROTATION_GATES.get(&caps[1]) {
+            let k = parse_angle(&caps[2], line)?;
+            return Ok(Some(gate_fn(parse_qubit_index(&caps[4], line)?, k)));
+        }
+    }
+
+    if let Some(caps) = GATE1_RE.captures(line) {
+        if let Some(gate_fn) = SINGLE_QUBIT_GATES.get(&caps[1]) {
+            return Ok(Some(gate_fn(parse_qubit_index(&caps[3], line)?)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Parses a gate angle such as `pi/4`, `-3*pi/4` or `1.5707963267948966` and returns it as the
+/// multiple `k` of π/4.
+///
+/// ## Returns
+/// A [`Result`] containing `k`, or a [`QasmParsingError`](Error::QasmParsingError) if the
+/// expression is malformed or the angle is not a multiple of π/4.
+fn parse_angle(expression: &str, line: &str) -> Result<i64> {
+    let mut parser = AngleParser {
+        tokens: tokenize_angle(expression).ok_or_else(|| {
+            Error::QasmParsingError(format!("Invalid angle '{}' in line: {}", expression, line))
+        })?,
+        pos: 0,
+    };
+    let angle = parser
+        .expr()
+        .filter(|_| parser.pos == parser.tokens.len())
+        .ok_or_else(|| {
+            Error::QasmParsingError(format!("Invalid angle '{}' in line: {}", expression, line))
+        })?;
+
+    let k = angle / std::f64::consts::FRAC_PI_4;
+    if !k.is_finite() || (k - k.round()).abs() > 1e-9 {
+        return Err(Error::QasmParsingError(format!(
+            "Angle '{}' ({} rad) is not a multiple of pi/4 in line: {}",
+            expression, angle, line
+        )));
+    }
+    Ok(k.round() as i64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AngleToken {
+    Number(f64),
+    Op(char),
+}
+
+fn tokenize_angle(expression: &str) -> Option<Vec<AngleToken>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if "+-*/()".contains(c) {
+            tokens.push(AngleToken::Op(c));
+            chars.next();
+        } else if c == 'π' {
+            tokens.push(AngleToken::Number(std::f64::consts::PI));
+            chars.next();
+        } else if c.is_ascii_alphabetic() {
+            let mut ident = String::new();
+            while let Some(c) = chars.next_if(char::is_ascii_alphanumeric) {
+                ident.push(c);
+            }
+            if ident != "pi" {
+                return None;
+            }
+            tokens.push(AngleToken::Number(std::f64::consts::PI));
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut literal = String::new();
+            while let Some(c) =
+                chars.next_if(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E'))
+            {
+                literal.push(c);
+                // A sign directly after an exponent belongs to the literal
+                if matches!(c, 'e' | 'E') {
+                    literal.extend(chars.next_if(|c| matches!(c, '+' | '-')));
+                }
+            }
+            tokens.push(AngleToken::Number(literal.parse().ok()?));
+        } else {
+            return None;
+        }
+    }
+    Some(tokens)
+}
+
+/// A recursive descent parser for angle expressions made of numbers, `pi`, the four basic
+/// arithmetic operators and parentheses.
+struct AngleParser {
+    tokens: Vec<AngleToken>,
+    pos: usize,
+}
+
+impl AngleParser {
+    fn next_op_in(&mut self, ops: &str) -> Option<char> {
+        match self.tokens.get(self.pos) {
+            Some(&AngleToken::Op(op)) if ops.contains(op) => {
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    /// expr = term (('+' | '-') term)*
+    fn expr(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        while let Some(op) = self.next_op_in("+-") {
+            let rhs = self.term()?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Some(value)
+    }
+
+    /// term = factor (('*' | '/') factor)*
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+        while let Some(op) = self.next_op_in("*/") {
+            let rhs = self.factor()?;
+            value = if op == '*' { value * rhs } else { value / rhs };
+        }
+        Some(value)
+    }
+
+    /// factor = ('+' | '-') factor | '(' expr ')' | number
+    fn factor(&mut self) -> Option<f64> {
+        if let Some(op) = self.next_op_in("+-") {
+            let value = self.factor()?;
+            return Some(if op == '-' { -value } else { value });
+        }
+        if self.next_op_in("(").is_some() {
+            let value = self.expr()?;
+            self.next_op_in(")")?;
+            return Some(value);
+        }
+        match self.tokens.get(self.pos) {
+            Some(&AngleToken::Number(value)) => {
+                self.pos += 1;
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse_qubit_index(index: &str, line: &str) -> Result<usize> {
+    index.parse::<usize>().map_err(|e| {
+        Error::QasmParsingError(format!("Invalid qubit index in line: '{}' ({})", line, e))
+    })
+}
+
 /// Parses an OpenQASM 2.0 file into a [`QuantumCircuit`].
 ///
 /// ## Arguments
@@ -206,10 +571,23 @@ pub(crate) fn to_qasm_str(circuit: &QuantumCircuit, reg_name: &str) -> String {
     lines.push("include \"qelib1.inc\";".to_string());
     lines.push(format!("qreg {}[{}];", reg_name, circuit.num_qubits));
 
+    // Measured bits are written to a classical register named after the quantum register.
+    let creg_name = format!("{}_c", reg_name);
+    if let Some(num_clbits) = circuit.measurements.iter().map(|&(_, c)| c + 1).max() {
+        lines.push(format!("creg {}[{}];", creg_name, num_clbits));
+    }
+
     for gate in &circuit.gates {
         lines.push(gate.to_qasm_str(reg_name));
     }
 
+    for &(qubit, clbit) in &circuit.measurements {
+        lines.push(format!(
+            "measure {}[{}] -> {}[{}];",
+            reg_name, qubit, creg_name, clbit
+        ));
+    }
+
     lines.join("\n")
 }
 
@@ -306,18 +684,33 @@ t q[0];"#;
 
     #[test]
     fn test_qasm_parser_errors() {
-        // The parser should reject parametrized gates like RX
+        // The parser should reject rotations by angles other than multiples of π/4
         let qasm_str_unsupported_gate = r#"OPENQASM 2.0;
 include "qelib1.inc";
 qreg q[1];
-rx(pi/4) q[0];"#;
+rx(pi/3) q[0];"#;
 
         let result = from_qasm_str(qasm_str_unsupported_gate);
         assert!(
-            result.is_err(),
-            "Parser should fail on unsupported parametrized gate"
+            matches!(result, Err(Error::QasmParsingError(ref msg)) if msg.contains("pi/3")),
+            "Parser should fail on unsupported rotation angle"
         );
 
+        // The parser should reject unknown parametrized gates and malformed angles
+        for statement in [
+            "u3(0, 0, 0) q[0];",
+            "rz(theta) q[0];",
+            "rz(pi/) q[0];",
+            "rz() q[0];",
+        ] {
+            let qasm_str = format!("OPENQASM 2.0;\nqreg q[1];\n{}", statement);
+            assert!(
+                matches!(from_qasm_str(&qasm_str), Err(Error::QasmParsingError(_))),
+                "Parser should fail on '{}'",
+                statement
+            );
+        }
+
         // The parser should reject malformed QASM syntax (e.g., missing semicolon)
         let qasm_str_bad_syntax = r#"OPENQASM 2.0;
 include "qelib1.inc";
@@ -328,4 +721,203 @@ h q[0]"#;
             "Parser should fail on syntax error"
         );
     }
+
+    #[test]
+    fn test_from_qasm3_str_ghz() {
+        let qasm_str = r#"OPENQASM 3.0;
+include "stdgates.inc";
+qubit[3] q;
+bit[3] c;
+h q[0];
+cx q[0], q[1];
+cx q[1], q[2];
+c[0] = measure q[0];"#;
+
+        let circuit = from_qasm3_str(qasm_str).expect("QASM parsing failed");
+
+        let mut expected_circuit = QuantumCircuit::new(3);
+        expected_circuit.apply_h(0);
+        expected_circuit.apply_cx(0, 1);
+        expected_circuit.apply_cx(1, 2);
+        expected_circuit.measurements = vec![(0, 0)];
+
+        assert_eq!(circuit, expected_circuit);
+    }
+
+    #[test]
+    fn test_qasm_measurements() {
+        let qasm_str = r#"OPENQASM 2.0;
+include "qelib1.inc";
+qreg q[2];
+creg c[2];
+h q[0];
+cx q[0], q[1];
+barrier q[0], q[1];
+measure q[0] -> c[0];
+measure q[1] -> c[1];"#;
+
+        let circuit = from_qasm_str(qasm_str).expect("QASM parsing failed");
+        let mut expected_circuit = QuantumCircuit::new(2);
+        expected_circuit.apply_h(0);
+        expected_circuit.apply_cx(0, 1);
+        assert_eq!(circuit.gates, expected_circuit.gates);
+        assert_eq!(circuit.measurements, vec![(0, 0), (1, 1)]);
+
+        // Round-trip through the writer
+        assert_eq!(from_qasm_str(&to_qasm_str(&circuit, "q")).unwrap(), circuit);
+
+        // Whole-register measurements and several classical registers
+        let qasm_str = r#"OPENQASM 2.0;
+qreg q[2];
+creg a[1];
+creg b[2];
+barrier q;
+measure q[1] -> a[0];
+measure q -> b;"#;
+        let circuit = from_qasm_str(qasm_str).unwrap();
+        assert_eq!(circuit.measurements, vec![(1, 0), (0, 1), (1, 2)]);
+
+        // OpenQASM 3.0 assignment syntax
+        let qasm_str = r#"OPENQASM 3.0;
+qubit[2] q;
+bit[2] c;
+h q[0];
+cx q[0], q[1];
+c[1] = measure q[0];
+measure q[1] -> c[0];
+c = measure q;"#;
+        let circuit = from_qasm3_str(qasm_str).unwrap();
+        assert_eq!(circuit.gates, expected_circuit.gates);
+        assert_eq!(circuit.measurements, vec![(0, 1), (1, 0), (0, 0), (1, 1)]);
+
+        // Invalid measurement targets
+        let header = "OPENQASM 2.0;\nqreg q[2];\ncreg c[1];\n";
+        for statement in [
+            "measure q[0] -> c[1];",
+            "measure q[2] -> c[0];",
+            "measure q[0] -> d[0];",
+            "measure q -> c;",
+            "measure q[0] -> c;",
+        ] {
+            let result = from_qasm_str(&format!("{}{}", header, statement));
+            assert!(
+                matches!(result, Err(Error::QasmParsingError(_))),
+                "Parser should fail on '{}'",
+                statement
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_qasm3_str_matches_qasm2() {
+        let body = r#"h q[0];
+t q[0];
+cx q[0], q[1];
+tdg q[1];
+s q[2];
+sdg q[2];
+sx q[1];
+ccx q[0], q[1], q[2];
+swap q[0], q[2];
+cz q[1], q[2];
+t q[2];"#;
+        let qasm3_str = format!("OPENQASM 3;\nqubit[3] q;\n{}", body);
+        let qasm2_str = format!(
+            "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[3];\n{}",
+            body
+        );
+
+        let circuit = from_qasm3_str(&qasm3_str).expect("QASM 3 parsing failed");
+        assert_eq!(circuit, from_qasm_str(&qasm2_str).unwrap());
+        assert_eq!(circuit.gates.len(), 11);
+        assert_eq!(circuit.gates[1], QuantumGate::T(0));
+        assert_eq!(circuit.gates[7], QuantumGate::CCX(0, 1, 2));
+
+        // Round-trip through the OpenQASM 2.0 writer
+        let reparsed = from_qasm_str(&to_qasm_str(&circuit, "q")).unwrap();
+        assert_eq!(reparsed.gates, circuit.gates);
+
+        // A single qubit declaration
+        let circuit = from_qasm3_str("OPENQASM 3;\nqubit q;\nx q[0];").unwrap();
+        assert_eq!(circuit.num_qubits, 1);
+        assert_eq!(circuit.gates, vec![QuantumGate::X(0)]);
+    }
+
+    #[test]
+    fn test_from_qasm3_str_errors() {
+        let unsupported = [
+            "if (c[0]) x q[0];",
+            "for int i in [0:1] { h q[0]; }",
+            "gate my_h a { h a; }",
+            "reset q[0];",
+            "rx(pi/3) q[0];",
+        ];
+        for statement in unsupported {
+            let qasm_str = format!("OPENQASM 3.0;\nqubit[2] q;\n{}", statement);
+            assert!(
+                matches!(from_qasm3_str(&qasm_str), Err(Error::QasmParsingError(_))),
+                "Parser should fail on '{}'",
+                statement
+            );
+        }
+
+        let result = from_qasm3_str("OPENQASM 3.0;\nqubit[2] q;\nif (c[0]) x q[0];");
+        assert!(matches!(result, Err(Error::QasmParsingError(msg)) if msg.contains("`if`")));
+
+        // OpenQASM 2.0 input and missing declarations
+        let qasm2_str = "OPENQASM 2.0;\nqreg q[1];\nh q[0];";
+        assert!(from_qasm3_str(qasm2_str).is_err());
+        assert!(from_qasm3_str("OPENQASM 3.0;\nh q[0];").is_err());
+    }
+
+    #[test]
+    fn test_qasm_rotation_gates() {
+        use crate::state::QuantumState;
+
+        use QuantumGate::{Phase, RZ, S, Sdg, T, Tdg, Z};
+        // The parsed gate and an equivalent sequence of gates
+        let cases: [(&str, QuantumGate, &[QuantumGate]); 10] = [
+            ("rz(pi/4)", RZ(0, 1), &[T(0)]),
+            ("rz(-pi/4)", RZ(0, -1), &[Tdg(0)]),
+            ("rz(pi/2)", RZ(0, 2), &[S(0)]),
+            ("rz(pi)", RZ(0, 4), &[Z(0)]),
+            ("u1(pi/2)", Phase(0, 2), &[S(0)]),
+            ("p(pi)", Phase(0, 4), &[Z(0)]),
+            ("p(3*pi/4)", Phase(0, 3), &[S(0), T(0)]),
+            ("p(0.7853981633974483)", Phase(0, 1), &[T(0)]),
+            ("rz(-(pi/2))", RZ(0, -2), &[Sdg(0)]),
+            ("p(2*pi)", Phase(0, 8), &[]),
+        ];
+
+        for (gate_str, expected_gate, equivalent_gates) in cases {
+            let qasm_str = format!(
+                "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[1];\nh q[0];\n{} q[0];\nh q[0];",
+                gate_str
+            );
+            let circuit = from_qasm_str(&qasm_str).expect("QASM parsing failed");
+            assert_eq!(circuit.gates[1], expected_gate, "{}", gate_str);
+
+            let mut expected_circuit = QuantumCircuit::new(1);
+            expected_circuit.apply_h(0);
+            expected_circuit.apply_gates(equivalent_gates);
+            expected_circuit.apply_h(0);
+
+            let actual = QuantumState::from_circuit(&circuit).unwrap();
+            let expected = QuantumState::from_circuit(&expected_circuit).unwrap();
+            crate::test_utils::assert_eq_complex_array1(
+                &actual.to_statevector().unwrap(),
+                &expected.to_statevector().unwrap(),
+            );
+        }
+
+        // sx and the rotations written by to_qasm_str round-trip
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_sqrt_x(0);
+        circuit.apply_rz(1, 3);
+        circuit.apply_rx(0, -1);
+        circuit.apply_ry(1, 5);
+        circuit.apply_phase(0, 7);
+        let reparsed = from_qasm_str(&to_qasm_str(&circuit, "q")).unwrap();
+        assert_eq!(reparsed, circuit);
+    }
 }
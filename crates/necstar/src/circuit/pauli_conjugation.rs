@@ -0,0 +1,125 @@
+use stabilizer_ch_form_rust::circuit::CliffordGate;
+use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString};
+
+use crate::circuit::{QuantumCircuit, QuantumGate};
+use crate::error::{Error, Result};
+
+/// Computes `U† P U` for the Clifford circuit `U` as a dense Pauli string of
+/// `circuit.num_qubits` qubits together with its sign (`1` or `-1`).
+///
+/// `U† P U = V P V†` with `V = U†`, so the gates of the inverse circuit are applied in order to
+/// a single row of a stabilizer tableau, using the update rules of Aaronson and Gottesman.
+pub(crate) fn conjugate_pauli(
+    circuit: &QuantumCircuit,
+    pauli_string: &PauliString,
+) -> Result<(PauliString, i8)> {
+    if let Some(gate) = circuit.gates.iter().find(|gate| !gate.is_clifford()) {
+        return Err(Error::GateNotClifford(gate.name().to_string()));
+    }
+    let PauliString::Dense(ops) = pauli_string.padded_to(circuit.num_qubits)? else {
+        unreachable!("padded_to always returns a dense Pauli string");
+    };
+
+    let mut row = PauliRow::from_ops(&ops);
+    for gate in circuit
+        .inverse()
+        .gates
+        .iter()
+        .flat_map(QuantumGate::decompose)
+    {
+        row.conjugate(&gate.to_clifford_gate()?);
+    }
+    Ok(row.into_pauli_string())
+}
+
+/// A Hermitian Pauli operator `(-1)^sign Π_j P_j` in the symplectic representation, where
+/// `(x_j, z_j) = (1, 1)` denotes `Y_j`.
+struct PauliRow {
+    x: Vec<bool>,
+    z: Vec<bool>,
+    sign: bool,
+}
+
+impl PauliRow {
+    fn from_ops(ops: &[Pauli]) -> Self {
+        Self {
+            x: ops
+                .iter()
+                .map(|op| matches!(op, Pauli::X | Pauli::Y))
+                .collect(),
+            z: ops
+                .iter()
+                .map(|op| matches!(op, Pauli::Z | Pauli::Y))
+                .collect(),
+            sign: false,
+        }
+    }
+
+    fn into_pauli_string(self) -> (PauliString, i8) {
+        let ops = self
+            .x
+            .iter()
+            .zip(self.z.iter())
+            .map(|(&x, &z)| match (x, z) {
+                (false, false) => Pauli::I,
+                (true, false) => Pauli::X,
+                (true, true) => Pauli::Y,
+                (false, true) => Pauli::Z,
+            })
+            .collect();
+        (PauliString::Dense(ops), if self.sign { -1 } else { 1 })
+    }
+
+    /// Replaces `P` with `G P G†`.
+    fn conjugate(&mut self, gate: &CliffordGate) {
+        match *gate {
+            CliffordGate::H(q) => self.h(q),
+            CliffordGate::X(q) => self.sign ^= self.z[q],
+            CliffordGate::Y(q) => self.sign ^= self.x[q] ^ self.z[q],
+            CliffordGate::Z(q) => self.sign ^= self.x[q],
+            CliffordGate::S(q) => self.s(q),
+            CliffordGate::Sdg(q) => self.sdg(q),
+            CliffordGate::SqrtX(q) => {
+                self.h(q);
+                self.s(q);
+                self.h(q);
+            }
+            CliffordGate::SqrtXdg(q) => {
+                self.h(q);
+                self.sdg(q);
+                self.h(q);
+            }
+            CliffordGate::CX(c, t) => self.cx(c, t),
+            CliffordGate::CZ(q1, q2) => {
+                self.h(q2);
+                self.cx(q1, q2);
+                self.h(q2);
+            }
+            CliffordGate::Swap(q1, q2) => {
+                self.x.swap(q1, q2);
+                self.z.swap(q1, q2);
+            }
+        }
+    }
+
+    fn h(&mut self, q: usize) {
+        self.sign ^= self.x[q] && self.z[q];
+        std::mem::swap(&mut self.x[q], &mut self.z[q]);
+    }
+
+    fn s(&mut self, q: usize) {
+        self.sign ^= self.x[q] && self.z[q];
+        self.z[q] ^= self.x[q];
+    }
+
+    fn sdg(&mut self, q: usize) {
+        self.sign ^= self.x[q] && !self.z[q];
+        self.z[q] ^= self.x[q];
+    }
+
+    fn cx(&mut self, c: usize, t: usize) {
+        self.sign ^= self.x[c] && self.z[t] && (self.x[t] == self.z[c]);
+        self.x[t] ^= self.x[c];
+        self.z[c] ^= self.z[t];
+    }
+}
@@ -0,0 +1,122 @@
+use crate::circuit::{QuantumCircuit, QuantumGate};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Generates a random Clifford+T circuit.
+///
+/// The circuit consists of `clifford_gate_count` Clifford gates drawn uniformly from
+/// `H`, `X`, `Y`, `Z`, `S`, `Sdg`, `SqrtX`, `SqrtXdg` (and `CX`, `CZ`, `Swap` for two or more
+/// qubits) and `t_type_gate_count` gates drawn from `T` and `Tdg`, in a random order and on
+/// random qubits.
+///
+/// ## Arguments
+/// * `num_qubits` - The number of qubits. Must be greater than 0.
+/// * `clifford_gate_count` - The number of Clifford gates.
+/// * `t_type_gate_count` - The number of T-type gates.
+/// * `seed` - An optional seed for the random number generator for reproducibility.
+///   If [`None`] is provided, a seed will be generated from system entropy.
+///
+/// ## Returns
+/// A [`QuantumCircuit`] object representing the random Clifford+T circuit.
+pub(crate) fn random_clifford_t(
+    num_qubits: usize,
+    clifford_gate_count: usize,
+    t_type_gate_count: usize,
+    seed: Option<[u8; 32]>,
+) -> QuantumCircuit {
+    let mut rng = match seed {
+        Some(s) => StdRng::from_seed(s),
+        None => StdRng::from_entropy(),
+    };
+    random_clifford_t_with_rng(num_qubits, clifford_gate_count, t_type_gate_count, &mut rng)
+}
+
+/// Generates a random Clifford+T circuit using the given random number generator.
+pub(crate) fn random_clifford_t_with_rng(
+    num_qubits: usize,
+    clifford_gate_count: usize,
+    t_type_gate_count: usize,
+    rng: &mut StdRng,
+) -> QuantumCircuit {
+    let mut circuit = QuantumCircuit::new(num_qubits);
+
+    // An enum to represent the category of gate to be generated.
+    #[derive(Clone, Copy)]
+    enum GateCategory {
+        Clifford,
+        TType,
+    }
+
+    // Create a pool of gate categories to be generated.
+    let total_gates = clifford_gate_count + t_type_gate_count;
+    let mut gate_categories: Vec<GateCategory> = Vec::with_capacity(total_gates);
+    gate_categories.extend(std::iter::repeat_n(
+        GateCategory::Clifford,
+        clifford_gate_count,
+    ));
+    gate_categories.extend(std::iter::repeat_n(GateCategory::TType, t_type_gate_count));
+
+    // Shuffle the pool to ensure random ordering of Clifford and T-type gates.
+    gate_categories.shuffle(rng);
+
+    for category in gate_categories {
+        let gate = match category {
+            GateCategory::Clifford => {
+                // Define the number of available Clifford gates of each arity.
+                const NUM_1Q_CLIFFORDS: u32 = 8; // H, X, Y, Z, S, Sdg, SqrtX, SqrtXdg
+                const NUM_2Q_CLIFFORDS: u32 = 3; // CX, CZ, Swap
+
+                // Determine the range of possible gates based on the number of qubits.
+                let max_gate_idx = if num_qubits < 2 {
+                    NUM_1Q_CLIFFORDS
+                } else {
+                    NUM_1Q_CLIFFORDS + NUM_2Q_CLIFFORDS
+                };
+
+                let gate_idx = rng.gen_range(0..max_gate_idx);
+
+                if gate_idx < NUM_1Q_CLIFFORDS {
+                    // Generate a 1-qubit Clifford gate.
+                    let q = rng.gen_range(0..num_qubits);
+                    match gate_idx {
+                        0 => QuantumGate::H(q),
+                        1 => QuantumGate::X(q),
+                        2 => QuantumGate::Y(q),
+                        3 => QuantumGate::Z(q),
+                        4 => QuantumGate::S(q),
+                        5 => QuantumGate::Sdg(q),
+                        6 => QuantumGate::SqrtX(q),
+                        7 => QuantumGate::SqrtXdg(q),
+                        _ => unreachable!(),
+                    }
+                } else {
+                    // Generate a 2-qubit Clifford gate.
+                    let q1 = rng.gen_range(0..num_qubits);
+                    let mut q2 = rng.gen_range(0..num_qubits);
+                    while q1 == q2 {
+                        q2 = rng.gen_range(0..num_qubits);
+                    }
+                    match gate_idx - NUM_1Q_CLIFFORDS {
+                        0 => QuantumGate::CX(q1, q2),
+                        1 => QuantumGate::CZ(q1, q2),
+                        2 => QuantumGate::Swap(q1, q2),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+            GateCategory::TType => {
+                // Generate a T or Tdg gate.
+                let q = rng.gen_range(0..num_qubits);
+                if rng.gen_bool(0.5) {
+                    QuantumGate::T(q)
+                } else {
+                    QuantumGate::Tdg(q)
+                }
+            }
+        };
+        circuit.apply_gate(gate);
+    }
+
+    circuit
+}
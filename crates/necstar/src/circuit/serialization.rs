@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+use crate::circuit::{QuantumCircuit, QuantumGate};
+use crate::error::{Error, Result};
+
+/// The unvalidated form of a deserialized [`QuantumCircuit`].
+#[derive(Deserialize)]
+pub(crate) struct SerializedCircuit {
+    num_qubits: usize,
+    gates: Vec<QuantumGate>,
+    #[serde(default)]
+    measurements: Vec<(usize, usize)>,
+}
+
+impl TryFrom<SerializedCircuit> for QuantumCircuit {
+    type Error = Error;
+
+    /// Checks that every gate is valid for the circuit.
+    fn try_from(circuit: SerializedCircuit) -> Result<Self> {
+        for gate in &circuit.gates {
+            gate.validate(circuit.num_qubits)?;
+        }
+        for &(qubit, _) in &circuit.measurements {
+            if qubit >= circuit.num_qubits {
+                return Err(Error::QubitIndexOutOfBounds(qubit, circuit.num_qubits));
+            }
+        }
+        Ok(QuantumCircuit {
+            num_qubits: circuit.num_qubits,
+            gates: circuit.gates,
+            measurements: circuit.measurements,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circuit::QuantumCircuit;
+    use crate::error::Error;
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_tdg(2);
+        circuit.apply_s(1);
+        circuit.apply_clifford1(2, 13).unwrap();
+        circuit.apply_rz(1, 3);
+        circuit.apply_ccz(0, 1, 2);
+        circuit.apply_cswap(2, 0, 1);
+
+        let json = circuit.to_json().unwrap();
+        assert_eq!(QuantumCircuit::from_json(&json).unwrap(), circuit);
+        assert!(json.starts_with(r#"{"num_qubits":3,"gates":[{"H":0},{"T":0},{"CX":[0,1]}"#));
+
+        circuit.measurements = vec![(2, 0), (0, 1)];
+        let json = circuit.to_json().unwrap();
+        assert!(json.ends_with(r#""measurements":[[2,0],[0,1]]}"#));
+        assert_eq!(QuantumCircuit::from_json(&json).unwrap(), circuit);
+    }
+
+    #[test]
+    fn test_json_rejects_invalid_gates() {
+        let err = QuantumCircuit::from_json(r#"{"num_qubits":2,"gates":[{"CX":[0,2]}]}"#);
+        assert!(matches!(err, Err(Error::Json(_))));
+        assert!(err.unwrap_err().to_string().contains("out of bounds"));
+
+        let err = QuantumCircuit::from_json(r#"{"num_qubits":1,"gates":[{"Clifford1":[0,24]}]}"#);
+        assert!(err.unwrap_err().to_string().contains("out of range"));
+
+        assert!(QuantumCircuit::from_json(r#"{"num_qubits":1,"gates":[{"U3":0}]}"#).is_err());
+
+        let err =
+            QuantumCircuit::from_json(r#"{"num_qubits":1,"gates":[],"measurements":[[1,0]]}"#);
+        assert!(err.unwrap_err().to_string().contains("out of bounds"));
+    }
+}
@@ -24,6 +24,14 @@ pub enum Error {
     #[error("Calculating the statevector for a state with {0} qubits is not feasible.")]
     StatevectorTooLarge(usize),
 
+    /// Error for dense probability vectors that exceed feasible limits.
+    #[error("Calculating the probability vector over {0} qubits is not feasible.")]
+    ProbabilityVectorTooLarge(usize),
+
+    /// Error for reduced density matrices that exceed feasible limits.
+    #[error("Calculating the reduced density matrix over {0} qubits is not feasible.")]
+    DensityMatrixTooLarge(usize),
+
     /// Error for direct application of non-Clifford gates.
     #[error("Direct application of non-Clifford gate {0} is not supported.")]
     NonCliffordGateApplication(String),
@@ -42,7 +50,7 @@ pub enum Error {
     },
 
     /// Error for measurements exceeding supported qubit limits.
-    #[error("Measurements of more than 128 qubits are not supoprted.")]
+    #[error("Measurements of more than 128 qubits are not supported.")]
     MeasurementTooManyQubits,
 
     /// Error for sampling more than supported qubit limits.
@@ -57,6 +65,36 @@ pub enum Error {
     )]
     ImpossibleProjection { qubit_index: usize, desired: bool },
 
+    /// Error for normalizing a state with zero norm.
+    #[error("Cannot normalize a state with zero norm.")]
+    ZeroNormState,
+
+    /// Error for an expectation value with a non-negligible imaginary part.
+    #[error(
+        "Expectation value has a nonzero imaginary part {0:e}; the observable must be Hermitian."
+    )]
+    NonRealExpectationValue(f64),
+
+    /// Error for gates without a supported controlled version.
+    #[error("Controlled version of gate {0} is not supported.")]
+    GateNotControllable(String),
+
+    /// Error for parsing a single gate from a string.
+    #[error("Gate parsing error: {0}")]
+    GateParsingError(String),
+
+    /// Error for inconsistent components passed to `QuantumState::from_stabilizers`.
+    #[error("Invalid stabilizer decomposition: {0}")]
+    InvalidDecomposition(String),
+
+    /// Error for operations that require a single stabilizer component.
+    #[error("The state is not a single stabilizer state: its compressed stabilizer rank is {0}.")]
+    NotStabilizerState(usize),
+
+    /// Error for setting a global phase that is not a unit complex number.
+    #[error("Global phase must be a unit complex number, got modulus {0}.")]
+    NonUnitGlobalPhase(f64),
+
     /// Error for duplicate qubit indices in a argument list.
     #[error("Duplicate qubit index found: {0}.")]
     DuplicateQubitIndex(usize),
@@ -65,6 +103,18 @@ pub enum Error {
     #[error("Invalid Pauli string length: expected {expected}, found {found}.")]
     InvalidPauliStringLength { expected: usize, found: usize },
 
+    /// Error for an observable acting on a qubit outside the given qubit subset.
+    #[error("The observable acts on qubit {0}, which is not in the qubit subset.")]
+    PauliOutsideQubitSubset(usize),
+
+    /// Error for invalid bitstring length.
+    #[error("Invalid bitstring length: expected {expected}, found {found}.")]
+    InvalidBitstringLength { expected: usize, found: usize },
+
+    /// Error for basis state indices outside of `0..2^n`.
+    #[error("Basis state index {0} is out of bounds for {1} qubits.")]
+    BasisStateIndexOutOfBounds(usize, usize),
+
     /// Error for empty qubit index list.
     #[error("Qubit index list is empty.")]
     EmptyQubitIndices,
@@ -76,6 +126,20 @@ pub enum Error {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Error for an out-of-range index of [`QuantumGate::Clifford1`](crate::circuit::QuantumGate).
+    #[error("Single-qubit Clifford index {0} is out of range (0..24).")]
+    InvalidClifford1Index(u8),
+
+    /// Error for a saved state file that cannot be restored.
+    #[cfg(feature = "serde")]
+    #[error("Invalid saved state: {0}")]
+    InvalidSavedState(String),
+
+    /// Error for JSON serialization and deserialization.
+    #[cfg(feature = "serde")]
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
     #[error(transparent)]
     Compile(#[from] CompileError),
 
@@ -11,6 +11,11 @@ pub enum Error {
     #[error("Gate {0} is not supported.")]
     GateNotSupported(String),
 
+    /// Error for a compilation exceeding the configured cap on the number of stabilizer
+    /// components.
+    #[error("The number of stabilizer components {count} exceeds the cap of {cap}.")]
+    StabilizerRankExceeded { count: usize, cap: usize },
+
     #[error(transparent)]
     ChForm(#[from] ChFormError),
 }
@@ -1,141 +1,916 @@
 pub mod error;
 use crate::{
-    circuit::QuantumCircuit,
+    circuit::{QuantumCircuit, QuantumGate},
     state::{
         InternalState, StabilizerDecomposedState,
-        magic_states::t_state::construct_t_tensor_state,
+        magic_states::{
+            ccz_state::construct_ccz_tensor_state,
+            t_state::{construct_t_tensor_state, t_tensor_state_rank},
+            toffoli_state::construct_toffoli_tensor_state,
+        },
         types::{coefficient::Amplify, scalar::Scalar},
     },
 };
 use error::{Error as CompileError, Result as CompileResult};
 use num_traits::One;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use stabilizer_ch_form_rust::{
     StabilizerCHForm,
     circuit::{CliffordCircuit, CliffordGate},
 };
+use std::ops::Range;
 
 /// A trait for compilers that transform a [`QuantumCircuit`] blueprint into a
 /// computable [`InternalState`].
 pub(crate) trait CircuitCompiler {
-    fn compile(&self, circuit: &QuantumCircuit) -> Result<InternalState, CompileError>;
+    fn compile(&self, circuit: &QuantumCircuit) -> crate::error::Result<InternalState>;
+}
+
+/// The order in which the compiler teleports non-Clifford gates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompileStrategy {
+    /// Teleports all non-Clifford gates at once using the stabilizer decomposition of
+    /// |T>^{⊗t}, where `t` is the number of T-type gates, tensored with the magic states of
+    /// the other non-Clifford gates.
+    ///
+    /// The number of components is fixed by the decomposition of the magic states, which is the
+    /// best choice for generic circuits.
+    #[default]
+    Batch,
+    /// Teleports each non-Clifford gate as soon as it appears in the circuit, interleaved with
+    /// the Clifford gates.
+    ///
+    /// Each T-type gate splits every component into at most two, but the branches with zero
+    /// amplitude are dropped immediately. A component which is an eigenstate of Z on the target
+    /// qubit therefore does not split at all, which keeps the intermediate number of components
+    /// small for structured circuits (e.g. T gates acting on computational basis states, or
+    /// repeated T gates on the same qubit). For generic circuits, the number of components
+    /// grows as `2^t`.
+    Sequential,
+}
+
+/// The default cap on the number of stabilizer components held during the compilation, see
+/// [`StabDecompCompilerBuilder::max_rank`].
+pub const DEFAULT_MAX_RANK: usize = 1 << 24;
+
+/// The magic states used to teleport the CCZ and Toffoli gates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MagicStateStrategy {
+    /// Teleports CCZ and Toffoli gates with the three-qubit |CCZ> and |Toffoli> states.
+    #[default]
+    ToffoliState,
+    /// Decomposes CCZ and Toffoli gates into Clifford gates and seven T-type gates each, so
+    /// that all non-Clifford gates are teleported with |T>.
+    TState,
 }
 
 /// A compiler that implements the stabilizer decomposition simulation method.
 ///
 /// This compiler transforms a [`QuantumCircuit`] into a [`InternalState`] which
 /// internally uses a [`StabilizerDecomposedState`]. It processes non-Clifford
-/// gates (like T and Toffoli) in a batch by preparing the necessary magic
-/// states and then applying gate teleportation.
-pub(crate) struct StabDecompCompiler;
+/// gates (like T and Toffoli) by preparing the necessary magic states and then
+/// applying gate teleportation, in the order given by the [`CompileStrategy`].
+///
+/// [`QuantumState::from_circuit`](crate::state::QuantumState::from_circuit) uses the default
+/// configuration. A customized compiler is created with [`StabDecompCompiler::builder`] and
+/// used with [`QuantumState::from_circuit_with`](crate::state::QuantumState::from_circuit_with).
+#[derive(Clone, Debug)]
+pub struct StabDecompCompiler {
+    strategy: CompileStrategy,
+    magic_state_strategy: MagicStateStrategy,
+    /// The largest number of stabilizer components allowed during the compilation.
+    max_rank: usize,
+    /// Whether the compiled state is compressed with [`QuantumState::compress`](crate::state::QuantumState::compress).
+    deduplicate: bool,
+    /// Whether the components of the magic state are teleported in parallel by
+    /// [`CompileStrategy::Batch`].
+    #[cfg(feature = "parallel")]
+    parallel: bool,
+}
 
 impl StabDecompCompiler {
     pub(crate) fn new() -> Self {
-        Self
+        Self::with_strategy(CompileStrategy::default())
     }
-}
 
-impl CircuitCompiler for StabDecompCompiler {
-    /// Compiles a [`QuantumCircuit`] into an [`InternalState`] using stabilizer decomposition.
+    pub(crate) fn with_strategy(strategy: CompileStrategy) -> Self {
+        Self::builder().strategy(strategy).build()
+    }
+
+    /// Returns a [`StabDecompCompilerBuilder`] with the default configuration, which compiles
+    /// circuits the same way as [`QuantumState::from_circuit`](crate::state::QuantumState::from_circuit).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::state::{MagicStateStrategy, StabDecompCompiler};
     ///
-    /// NOTE: Currently only supports Clifford + T circuits.
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// circuit.apply_h(0);
+    /// circuit.apply_h(1);
+    /// circuit.apply_ccx(0, 1, 2);
     ///
-    /// TODO: Generalize by abstracting magic state preparation and gate teleportation
-    /// to support arbitrary non-Clifford gates for better extensibility.
-    fn compile(&self, circuit: &QuantumCircuit) -> CompileResult<InternalState> {
+    /// let compiler = StabDecompCompiler::builder()
+    ///     .magic_state_strategy(MagicStateStrategy::TState)
+    ///     .max_rank(64)
+    ///     .deduplicate(true)
+    ///     .build();
+    /// let state = QuantumState::from_circuit_with(&circuit, &compiler).unwrap();
+    /// assert!(state.stabilizer_rank() <= 64);
+    /// ```
+    pub fn builder() -> StabDecompCompilerBuilder {
+        StabDecompCompilerBuilder::default()
+    }
+
+    /// Whether the compiled state should be compressed.
+    pub(crate) fn deduplicates(&self) -> bool {
+        self.deduplicate
+    }
+
+    /// Fails with [`CompileError::StabilizerRankExceeded`] if `count` exceeds the cap.
+    fn check_rank(&self, count: usize) -> CompileResult<()> {
+        if count > self.max_rank {
+            return Err(CompileError::StabilizerRankExceeded {
+                count,
+                cap: self.max_rank,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(all(test, feature = "parallel"))]
+    pub(crate) fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Compiles the circuit and additionally returns the peak number of stabilizer components
+    /// held during the compilation.
+    pub(crate) fn compile_with_peak_rank(
+        &self,
+        circuit: &QuantumCircuit,
+    ) -> CompileResult<(InternalState, usize)> {
+        // An empty circuit leaves the initial state |0...0> untouched.
+        if circuit.gates.is_empty() {
+            let state = StabilizerDecomposedState::new(
+                circuit.num_qubits,
+                vec![StabilizerCHForm::new(circuit.num_qubits)?],
+                vec![Scalar::one()],
+            );
+            return Ok((InternalState::StabilizerDecomposedStateScalar(state), 1));
+        }
+
+        let (state, peak_rank) = match self.strategy {
+            CompileStrategy::Batch => self.compile_batch(circuit)?,
+            CompileStrategy::Sequential => self.compile_sequential(circuit)?,
+        };
+        Ok((
+            InternalState::StabilizerDecomposedStateScalar(state),
+            peak_rank,
+        ))
+    }
+
+    /// Teleports each non-Clifford gate as it appears in the circuit.
+    fn compile_sequential(
+        &self,
+        circuit: &QuantumCircuit,
+    ) -> CompileResult<(StabilizerDecomposedState<Scalar>, usize)> {
+        let num_qubits = circuit.num_qubits;
+
+        let mut stabilizers = vec![StabilizerCHForm::new(num_qubits)?];
+        let mut coefficients = vec![Scalar::one()];
+        let mut peak_rank = 1;
+
+        for gate in &decomposed_gates(circuit, self.magic_state_strategy) {
+            if gate.is_clifford() {
+                let clifford_gate = gate.to_clifford_gate().unwrap();
+                for stab in &mut stabilizers {
+                    stab.apply_gate(&clifford_gate)?;
+                }
+                continue;
+            }
+
+            let magic_state = MagicState::of(gate)
+                .ok_or_else(|| CompileError::GateNotSupported(gate.name().to_string()))?;
+            let magic_tensor_state = magic_state.construct_tensor_state(1);
+            let ancillas = num_qubits..(num_qubits + magic_state.num_qubits());
+            let teleportation_ops = teleportation_ops(gate, num_qubits);
+
+            let mut new_stabilizers = Vec::with_capacity(2 * stabilizers.len());
+            let mut new_coefficients = Vec::with_capacity(2 * coefficients.len());
+            for (stab, coeff) in stabilizers.iter().zip(coefficients.iter()) {
+                for (magic_stab, magic_coeff) in magic_tensor_state
+                    .stabilizers
+                    .iter()
+                    .zip(magic_tensor_state.coefficients.iter())
+                {
+                    let mut full_stab_state = stab.kron(magic_stab)?;
+                    for op in &teleportation_ops {
+                        full_stab_state.apply_gate(op)?;
+                    }
+                    // Branches that cannot be post-selected have zero amplitude.
+                    if let Some(num_deterministic_qubits) =
+                        postselect_ancillas(&mut full_stab_state, ancillas.clone())
+                    {
+                        new_stabilizers.push(full_stab_state);
+                        new_coefficients
+                            .push((*coeff * *magic_coeff).amplify(num_deterministic_qubits));
+                    }
+                }
+            }
+
+            stabilizers = new_stabilizers;
+            coefficients = new_coefficients;
+            peak_rank = peak_rank.max(stabilizers.len());
+            self.check_rank(peak_rank)?;
+        }
+
+        let final_state = StabilizerDecomposedState::new(num_qubits, stabilizers, coefficients);
+        Ok((final_state, peak_rank))
+    }
+
+    /// Teleports all non-Clifford gates at once using the tensor product of the magic states,
+    /// where all T-type gates share the decomposition of |T>^{⊗t}.
+    fn compile_batch(
+        &self,
+        circuit: &QuantumCircuit,
+    ) -> CompileResult<(StabilizerDecomposedState<Scalar>, usize)> {
         let num_qubits_original = circuit.num_qubits;
-        let mut num_t_type_gates = 0;
-        let mut clifford_ops: Vec<CliffordGate> = Vec::new();
+        let gates = decomposed_gates(circuit, self.magic_state_strategy);
 
-        for gate in &circuit.gates {
+        // The ancillas are grouped by magic state, in the order of `MagicState::ALL`.
+        let mut num_magic_states = [0; MagicState::ALL.len()];
+        for gate in &gates {
+            if gate.is_clifford() {
+                continue;
+            }
+            match MagicState::of(gate) {
+                Some(magic_state) => num_magic_states[magic_state as usize] += 1,
+                None => return Err(CompileError::GateNotSupported(gate.name().to_string())),
+            }
+        }
+        let mut next_ancilla_idx = [0; MagicState::ALL.len()];
+        let mut num_ancillas = 0;
+        for magic_state in MagicState::ALL {
+            next_ancilla_idx[magic_state as usize] = num_qubits_original + num_ancillas;
+            num_ancillas += num_magic_states[magic_state as usize] * magic_state.num_qubits();
+        }
+
+        let mut clifford_ops: Vec<CliffordGate> = Vec::new();
+        for gate in &gates {
             if gate.is_clifford() {
                 clifford_ops.push(gate.to_clifford_gate().unwrap());
-            } else if gate.is_t_type_gate() {
-                let ancilla_idx = num_qubits_original + num_t_type_gates;
-                let target_idx = gate.qubits()[0];
-                clifford_ops.push(CliffordGate::CX(target_idx, ancilla_idx));
-                if gate.is_tdg_gate() {
-                    clifford_ops.push(CliffordGate::Sdg(target_idx));
-                }
-                num_t_type_gates += 1;
             } else {
-                return Err(CompileError::GateNotSupported(gate.name().to_string()));
+                let magic_state = MagicState::of(gate).unwrap();
+                let ancilla_idx = &mut next_ancilla_idx[magic_state as usize];
+                clifford_ops.extend(teleportation_ops(gate, *ancilla_idx));
+                *ancilla_idx += magic_state.num_qubits();
             }
         }
 
-        // If there are no T-gates, the circuit is purely Clifford.
-        if num_t_type_gates == 0 {
+        // If there are no non-Clifford gates, the circuit is purely Clifford.
+        if num_ancillas == 0 {
             let mut circuit = CliffordCircuit::new(num_qubits_original);
             for gate in clifford_ops {
                 circuit.add_gate(gate);
             }
-            let ch_form = StabilizerCHForm::from_clifford_circuit(&circuit).unwrap();
+            let ch_form = StabilizerCHForm::from_clifford_circuit(&circuit)?;
             let stab_decomp_state = StabilizerDecomposedState::new(
                 num_qubits_original,
                 vec![ch_form],
                 vec![Scalar::one()],
             );
-            return Ok(InternalState::StabilizerDecomposedStateScalar(
-                stab_decomp_state,
-            ));
+            return Ok((stab_decomp_state, 1));
         }
 
-        // Initialize the T-tensor state for the ancilla qubits.
-        let t_tensor_state = construct_t_tensor_state(num_t_type_gates).unwrap();
+        // Check the number of components before constructing the magic states, which may not
+        // fit into memory.
+        let anticipated_rank = MagicState::ALL
+            .into_iter()
+            .filter(|&magic_state| num_magic_states[magic_state as usize] > 0)
+            .map(|magic_state| magic_state.tensor_rank(num_magic_states[magic_state as usize]))
+            .fold(1, usize::saturating_mul);
+        self.check_rank(anticipated_rank)?;
 
-        let mut final_stabilizers: Vec<StabilizerCHForm> = Vec::new();
-        let mut final_coefficients: Vec<Scalar> = Vec::new();
+        // Initialize the magic states for the ancilla qubits.
+        let magic_tensor_state = MagicState::ALL
+            .into_iter()
+            .filter(|&magic_state| num_magic_states[magic_state as usize] > 0)
+            .map(|magic_state| {
+                magic_state.construct_tensor_state(num_magic_states[magic_state as usize])
+            })
+            .reduce(|acc, state| acc.kron(&state).unwrap())
+            .unwrap();
+        let peak_rank = magic_tensor_state.stabilizers.len();
 
-        // Process each stabilizer component of the |T^n> state.
-        // NOTE: This process may be improved by "right-applying" the t-tensor
+        // Process each stabilizer component of the magic state independently.
+        // NOTE: This process may be improved by "right-applying" the magic state
         // preparation to the whole circuit, instead of "left-applying" the
         // clifford operations to each stabilizer component.
-        for (stab, coeff) in t_tensor_state
+        let ancillas = num_qubits_original..(num_qubits_original + num_ancillas);
+        let teleport = |(stab, coeff): (&StabilizerCHForm, &Scalar)| {
+            teleport_component(
+                num_qubits_original,
+                stab,
+                *coeff,
+                &clifford_ops,
+                ancillas.clone(),
+            )
+            .transpose()
+        };
+        #[cfg(feature = "parallel")]
+        let components: Vec<(StabilizerCHForm, Scalar)> = if self.parallel {
+            magic_tensor_state
+                .stabilizers
+                .par_iter()
+                .zip(magic_tensor_state.coefficients.par_iter())
+                .filter_map(teleport)
+                .collect::<CompileResult<_>>()?
+        } else {
+            magic_tensor_state
+                .stabilizers
+                .iter()
+                .zip(magic_tensor_state.coefficients.iter())
+                .filter_map(teleport)
+                .collect::<CompileResult<_>>()?
+        };
+        #[cfg(not(feature = "parallel"))]
+        let components: Vec<(StabilizerCHForm, Scalar)> = magic_tensor_state
             .stabilizers
             .iter()
-            .zip(t_tensor_state.coefficients.iter())
-        {
-            let mut full_stab_state = StabilizerCHForm::new(num_qubits_original)?.kron(stab)?;
+            .zip(magic_tensor_state.coefficients.iter())
+            .filter_map(teleport)
+            .collect::<CompileResult<_>>()?;
+        let (final_stabilizers, final_coefficients) = components.into_iter().unzip();
+
+        let final_state = StabilizerDecomposedState::new(
+            num_qubits_original,
+            final_stabilizers,
+            final_coefficients,
+        );
+
+        Ok((final_state, peak_rank))
+    }
+}
+
+/// The magic states consumed by the gate teleportation of non-Clifford gates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MagicState {
+    /// |T> = T|+>, consumed by T-type gates.
+    T,
+    /// |CCZ> = CCZ|+++>, consumed by CCZ gates.
+    Ccz,
+    /// |Toffoli> = CCX|++0>, consumed by Toffoli gates.
+    Toffoli,
+}
+
+impl MagicState {
+    const ALL: [MagicState; 3] = [MagicState::T, MagicState::Ccz, MagicState::Toffoli];
+
+    /// Returns the magic state consumed by the non-Clifford `gate`, if it is supported.
+    fn of(gate: &QuantumGate) -> Option<Self> {
+        match gate {
+            QuantumGate::T(_) | QuantumGate::Tdg(_) => Some(MagicState::T),
+            QuantumGate::CCZ(_, _, _) => Some(MagicState::Ccz),
+            QuantumGate::CCX(_, _, _) => Some(MagicState::Toffoli),
+            _ => None,
+        }
+    }
 
-            // Apply the clifford operations to the combined state.
-            for gate in &clifford_ops {
-                full_stab_state.apply_gate(gate)?;
+    /// The number of ancilla qubits holding a single copy of the magic state.
+    fn num_qubits(self) -> usize {
+        match self {
+            MagicState::T => 1,
+            MagicState::Ccz | MagicState::Toffoli => 3,
+        }
+    }
+
+    fn construct_tensor_state(self, num_tensors: usize) -> StabilizerDecomposedState<Scalar> {
+        match self {
+            MagicState::T => construct_t_tensor_state(num_tensors),
+            MagicState::Ccz => construct_ccz_tensor_state(num_tensors),
+            MagicState::Toffoli => construct_toffoli_tensor_state(num_tensors),
+        }
+        .unwrap()
+    }
+
+    /// The number of components of [`construct_tensor_state`](Self::construct_tensor_state),
+    /// saturating at `usize::MAX`.
+    fn tensor_rank(self, num_tensors: usize) -> usize {
+        match self {
+            MagicState::T => t_tensor_state_rank(num_tensors),
+            MagicState::Ccz | MagicState::Toffoli => {
+                2usize.checked_pow(num_tensors as u32).unwrap_or(usize::MAX)
             }
+        }
+    }
+}
+
+/// Returns the Clifford operations teleporting the non-Clifford `gate` with its magic state
+/// placed on the ancillas starting at `ancilla_idx`.
+///
+/// After post-selecting all the ancillas onto |0>, the gate is applied to the data qubits up
+/// to the factor `(1/sqrt(2))^{m}`, where `m` is the number of ancillas.
+fn teleportation_ops(gate: &QuantumGate, ancilla_idx: usize) -> Vec<CliffordGate> {
+    match *gate {
+        QuantumGate::T(target) => vec![CliffordGate::CX(target, ancilla_idx)],
+        QuantumGate::Tdg(target) => vec![
+            CliffordGate::CX(target, ancilla_idx),
+            CliffordGate::Sdg(target),
+        ],
+        // Copying each data qubit onto its ancilla picks the term of
+        // |CCZ> = Σ (-1)^{abc} |abc> / sqrt(8) matching the data qubits.
+        QuantumGate::CCZ(q1, q2, q3) => vec![
+            CliffordGate::CX(q1, ancilla_idx),
+            CliffordGate::CX(q2, ancilla_idx + 1),
+            CliffordGate::CX(q3, ancilla_idx + 2),
+        ],
+        // Post-selecting the control ancillas of |Toffoli> = Σ |a, b, ab> / 2 picks the term
+        // with (a, b) equal to the controls, so that the target ancilla holds their product.
+        // It is added to the target, and then erased by post-selecting it onto |+>.
+        QuantumGate::CCX(c1, c2, t) => vec![
+            CliffordGate::CX(c1, ancilla_idx),
+            CliffordGate::CX(c2, ancilla_idx + 1),
+            CliffordGate::CX(ancilla_idx + 2, t),
+            CliffordGate::H(ancilla_idx + 2),
+        ],
+        _ => unreachable!("{} is not teleported by a magic state", gate.name()),
+    }
+}
 
-            let mut can_postselect_all = true;
-            let mut num_deterministic_qubits = 0;
+/// Applies the Clifford operations `clifford_ops` to `|0...0> ⊗ |stab>` of a magic state
+/// component and post-selects the `ancillas`.
+///
+/// Returns the resulting component with its normalized coefficient, or `None` if the
+/// post-selection has zero amplitude.
+fn teleport_component(
+    num_qubits: usize,
+    stab: &StabilizerCHForm,
+    coeff: Scalar,
+    clifford_ops: &[CliffordGate],
+    ancillas: Range<usize>,
+) -> CompileResult<Option<(StabilizerCHForm, Scalar)>> {
+    let mut full_stab_state = StabilizerCHForm::new(num_qubits)?.kron(stab)?;
+    for gate in clifford_ops {
+        full_stab_state.apply_gate(gate)?;
+    }
+    Ok(postselect_ancillas(&mut full_stab_state, ancillas)
+        .map(|num_deterministic_qubits| (full_stab_state, coeff.amplify(num_deterministic_qubits))))
+}
 
-            // Iterate reverse to handle qubit index shifts after discards.
-            for qubit in (num_qubits_original..(num_qubits_original + num_t_type_gates)).rev() {
-                match full_stab_state.project(qubit, false) {
-                    Ok(deterministic) => {
-                        if deterministic {
-                            num_deterministic_qubits += 1;
-                        }
-                    }
-                    Err(_) => {
-                        can_postselect_all = false;
-                        break;
-                    }
-                }
+/// Post-selects the `ancillas` onto |0> and discards them.
+///
+/// Returns the number of deterministic projections, or `None` if the post-selection has zero
+/// amplitude.
+fn postselect_ancillas(stab: &mut StabilizerCHForm, ancillas: Range<usize>) -> Option<isize> {
+    let mut num_deterministic_qubits = 0;
+    // Iterate reverse to handle qubit index shifts after discards.
+    for qubit in ancillas.clone().rev() {
+        if stab.project(qubit, false).ok()? {
+            num_deterministic_qubits += 1;
+        }
+    }
+    // Reversely discard the ancilla qubits
+    for qubit in ancillas.rev() {
+        stab.discard(qubit).unwrap();
+    }
+    Some(num_deterministic_qubits)
+}
+
+/// Expands composite gates (e.g. rotations) into the primitive gate set handled by the
+/// compiler. With [`MagicStateStrategy::TState`], CCZ and Toffoli gates are further expanded
+/// into Clifford and T-type gates.
+fn decomposed_gates(
+    circuit: &QuantumCircuit,
+    magic_state_strategy: MagicStateStrategy,
+) -> Vec<QuantumGate> {
+    let gates = circuit.gates.iter().flat_map(QuantumGate::decompose);
+    match magic_state_strategy {
+        MagicStateStrategy::ToffoliState => gates.collect(),
+        MagicStateStrategy::TState => gates.flat_map(|gate| ccz_to_clifford_t(&gate)).collect(),
+    }
+}
+
+/// Expands CCZ and Toffoli gates into the standard circuit of Clifford gates and seven T-type
+/// gates, leaving the other gates untouched.
+fn ccz_to_clifford_t(gate: &QuantumGate) -> Vec<QuantumGate> {
+    use QuantumGate::{CX, H, T, Tdg};
+    match *gate {
+        QuantumGate::CCZ(a, b, c) => vec![
+            CX(b, c),
+            Tdg(c),
+            CX(a, c),
+            T(c),
+            CX(b, c),
+            Tdg(c),
+            CX(a, c),
+            T(b),
+            T(c),
+            CX(a, b),
+            T(a),
+            Tdg(b),
+            CX(a, b),
+        ],
+        // CCX = H(t) CCZ H(t)
+        QuantumGate::CCX(c1, c2, t) => {
+            let mut gates = vec![H(t)];
+            gates.extend(ccz_to_clifford_t(&QuantumGate::CCZ(c1, c2, t)));
+            gates.push(H(t));
+            gates
+        }
+        _ => vec![gate.clone()],
+    }
+}
+
+/// A builder for [`StabDecompCompiler`], created by [`StabDecompCompiler::builder`].
+#[derive(Clone, Debug)]
+pub struct StabDecompCompilerBuilder {
+    strategy: CompileStrategy,
+    magic_state_strategy: MagicStateStrategy,
+    max_rank: usize,
+    deduplicate: bool,
+}
+
+impl Default for StabDecompCompilerBuilder {
+    fn default() -> Self {
+        Self {
+            strategy: CompileStrategy::default(),
+            magic_state_strategy: MagicStateStrategy::default(),
+            max_rank: DEFAULT_MAX_RANK,
+            deduplicate: false,
+        }
+    }
+}
+
+impl StabDecompCompilerBuilder {
+    /// Sets the order in which non-Clifford gates are teleported. Defaults to
+    /// [`CompileStrategy::Batch`].
+    pub fn strategy(mut self, strategy: CompileStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the magic states used for CCZ and Toffoli gates. Defaults to
+    /// [`MagicStateStrategy::ToffoliState`].
+    pub fn magic_state_strategy(mut self, magic_state_strategy: MagicStateStrategy) -> Self {
+        self.magic_state_strategy = magic_state_strategy;
+        self
+    }
+
+    /// Limits the number of stabilizer components held during the compilation. Exceeding it
+    /// fails with [`CompileError::StabilizerRankExceeded`]. With [`CompileStrategy::Batch`],
+    /// the number of components of the magic states is checked before they are constructed.
+    /// Defaults to [`DEFAULT_MAX_RANK`]; `usize::MAX` disables the check.
+    pub fn max_rank(mut self, max_rank: usize) -> Self {
+        self.max_rank = max_rank;
+        self
+    }
+
+    /// Sets whether the compiled state is compressed by merging the components equal up to
+    /// global phase, see [`QuantumState::compress`](crate::state::QuantumState::compress).
+    /// Disabled by default.
+    pub fn deduplicate(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    /// Builds the [`StabDecompCompiler`].
+    pub fn build(self) -> StabDecompCompiler {
+        StabDecompCompiler {
+            strategy: self.strategy,
+            magic_state_strategy: self.magic_state_strategy,
+            max_rank: self.max_rank,
+            deduplicate: self.deduplicate,
+            #[cfg(feature = "parallel")]
+            parallel: true,
+        }
+    }
+}
+
+impl CircuitCompiler for StabDecompCompiler {
+    /// Compiles a [`QuantumCircuit`] into an [`InternalState`] using stabilizer decomposition.
+    ///
+    /// NOTE: Currently only supports Clifford + T circuits with CCZ and Toffoli gates.
+    fn compile(&self, circuit: &QuantumCircuit) -> crate::error::Result<InternalState> {
+        // `QuantumCircuit::gates` is public, so the gates may not have been validated.
+        for gate in &circuit.gates {
+            gate.validate(circuit.num_qubits)?;
+        }
+        let (state, _) = self.compile_with_peak_rank(circuit)?;
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompileStrategy, StabDecompCompiler};
+    use crate::circuit::QuantumCircuit;
+    use crate::state::QuantumState;
+    use crate::test_utils::{assert_eq_complex_array1, random_circuit_with_t_gate};
+    use ndarray::Array1;
+    use num_complex::Complex64;
+    use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+    fn compile(circuit: &QuantumCircuit, strategy: CompileStrategy) -> (QuantumState, usize) {
+        let (internal_state, peak_rank) = StabDecompCompiler::with_strategy(strategy)
+            .compile_with_peak_rank(circuit)
+            .unwrap();
+        (QuantumState { internal_state }, peak_rank)
+    }
+
+    #[test]
+    fn test_compile_empty_circuit() {
+        for strategy in [CompileStrategy::Batch, CompileStrategy::Sequential] {
+            for num_qubits in 1..=4 {
+                let circuit = QuantumCircuit::new(num_qubits);
+                let (state, peak_rank) = compile(&circuit, strategy);
+                assert_eq!(peak_rank, 1);
+                assert_eq!(state.num_qubits(), num_qubits);
+                assert_eq!(state.stabilizer_rank(), 1);
+                assert!((state.norm().unwrap() - 1.0).abs() < 1e-12);
+
+                let mut expected = Array1::zeros(1 << num_qubits);
+                expected[0] = Complex64::new(1.0, 0.0);
+                assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
             }
+        }
+
+        // 1-qubit empty circuit through the public API
+        let state = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
+        let statevector = state.to_statevector().unwrap();
+        assert_eq_complex_array1(
+            &statevector,
+            &Array1::from(vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]),
+        );
+    }
+
+    #[test]
+    fn test_sequential_strategy_reduces_peak_rank() {
+        // T gates acting on computational basis states and repeated T gates
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_t(1);
+        circuit.apply_x(2);
+        circuit.apply_tdg(2);
+        circuit.apply_h(0);
+        for _ in 0..6 {
+            circuit.apply_t(0);
+        }
+        circuit.apply_cx(0, 1);
+        circuit.apply_t(1);
+        circuit.apply_t(1);
+        circuit.apply_h(0);
+
+        let (batch_state, batch_peak) = compile(&circuit, CompileStrategy::Batch);
+        let (sequential_state, sequential_peak) = compile(&circuit, CompileStrategy::Sequential);
+
+        assert!(sequential_peak < batch_peak);
+        assert_eq!(sequential_peak, 2);
+        assert!(
+            batch_state
+                .approx_eq_with_phase(&sequential_state, 1e-8)
+                .unwrap()
+        );
+        assert!((sequential_state.norm().unwrap() - batch_state.norm().unwrap()).abs() < 1e-8);
+    }
 
-            // If the post-selection was successful, add the resulting state with the normalized coefficient.
-            if can_postselect_all {
-                // Reversely discard the ancilla qubits
-                for qubit in (num_qubits_original..(num_qubits_original + num_t_type_gates)).rev() {
-                    full_stab_state.discard(qubit).unwrap();
+    #[test]
+    fn test_sequential_strategy_random_circuits() {
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 20, 5, Some(seed));
+            let (batch_state, _) = compile(&circuit, CompileStrategy::Batch);
+            let (sequential_state, _) = compile(&circuit, CompileStrategy::Sequential);
+            assert!(
+                batch_state
+                    .approx_eq_with_phase(&sequential_state, 1e-8)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_compile_ccz() {
+        // CCZ |+++> is the magic state itself.
+        let mut circuit = QuantumCircuit::new(3);
+        for q in 0..3 {
+            circuit.apply_h(q);
+        }
+        circuit.apply_ccz(0, 1, 2);
+        let amplitude = 1.0 / 8.0_f64.sqrt();
+        let mut expected = Array1::from_elem(8, Complex64::new(amplitude, 0.0));
+        expected[7] = Complex64::new(-amplitude, 0.0);
+        for strategy in [CompileStrategy::Batch, CompileStrategy::Sequential] {
+            let (state, _) = compile(&circuit, strategy);
+            assert_eq!(state.stabilizer_rank(), 2);
+            assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+        }
+
+        // Random Clifford+T prefixes followed by CCZ gates, compared with a naive reference
+        // which flips the sign of the amplitudes with all three qubits set.
+        let zzz: PauliString = "ZZZ".parse().unwrap();
+        for seed in 0..5 {
+            let prefix = random_circuit_with_t_gate(3, 20, 3, Some(seed));
+            let (prefix_state, _) = compile(&prefix, CompileStrategy::Batch);
+            let mut expected = prefix_state.to_statevector().unwrap();
+            for (i, amplitude) in expected.iter_mut().enumerate() {
+                let bits = [2, 0, 1].map(|q| (i >> q) & 1 == 1);
+                if bits.iter().all(|&b| b) {
+                    *amplitude = -*amplitude;
                 }
-                final_stabilizers.push(full_stab_state);
-                final_coefficients.push(coeff.amplify(num_deterministic_qubits));
+            }
+            let expected_zzz: f64 = expected
+                .iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    let sign = if (i.count_ones() % 2) == 0 { 1.0 } else { -1.0 };
+                    sign * a.norm_sqr()
+                })
+                .sum();
+
+            let mut circuit = random_circuit_with_t_gate(3, 20, 3, Some(seed));
+            circuit.apply_ccz(2, 0, 1);
+            for strategy in [CompileStrategy::Batch, CompileStrategy::Sequential] {
+                let (state, _) = compile(&circuit, strategy);
+                assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+                assert!((state.exp_value(&zzz).unwrap() - expected_zzz).abs() < 1e-8);
             }
         }
+    }
 
-        let final_state = StabilizerDecomposedState::new(
-            num_qubits_original,
-            final_stabilizers,
-            final_coefficients,
+    #[test]
+    fn test_compile_ccx() {
+        // CCX(0, 1, 2) |++0> = (|000> + |001> + |010> + |111>) / 2 (little-endian)
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_h(1);
+        circuit.apply_ccx(0, 1, 2);
+        let mut expected = Array1::zeros(8);
+        for i in [0, 1, 2, 7] {
+            expected[i] = Complex64::new(0.5, 0.0);
+        }
+        for strategy in [CompileStrategy::Batch, CompileStrategy::Sequential] {
+            let (state, _) = compile(&circuit, strategy);
+            assert_eq!(state.stabilizer_rank(), 2);
+            assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+        }
+
+        // Random Clifford+T prefixes followed by Toffoli gates, compared with a naive reference
+        // which permutes the amplitudes.
+        let ccx =
+            |i: usize, (c1, c2, t): (usize, usize, usize)| i ^ ((((i >> c1) & (i >> c2)) & 1) << t);
+        for seed in 0..5 {
+            let prefix = random_circuit_with_t_gate(4, 20, 3, Some(seed));
+            let (prefix_state, _) = compile(&prefix, CompileStrategy::Batch);
+            let mut expected = Array1::zeros(16);
+            for (i, amplitude) in prefix_state.to_statevector().unwrap().iter().enumerate() {
+                expected[ccx(ccx(i, (3, 0, 1)), (1, 2, 0))] = *amplitude;
+            }
+
+            let mut circuit = random_circuit_with_t_gate(4, 20, 3, Some(seed));
+            circuit.apply_ccx(3, 0, 1);
+            circuit.apply_ccx(1, 2, 0);
+            for strategy in [CompileStrategy::Batch, CompileStrategy::Sequential] {
+                let (state, _) = compile(&circuit, strategy);
+                assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+            }
+        }
+    }
+
+    // This test is ignored by default due to its long runtime.
+    // Run with `cargo test --features parallel -- --ignored test_parallel_batch_compile`.
+    #[cfg(feature = "parallel")]
+    #[test]
+    #[ignore]
+    fn test_parallel_batch_compile() {
+        use super::CircuitCompiler;
+
+        let circuit = random_circuit_with_t_gate(8, 200, 12, Some(12));
+        let compile_with = |parallel: bool| {
+            let compiler = StabDecompCompiler::new().with_parallel(parallel);
+            let internal_state = compiler.compile(&circuit).unwrap();
+            QuantumState { internal_state }
+        };
+        let serial = compile_with(false);
+        let parallel = compile_with(true);
+
+        assert_eq!(serial.stabilizer_rank(), parallel.stabilizer_rank());
+        assert_eq_complex_array1(
+            &serial.to_statevector().unwrap(),
+            &parallel.to_statevector().unwrap(),
         );
+    }
+
+    #[test]
+    fn test_builder_default_matches_from_circuit() {
+        let compiler = StabDecompCompiler::builder().build();
+        for seed in 0..5 {
+            let mut circuit = random_circuit_with_t_gate(4, 20, 5, Some(seed));
+            circuit.apply_ccx(0, 1, 2);
+            let expected = QuantumState::from_circuit(&circuit).unwrap();
+            let state = QuantumState::from_circuit_with(&circuit, &compiler).unwrap();
+            assert_eq!(state.stabilizer_rank(), expected.stabilizer_rank());
+            assert_eq_complex_array1(
+                &state.to_statevector().unwrap(),
+                &expected.to_statevector().unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_t_state_strategy() {
+        use super::MagicStateStrategy;
+
+        let compiler = StabDecompCompiler::builder()
+            .magic_state_strategy(MagicStateStrategy::TState)
+            .build();
+        for seed in 0..3 {
+            let mut circuit = random_circuit_with_t_gate(4, 20, 2, Some(seed));
+            circuit.apply_ccx(0, 1, 2);
+            circuit.apply_ccz(3, 1, 0);
+            let expected = QuantumState::from_circuit(&circuit).unwrap();
+            let state = QuantumState::from_circuit_with(&circuit, &compiler).unwrap();
+            assert_eq_complex_array1(
+                &state.to_statevector().unwrap(),
+                &expected.to_statevector().unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_max_rank_and_deduplicate() {
+        use super::error::Error as CompileError;
+        use crate::error::Error;
 
-        Ok(InternalState::StabilizerDecomposedStateScalar(final_state))
+        let mut circuit = QuantumCircuit::new(4);
+        for _ in 0..3 {
+            for q in 0..4 {
+                circuit.apply_h(q);
+                circuit.apply_t(q);
+            }
+            for q in 0..3 {
+                circuit.apply_cx(q, q + 1);
+            }
+        }
+        let unlimited = QuantumState::from_circuit(&circuit).unwrap();
+        let rank = unlimited.stabilizer_rank();
+        let (_, peak_rank) = compile(&circuit, CompileStrategy::Batch);
+        for strategy in [CompileStrategy::Batch, CompileStrategy::Sequential] {
+            let compiler = StabDecompCompiler::builder()
+                .strategy(strategy)
+                .max_rank(8)
+                .build();
+            assert!(matches!(
+                QuantumState::from_circuit_with(&circuit, &compiler),
+                Err(Error::Compile(CompileError::StabilizerRankExceeded {
+                    cap: 8,
+                    ..
+                }))
+            ));
+        }
+        let compiler = StabDecompCompiler::builder().max_rank(peak_rank).build();
+        assert!(QuantumState::from_circuit_with(&circuit, &compiler).is_ok());
+
+        let compiler = StabDecompCompiler::builder().deduplicate(true).build();
+        let state = QuantumState::from_circuit_with(&circuit, &compiler).unwrap();
+        assert!(state.stabilizer_rank() <= rank);
+        assert!(state.approx_eq_with_phase(&unlimited, 1e-8).unwrap());
+    }
+
+    #[test]
+    fn test_stabilizer_rank_cap() {
+        use super::error::Error as CompileError;
+        use crate::error::Error;
+
+        // |T>^{⊗26} has 1458 components.
+        let mut circuit = QuantumCircuit::new(26);
+        for q in 0..26 {
+            circuit.apply_h(q);
+            circuit.apply_t(q);
+        }
+        let compiler = StabDecompCompiler::builder().max_rank(1 << 10).build();
+        assert!(matches!(
+            QuantumState::from_circuit_with(&circuit, &compiler),
+            Err(Error::Compile(CompileError::StabilizerRankExceeded {
+                count: 1458,
+                cap: 1024
+            }))
+        ));
+        let compiler = StabDecompCompiler::builder().max_rank(1458).build();
+        assert!(QuantumState::from_circuit_with(&circuit, &compiler).is_ok());
+
+        // The default cap rejects the magic states of 70 T gates before constructing them.
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        for _ in 0..70 {
+            circuit.apply_t(0);
+            circuit.apply_h(0);
+        }
+        assert!(matches!(
+            QuantumState::from_circuit(&circuit),
+            Err(Error::Compile(CompileError::StabilizerRankExceeded {
+                cap: super::DEFAULT_MAX_RANK,
+                ..
+            }))
+        ));
     }
 }
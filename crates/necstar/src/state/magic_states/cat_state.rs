@@ -158,6 +158,18 @@ fn reduce_cat_state(state: &mut StabilizerDecomposedState<Scalar>) -> Result<()>
     Ok(())
 }
 
+/// Returns the number of stabilizer components of [`construct_cat_state`] without constructing
+/// it, saturating at `usize::MAX`.
+pub(crate) fn cat_state_rank(num_qubits: usize) -> usize {
+    match num_qubits {
+        0..=2 => 1,
+        3 | 4 => 2,
+        5 | 6 => 3,
+        // Each projection onto |cat_2> multiplies the rank by that of |cat_6>.
+        _ => cat_state_rank(num_qubits - 4).saturating_mul(3),
+    }
+}
+
 /// Constructs the "cat state" |cat_n> as a [`StabilizerDecomposedState`]
 pub(crate) fn construct_cat_state(num_qubits: usize) -> Result<StabilizerDecomposedState<Scalar>> {
     match num_qubits {
@@ -0,0 +1,58 @@
+use crate::error::{Error, Result};
+use crate::state::{StabilizerDecomposedState, types::scalar::Scalar};
+use stabilizer_ch_form_rust::prelude::*;
+
+/// Constructs the CCZ state |CCZ>:
+/// |CCZ> = CCZ |+++>
+///       = (|0>|++> + |1> CZ|++>) / sqrt(2)
+/// as a `StabilizerDecomposedState<Scalar>`, where the first factor is qubit 0.
+pub(crate) fn construct_ccz_state() -> Result<StabilizerDecomposedState<Scalar>> {
+    // |0>|++> part
+    let mut stab1 = StabilizerCHForm::new(3)?;
+    stab1.apply_h(1)?;
+    stab1.apply_h(2)?;
+
+    // |1> CZ|++> part
+    let mut stab2 = StabilizerCHForm::new(3)?;
+    stab2.apply_x(0)?;
+    stab2.apply_h(1)?;
+    stab2.apply_h(2)?;
+    stab2.apply_cz(1, 2)?;
+
+    let coeffs = vec![Scalar::ONE_OVER_SQRT_2, Scalar::ONE_OVER_SQRT_2];
+
+    Ok(StabilizerDecomposedState::new(
+        3,
+        vec![stab1, stab2],
+        coeffs,
+    ))
+}
+
+/// Constructs |CCZ>^{⊗n} as the tensor product of the two-term decompositions, which has
+/// `2^n` components.
+pub(crate) fn construct_ccz_tensor_state(
+    num_tensors: usize,
+) -> Result<StabilizerDecomposedState<Scalar>> {
+    match num_tensors {
+        0 => Err(Error::InvalidNumQubits(num_tensors)),
+        1 => construct_ccz_state(),
+        _ => Ok(construct_ccz_tensor_state(num_tensors - 1)?.kron(&construct_ccz_state()?)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::construct_ccz_state;
+    use crate::test_utils::assert_eq_complex_array1;
+    use ndarray::Array1;
+    use num_complex::Complex64;
+
+    #[test]
+    fn test_construct_ccz_state() {
+        let state = construct_ccz_state().unwrap();
+        let amplitude = 1.0 / 8.0_f64.sqrt();
+        let mut expected = Array1::from_elem(8, Complex64::new(amplitude, 0.0));
+        expected[7] = Complex64::new(-amplitude, 0.0);
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+    }
+}
@@ -1,3 +1,4 @@
 pub(crate) mod cat_state;
+pub(crate) mod ccz_state;
 pub(crate) mod t_state;
 pub(crate) mod toffoli_state;
@@ -1,7 +1,7 @@
 use stabilizer_ch_form_rust::StabilizerCHForm;
 
 use crate::error::Result;
-use crate::state::magic_states::cat_state::construct_cat_state;
+use crate::state::magic_states::cat_state::{cat_state_rank, construct_cat_state};
 use crate::state::{
     StabilizerDecomposedState,
     types::{phase_factor::PhaseFactor, scalar::Scalar},
@@ -17,6 +17,10 @@ fn apply_xs(state: &mut StabilizerCHForm, target: usize) -> Result<()> {
 /// Create a T-tensor state: $|T\rangle^{\otimes n}$ as a stabilizer decomposed state.
 /// The decomposition is based on the work by Qassim et al. (2021).
 ///
+/// Each additional four T states multiply the number of components of |cat_n> by 3, so the
+/// stabilizer rank grows as about `2^{0.4n}` (e.g. 324 components for `n = 20`), well below
+/// the `7^{n/6} ≈ 2^{0.47n}` of the decomposition of Bravyi, Smith and Smolin.
+///
 /// ## Arguments
 /// * `num_qubits` - The number of T states ($n$) to include in the tensor product.
 ///
@@ -70,6 +74,12 @@ pub(crate) fn construct_t_tensor_state(
     ))
 }
 
+/// Returns the number of stabilizer components of [`construct_t_tensor_state`] without
+/// constructing it, saturating at `usize::MAX`.
+pub(crate) fn t_tensor_state_rank(num_qubits: usize) -> usize {
+    cat_state_rank(num_qubits).saturating_mul(2)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -119,4 +129,28 @@ mod tests {
             assert_eq_complex_array1(&state_vector, &expected_vector);
         }
     }
+
+    #[test]
+    fn test_t_tensor_state_rank() {
+        use crate::state::magic_states::t_state::t_tensor_state_rank;
+
+        assert_eq!(construct_t_tensor_state(20).unwrap().stabilizers.len(), 324);
+        for num_qubits in 1..=20 {
+            assert_eq!(
+                t_tensor_state_rank(num_qubits),
+                construct_t_tensor_state(num_qubits)
+                    .unwrap()
+                    .stabilizers
+                    .len()
+            );
+        }
+        assert_eq!(t_tensor_state_rank(1000), usize::MAX);
+        for num_qubits in (8..=20).step_by(2) {
+            let rank = construct_t_tensor_state(num_qubits)
+                .unwrap()
+                .stabilizers
+                .len();
+            assert!((rank as f64) < 2f64.powf(0.47 * num_qubits as f64));
+        }
+    }
 }
@@ -1,4 +1,3 @@
-#![allow(dead_code)]
 use crate::error::{Error, Result};
 use crate::state::{StabilizerDecomposedState, types::scalar::Scalar};
 use stabilizer_ch_form_rust::prelude::*;
@@ -6,9 +5,8 @@ use stabilizer_ch_form_rust::prelude::*;
 /// Constructs the Toffoli state |Toffoli>:
 /// |Toffoli> = (|000> + |100> + |010> + |111>) / 2
 ///           = (|0+0> + |1,Bell>) / sqrt(2)
-/// as a `StabilizerDecomposedState<Scalar>`
-///
-/// Note: Toffoli state injection is not implemented yet.
+/// as a `StabilizerDecomposedState<Scalar>`, where qubits 0 and 1 are the controls and
+/// qubit 2 is the target, i.e. |Toffoli> = CCX |++0>.
 pub(crate) fn construct_toffoli_state() -> Result<StabilizerDecomposedState<Scalar>> {
     // |0+0> part
     let mut stab1 = StabilizerCHForm::new(3)?;
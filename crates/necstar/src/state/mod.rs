@@ -1,21 +1,29 @@
 pub(crate) mod compiler;
 pub(crate) mod magic_states;
+#[cfg(feature = "serde")]
+mod serialization;
 pub(crate) mod stabilizer_decomposed_state;
 pub(crate) mod types;
 
-use ndarray::Array1;
-use stabilizer_ch_form_rust::types::pauli::PauliString;
+pub use compiler::{
+    CompileStrategy, DEFAULT_MAX_RANK, MagicStateStrategy, StabDecompCompiler,
+    StabDecompCompilerBuilder,
+};
+use ndarray::{Array1, Array2};
+use num_complex::Complex64;
+use rand::RngCore;
+use stabilizer_ch_form_rust::StabilizerCHForm;
+use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString};
 pub(crate) use stabilizer_decomposed_state::StabilizerDecomposedState;
+use std::fmt;
 pub(crate) use types::coefficient::Coefficient;
+pub use types::coefficient::CoefficientType;
 
 use crate::{
     circuit::{QuantumCircuit, QuantumGate},
     error::Result,
-    state::{
-        compiler::{CircuitCompiler, StabDecompCompiler},
-        types::scalar::Scalar,
-    },
-    types::shot_count::ShotCount,
+    state::{compiler::CircuitCompiler, types::scalar::Scalar},
+    types::shot_count::{Distribution, ShotCount},
 };
 
 /// The primary interface for simulating and analyzing quantum states.
@@ -69,11 +77,12 @@ pub struct QuantumState {
 }
 
 /// Internal representation of the quantum state.
-/// Currently, only `StabilizerDecomposedState<Scalar>` is supported.
-/// Future extensions may include other types like `StabilizerDecomposedState<Complex64>`
-/// for Clifford gates other than T-gates.
+/// The compiler always produces `StabilizerDecomposedState<Scalar>`; the `Complex64` variant is
+/// obtained by converting its coefficients (see [`CoefficientType`]).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum InternalState {
     StabilizerDecomposedStateScalar(StabilizerDecomposedState<Scalar>),
+    StabilizerDecomposedStateComplex(StabilizerDecomposedState<Complex64>),
 }
 
 impl QuantumState {
@@ -101,78 +110,86 @@ impl QuantumState {
         Ok(Self { internal_state })
     }
 
-    /// Returns the statevector as an `Array1<Complex64>`.
+    /// Creates a new [`QuantumState`] by compiling a [`QuantumCircuit`] with the given
+    /// [`CompileStrategy`].
     ///
-    /// This function is primarily for testing and debugging purposes. It computes the full, dense
-    /// statevector of size `2^n`, which can be computationally expensive and memory-intensive for a
-    /// large number of qubits (`n`). This approach deviates from the core strength of the
-    /// stabilizer decomposition simulator, which is designed to efficiently handle systems with
-    /// large `n` by avoiding this explicit statevector representation.
+    /// [`QuantumState::from_circuit`] uses [`CompileStrategy::Batch`], which is suited to generic
+    /// circuits. [`CompileStrategy::Sequential`] can keep the intermediate number of stabilizer
+    /// components much smaller for structured circuits, e.g. when many T gates act on
+    /// computational basis states.
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
-    /// use num_complex::Complex64;
+    /// use necstar::state::CompileStrategy;
     ///
     /// let mut circuit = QuantumCircuit::new(2);
     /// circuit.apply_h(0);
     /// circuit.apply_cx(0, 1);
     /// circuit.apply_t(0);
     ///
-    /// let state = QuantumState::from_circuit(&circuit).unwrap();
-    /// let statevector = state.to_statevector().unwrap();
-    ///
-    /// assert!((statevector[0] - num_complex::Complex64::new(0.70710678, 0.0)).norm() < 1e-6);
-    /// assert!(statevector[1].norm() < 1e-6);
-    /// assert!(statevector[2].norm() < 1e-6);
-    /// assert!((statevector[3] - num_complex::Complex64::new(0.5, 0.5)).norm() < 1e-6);
+    /// let state =
+    ///     QuantumState::from_circuit_with_strategy(&circuit, CompileStrategy::Sequential).unwrap();
+    /// assert_eq!(state.stabilizer_rank(), 2);
     /// ```
     ///
+    /// ## Arguments
+    /// * `circuit` - A reference to the [`QuantumCircuit`] to be simulated.
+    /// * `strategy` - The order in which T-type gates are teleported.
+    ///
     /// ## Returns
-    /// A [`Result`] containing the statevector as an `Array1<Complex64>` or an
-    /// [`Error`](crate::error::Error).
-    pub fn to_statevector(&self) -> Result<Array1<num_complex::Complex64>> {
-        match &self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.to_statevector(),
-        }
+    /// A [`Result`] containing the compiled [`QuantumState`] or a [`Error`](crate::error::Error).
+    pub fn from_circuit_with_strategy(
+        circuit: &QuantumCircuit,
+        strategy: CompileStrategy,
+    ) -> Result<Self> {
+        let compiler = StabDecompCompiler::with_strategy(strategy);
+        let internal_state = compiler.compile(circuit)?;
+        Ok(Self { internal_state })
     }
 
-    /// Returns the inner product of the state and another state, i.e. ⟨self|other⟩.
+    /// Creates a new [`QuantumState`] by compiling a [`QuantumCircuit`] with a compiler
+    /// configured by [`StabDecompCompiler::builder`].
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
-    /// use num_complex::Complex64;
-    ///
-    /// let mut circuit1 = QuantumCircuit::new(1);
-    /// circuit1.apply_h(0);
-    /// let state1 = QuantumState::from_circuit(&circuit1).unwrap();
+    /// use necstar::state::StabDecompCompiler;
     ///
-    /// let mut circuit2 = QuantumCircuit::new(1);
-    /// circuit2.apply_x(0);
-    /// let state2 = QuantumState::from_circuit(&circuit2).unwrap();
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// for _ in 0..4 {
+    ///     circuit.apply_t(0);
+    /// }
     ///
-    /// let inner_prod = state1.inner_product(&state2).unwrap();
-    /// assert!((inner_prod - Complex64::new(0.70710678, 0.0)).norm() < 1e-6);
+    /// let compiler = StabDecompCompiler::builder().max_rank(2).build();
+    /// assert!(QuantumState::from_circuit_with(&circuit, &compiler).is_err());
     /// ```
     ///
     /// ## Arguments
-    /// * `other` - A reference to another [`QuantumState`] to compute the inner product with.
+    /// * `circuit` - A reference to the [`QuantumCircuit`] to be simulated.
+    /// * `compiler` - The [`StabDecompCompiler`] used for the compilation.
     ///
     /// ## Returns
-    /// A [`Result`] containing the inner product as `Complex64` or an
-    /// [`Error`](crate::error::Error).
-    pub fn inner_product(&self, other: &Self) -> Result<num_complex::Complex64> {
-        match (&self.internal_state, &other.internal_state) {
-            (
-                InternalState::StabilizerDecomposedStateScalar(state1),
-                InternalState::StabilizerDecomposedStateScalar(state2),
-            ) => state1.inner_product(state2),
+    /// A [`Result`] containing the compiled [`QuantumState`] or a [`Error`](crate::error::Error),
+    /// e.g. if the stabilizer rank exceeds the limit of the compiler.
+    pub fn from_circuit_with(
+        circuit: &QuantumCircuit,
+        compiler: &StabDecompCompiler,
+    ) -> Result<Self> {
+        let internal_state = compiler.compile(circuit)?;
+        let mut state = Self { internal_state };
+        if compiler.deduplicates() {
+            state.compress()?;
         }
+        Ok(state)
     }
 
-    /// Measure the specified qubits in the computational basis and return the measurement results.
-    /// The state gets collapsed according to the measurement results.
+    /// Writes the compiled state to `path`, so that it can be restored with
+    /// [`QuantumState::load`] without compiling the circuit again. Requires the `serde` feature.
+    ///
+    /// The file is a JSON document `{"version": 1, "state": ...}` holding the CH-forms of the
+    /// stabilizer components, their coefficients and the global factor.
     ///
     /// ## Examples
     /// ```rust
@@ -180,146 +197,384 @@ impl QuantumState {
     ///
     /// let mut circuit = QuantumCircuit::new(2);
     /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
     /// circuit.apply_cx(0, 1);
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
     ///
-    /// let result = state.measure(&[0, 1], Some([42; 32])).unwrap();
-    /// // For the Bell state, the possible outcomes are |00> or |11>
-    /// assert!(result == vec![false, false] || result == vec![true, true]);
+    /// let path = std::env::temp_dir().join("necstar_doc_save.json");
+    /// state.save(&path).unwrap();
+    /// let loaded = QuantumState::load(&path).unwrap();
+    /// assert!(loaded.approx_eq_with_phase(&state, 1e-10).unwrap());
+    /// # std::fs::remove_file(&path).unwrap();
     /// ```
     ///
     /// ## Arguments
-    /// * `qargs` - A slice of qubit indices to measure.
-    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
-    ///   If `None` is provided, a seed will be generated from system entropy.
+    /// * `path` - The path to the output file.
     ///
     /// ## Returns
-    /// A [`Result`] containing a vector of boolean measurement results or an
-    /// [`Error`](crate::error::Error). The length of the vector corresponds to `qargs.len()`.
-    /// The `i`-th element in the vector corresponds to the result of the qubit specified by
-    /// `qargs[i]`. `false` represents the `|0>` outcome, and `true` represents the `|1>` outcome.
-    pub fn measure(&mut self, qargs: &[usize], seed: Option<[u8; 32]>) -> Result<Vec<bool>> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.measure(qargs, seed),
-        }
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    #[cfg(feature = "serde")]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        serialization::save(&self.internal_state, path)
     }
 
-    /// Measure all qubits in the computational basis and return the measurement results.
-    /// The state gets collapsed according to the measurement results.
+    /// Restores a state written by [`QuantumState::save`]. Requires the `serde` feature.
+    ///
+    /// ## Arguments
+    /// * `path` - The path to the saved state.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the [`QuantumState`], or an [`Error`](crate::error::Error) if the
+    /// file cannot be read, is malformed, or has an unsupported format version.
+    #[cfg(feature = "serde")]
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let internal_state = serialization::load(path)?;
+        Ok(Self { internal_state })
+    }
+
+    /// Creates a new [`QuantumState`] by compiling a [`QuantumCircuit`] into a stabilizer
+    /// decomposition whose coefficients are stored as the given [`CoefficientType`].
+    ///
+    /// [`QuantumState::from_circuit`] uses [`CoefficientType::Scalar`], which is exact.
+    /// [`CoefficientType::Complex64`] stores floating-point coefficients instead, so that
+    /// [`compress`](Self::compress) can merge every pair of duplicate components.
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::state::CoefficientType;
     ///
     /// let mut circuit = QuantumCircuit::new(2);
     /// circuit.apply_h(0);
     /// circuit.apply_cx(0, 1);
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    /// circuit.apply_t(0);
     ///
-    /// let result = state.measure_all(Some([42; 32])).unwrap();
-    /// // For the Bell state, the possible outcomes are |00> or |11>
-    /// assert!(result == vec![false, false] || result == vec![true, true]);
+    /// let state =
+    ///     QuantumState::from_circuit_with_coefficient(&circuit, CoefficientType::Complex64)
+    ///         .unwrap();
+    /// let reference = QuantumState::from_circuit(&circuit).unwrap();
+    /// assert!(state.approx_eq_with_phase(&reference, 1e-8).unwrap());
     /// ```
+    ///
     /// ## Arguments
-    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
-    ///   If `None` is provided, a seed will be generated from system entropy.
+    /// * `circuit` - A reference to the [`QuantumCircuit`] to be simulated.
+    /// * `coefficient_type` - The numeric type of the coefficients.
     ///
     /// ## Returns
-    /// A [`Result`] containing a vector of boolean measurement results or an
-    /// [`Error`](crate::error::Error). The length of the vector corresponds to the number of qubits
-    /// in the state. The `i`-th element in the vector corresponds to the result of the qubit
-    /// specified by index `i`. `false` represents the `|0>` outcome, and `true` represents the
-    /// `|1>` outcome.
-    pub fn measure_all(&mut self, seed: Option<[u8; 32]>) -> Result<Vec<bool>> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.measure_all(seed),
+    /// A [`Result`] containing the compiled [`QuantumState`] or a [`Error`](crate::error::Error).
+    pub fn from_circuit_with_coefficient(
+        circuit: &QuantumCircuit,
+        coefficient_type: CoefficientType,
+    ) -> Result<Self> {
+        let compiler = StabDecompCompiler::new();
+        let internal_state = match (compiler.compile(circuit)?, coefficient_type) {
+            (InternalState::StabilizerDecomposedStateScalar(state), CoefficientType::Complex64) => {
+                InternalState::StabilizerDecomposedStateComplex(state.to_complex_coefficients())
+            }
+            (internal_state, _) => internal_state,
+        };
+        Ok(Self { internal_state })
+    }
+
+    /// Creates the graph state `Π_{(a,b) ∈ E} CZ_{ab} |+>^n` of the given graph.
+    ///
+    /// Graph states are stabilizer states, so the resulting state has stabilizer rank χ = 1.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::QuantumState;
+    ///
+    /// // 3-qubit ring
+    /// let state = QuantumState::graph_state(&[(0, 1), (1, 2), (2, 0)], 3).unwrap();
+    /// assert_eq!(state.stabilizer_rank(), 1);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `adjacency` - The edges of the graph as pairs of qubit indices.
+    /// * `n` - The number of qubits (vertices).
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the graph state or an [`Error`](crate::error::Error), e.g. if an
+    /// edge refers to a qubit index out of bounds.
+    pub fn graph_state(adjacency: &[(usize, usize)], n: usize) -> Result<Self> {
+        Self::from_circuit(&QuantumCircuit::graph_state(adjacency, n))
+    }
+
+    /// Creates a [`QuantumState`] directly from a stabilizer decomposition
+    /// `Σ_i coefficients[i] |stabilizers[i]>`, e.g. to inject a custom magic state that does
+    /// not come from a [`QuantumCircuit`].
+    ///
+    /// The state uses `Complex64` coefficients and is not normalized automatically; see
+    /// [`normalize`](Self::normalize).
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::QuantumState;
+    /// use necstar::types::StabilizerCHForm;
+    /// use num_complex::Complex64;
+    ///
+    /// // |T> ∝ |0> + e^{iπ/4}|1>
+    /// let zero = StabilizerCHForm::new(1).unwrap();
+    /// let mut one = StabilizerCHForm::new(1).unwrap();
+    /// one.apply_x(0).unwrap();
+    /// let coefficients = vec![
+    ///     Complex64::new(std::f64::consts::FRAC_1_SQRT_2, 0.0),
+    ///     Complex64::from_polar(std::f64::consts::FRAC_1_SQRT_2, std::f64::consts::FRAC_PI_4),
+    /// ];
+    /// let state = QuantumState::from_stabilizers(1, vec![zero, one], coefficients).unwrap();
+    /// assert!((state.norm().unwrap() - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `num_qubits` - The number of qubits of the state.
+    /// * `stabilizers` - The stabilizer components, each on `num_qubits` qubits.
+    /// * `coefficients` - The coefficient of each component.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the [`QuantumState`], or
+    /// [`Error::InvalidDecomposition`](crate::error::Error::InvalidDecomposition) if the numbers
+    /// of stabilizers and coefficients differ or a stabilizer has the wrong number of qubits.
+    pub fn from_stabilizers(
+        num_qubits: usize,
+        stabilizers: Vec<StabilizerCHForm>,
+        coefficients: Vec<Complex64>,
+    ) -> Result<Self> {
+        if num_qubits == 0 {
+            return Err(crate::error::Error::InvalidNumQubits(num_qubits));
         }
+        if stabilizers.len() != coefficients.len() {
+            return Err(crate::error::Error::InvalidDecomposition(format!(
+                "{} stabilizers but {} coefficients",
+                stabilizers.len(),
+                coefficients.len()
+            )));
+        }
+        if let Some(stab) = stabilizers
+            .iter()
+            .find(|stab| stab.num_qubits() != num_qubits)
+        {
+            return Err(crate::error::Error::InvalidDecomposition(format!(
+                "a stabilizer has {} qubits instead of {}",
+                stab.num_qubits(),
+                num_qubits
+            )));
+        }
+        Ok(Self {
+            internal_state: InternalState::StabilizerDecomposedStateComplex(
+                StabilizerDecomposedState::new(num_qubits, stabilizers, coefficients),
+            ),
+        })
     }
 
-    /// Samples measurement outcomes for the specified qubits without collapsing the quantum state.
+    /// Returns the tensor product `|self> ⊗ |other>` of two states, where the qubits of `self`
+    /// come first (indices `0` to `self.num_qubits() - 1`) and those of `other` follow.
+    ///
+    /// The decomposition of the result pairs every component of `self` with every component of
+    /// `other`, so its stabilizer rank is the product of the two ranks. The result has
+    /// `Complex64` coefficients if either input has.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_x(0);
+    /// let one = QuantumState::from_circuit(&circuit).unwrap();
+    /// let zero = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
+    ///
+    /// // |0> on qubit 0 and |1> on qubit 1
+    /// let state = zero.tensor(&one).unwrap();
+    /// assert_eq!(state.num_qubits(), 2);
+    /// assert!((state.to_statevector().unwrap()[0b10].re - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `other` - The state on the subsequent qubits.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the combined state or an [`Error`](crate::error::Error).
+    pub fn tensor(&self, other: &Self) -> Result<Self> {
+        let internal_state = match (&self.internal_state, &other.internal_state) {
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => InternalState::StabilizerDecomposedStateScalar(state1.kron(state2)?),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => InternalState::StabilizerDecomposedStateComplex(state1.kron(state2)?),
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => InternalState::StabilizerDecomposedStateComplex(
+                state1.to_complex_coefficients().kron(state2)?,
+            ),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => InternalState::StabilizerDecomposedStateComplex(
+                state1.kron(&state2.to_complex_coefficients())?,
+            ),
+        };
+        Ok(QuantumState { internal_state })
+    }
+
+    /// Returns the statevector as an `Array1<Complex64>`.
+    ///
+    /// This function is primarily for testing and debugging purposes. It computes the full, dense
+    /// statevector of size `2^n`, which can be computationally expensive and memory-intensive for a
+    /// large number of qubits (`n`). This approach deviates from the core strength of the
+    /// stabilizer decomposition simulator, which is designed to efficiently handle systems with
+    /// large `n` by avoiding this explicit statevector representation.
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use num_complex::Complex64;
     ///
     /// let mut circuit = QuantumCircuit::new(2);
     /// circuit.apply_h(0);
     /// circuit.apply_cx(0, 1);
+    /// circuit.apply_t(0);
+    ///
     /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let statevector = state.to_statevector().unwrap();
     ///
-    /// let shots = 1000;
-    /// let samples = state.sample(&[0, 1], shots, Some([42; 32])).unwrap();
-    /// // For the Bell state, the possible outcomes are |00> or |11>
-    /// assert!(samples.iter().all(|(outcome, _count)|
-    ///     outcome == &vec![false, false] || outcome == &vec![true, true]
-    /// ));
+    /// assert!((statevector[0] - num_complex::Complex64::new(0.70710678, 0.0)).norm() < 1e-6);
+    /// assert!(statevector[1].norm() < 1e-6);
+    /// assert!(statevector[2].norm() < 1e-6);
+    /// assert!((statevector[3] - num_complex::Complex64::new(0.5, 0.5)).norm() < 1e-6);
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the statevector as an `Array1<Complex64>` or an
+    /// [`Error`](crate::error::Error). To prevent accidental multi-GB allocations, states with
+    /// more than 28 qubits fail with
+    /// [`Error::StatevectorTooLarge`](crate::error::Error::StatevectorTooLarge) before anything
+    /// is allocated; see [`to_statevector_with_limit`](Self::to_statevector_with_limit) and
+    /// [`to_statevector_unchecked`](Self::to_statevector_unchecked) to lift the limit.
+    pub fn to_statevector(&self) -> Result<Array1<num_complex::Complex64>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.to_statevector(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.to_statevector(),
+        }
+    }
+
+    /// Same as [`to_statevector`](Self::to_statevector), but with a custom limit on the number
+    /// of qubits.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let state = QuantumState::from_circuit(&QuantumCircuit::new(3)).unwrap();
+    /// assert!(state.to_statevector_with_limit(2).is_err());
+    /// assert_eq!(state.to_statevector_with_limit(3).unwrap().len(), 8);
     /// ```
     ///
     /// ## Arguments
-    /// * `qargs` - A slice of qubit indices to sample.
-    /// * `shots` - The number of measurement samples to generate.
-    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
-    ///   If `None` is provided, a seed will be generated from system entropy.
+    /// * `max_qubits` - The largest number of qubits for which the statevector is computed.
     ///
     /// ## Returns
-    /// A [`Result`] containing a [`ShotCount`], which is a vector of tuples.
-    /// Each tuple consists of:
-    /// 1. `Vec<bool>`: A unique measurement outcome. The `i`-th element
-    ///    corresponds to the qubit at `qargs[i]`, where `false` for `|0>` and `true` for `|1>`.
-    /// 2. `usize`: The frequency (count) of this specific outcome across the total `shots`.
-    ///
-    /// The sum of all `usize` values in the returned vector equals `shots`. Note that it is not
-    /// supported to sample more than 128 qubits at once due to internal representation limits.
-    pub fn sample(
+    /// A [`Result`] containing the statevector, or
+    /// [`Error::StatevectorTooLarge`](crate::error::Error::StatevectorTooLarge) if the state has
+    /// more than `max_qubits` qubits.
+    pub fn to_statevector_with_limit(
         &self,
-        qargs: &[usize],
-        shots: usize,
-        seed: Option<[u8; 32]>,
-    ) -> Result<ShotCount> {
+        max_qubits: usize,
+    ) -> Result<Array1<num_complex::Complex64>> {
         match &self.internal_state {
             InternalState::StabilizerDecomposedStateScalar(state) => {
-                state.sample(qargs, shots, seed)
+                state.to_statevector_with_limit(max_qubits)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.to_statevector_with_limit(max_qubits)
             }
         }
     }
 
-    /// Returns the expectation value of a given observable represented as a [`PauliString`].
+    /// Same as [`to_statevector`](Self::to_statevector), but without the limit on the number of
+    /// qubits. The statevector has `2^n` entries of 16 bytes each, so this may exhaust the
+    /// available memory.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the statevector as an `Array1<Complex64>` or an
+    /// [`Error`](crate::error::Error).
+    pub fn to_statevector_unchecked(&self) -> Result<Array1<num_complex::Complex64>> {
+        self.to_statevector_with_limit(usize::MAX)
+    }
+
+    /// Computes the nonzero amplitudes of the state as `(index, amplitude)` pairs, sorted by the
+    /// little-endian index as in [`to_statevector`](Self::to_statevector).
+    ///
+    /// Only the supports of the stabilizer components are enumerated, so states with few
+    /// nonzero amplitudes are handled efficiently for any number of qubits up to 128.
+    /// Amplitudes that cancel between the components are omitted.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(100);
+    /// circuit.apply_h(0);
+    /// for q in 1..100 {
+    ///     circuit.apply_cx(0, q);
+    /// }
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let sparse = state.to_sparse_statevector().unwrap();
+    /// assert_eq!(sparse.len(), 2);
+    /// assert_eq!(sparse[1].0, (1u128 << 100) - 1);
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the nonzero amplitudes, or
+    /// [`Error::StatevectorTooLarge`](crate::error::Error::StatevectorTooLarge) if the state has
+    /// more than 128 qubits.
+    pub fn to_sparse_statevector(&self) -> Result<Vec<(u128, num_complex::Complex64)>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.to_sparse_statevector(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.to_sparse_statevector(),
+        }
+    }
+
+    /// Returns the amplitude ⟨bitstring|ψ⟩ of a computational basis state without building the
+    /// full statevector.
+    /// Time complexity: `O(χn^2)`
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
-    /// use necstar::types::PauliString;
-    /// use std::str::FromStr;
     ///
     /// let mut circuit = QuantumCircuit::new(2);
     /// circuit.apply_h(0);
     /// circuit.apply_cx(0, 1);
     /// let state = QuantumState::from_circuit(&circuit).unwrap();
     ///
-    /// let observable = PauliString::from_str("ZZ").unwrap();
-    /// let exp_val = state.exp_value(&observable).unwrap();
-    /// assert!((exp_val - 1.0).abs() < 1e-6);
+    /// let amp = state.amplitude(&[true, true]).unwrap(); // <11|ψ>
+    /// assert!((amp.re - 1.0 / 2.0_f64.sqrt()).abs() < 1e-10);
+    /// let amp = state.amplitude(&[true, false]).unwrap(); // <01|ψ>
+    /// assert!(amp.norm() < 1e-10);
     /// ```
     ///
     /// ## Arguments
-    /// * `pauli_string` - A reference to a [`PauliString`] representing the observable.
+    /// * `bitstring` - The basis state, where the `i`-th element corresponds to qubit `i`.
     ///
     /// ## Returns
-    /// A [`Result`] containing the expectation value as `f64` or an [`Error`](crate::error::Error).
-    pub fn exp_value(&self, pauli_string: &PauliString) -> Result<f64> {
+    /// A [`Result`] containing the amplitude as `Complex64` or an
+    /// [`Error`](crate::error::Error) if the length of `bitstring` differs from the number of
+    /// qubits.
+    pub fn amplitude(&self, bitstring: &[bool]) -> Result<num_complex::Complex64> {
         match &self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.exp_value(pauli_string),
+            InternalState::StabilizerDecomposedStateScalar(state) => state.amplitude(bitstring),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.amplitude(bitstring),
         }
     }
 
-    /// Projects the state onto a computational basis state (`|0>` or `|1>`) for a specific qubit,
-    /// then normalizes the entire quantum state.
+    /// Returns the amplitude of a computational basis state given by its integer index.
     ///
-    /// This operation is equivalent to a projective measurement in the Z-basis. The state is
-    /// modified in place. If the projection is impossible (e.g., projecting a definite `|0>` state
-    /// onto `|1>`), an error is returned. The resulting state after successful projection is
-    /// normalized to have a total norm of 1. If the projection fails, the behavior of the state is
-    /// undefined.
+    /// The index follows the same little-endian convention as
+    /// [`to_statevector`](Self::to_statevector), so `amplitude_at(i)` equals
+    /// `to_statevector()[i]`, but only the single amplitude is computed.
     ///
     /// ## Examples
     /// ```rust
@@ -328,370 +583,2088 @@ impl QuantumState {
     /// let mut circuit = QuantumCircuit::new(2);
     /// circuit.apply_h(0);
     /// circuit.apply_cx(0, 1);
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
-    ///
-    /// state.project_normalized(0, false).unwrap();
-    /// assert!((state.norm().unwrap() - 1.0).abs() < 1e-6);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
     ///
-    /// let statevector = state.to_statevector().unwrap();
-    /// assert!((statevector[0] - 1.0).norm() < 1e-6); // |00>
+    /// let amp = state.amplitude_at(3).unwrap(); // <11|ψ>
+    /// assert!((amp.re - 1.0 / 2.0_f64.sqrt()).abs() < 1e-10);
     /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to project.
-    /// * `outcome` - The desired computational basis state to project onto: `false` for `|0>`
-    ///   (the +1 eigenspace of Pauli Z) and `true` for `|1>` (the -1 eigenspace of Pauli Z).
+    /// * `index` - The basis state index in `0..2^n`, where bit `i` corresponds to qubit `i`.
     ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error) if the
-    /// projection is impossible.
-    pub fn project_normalized(&mut self, qubit: usize, outcome: bool) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => {
-                state.project_normalized(qubit, outcome)
-            }
+    /// A [`Result`] containing the amplitude as `Complex64` or an
+    /// [`Error`](crate::error::Error) if `index` is out of range.
+    pub fn amplitude_at(&self, index: usize) -> Result<num_complex::Complex64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.amplitude_at(index),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.amplitude_at(index),
         }
     }
 
-    #[cfg_attr(doc, katexit::katexit)]
-    /// Projects the state onto a computational basis state (`|0>` or `|1>`) for a specific qubit,
-    /// without normalizing the resulting state.
-    ///
-    /// The state is modified in place. After this operation, the total norm of the quantum state
-    /// will generally not equal 1. This method is useful for intermediate steps in algorithms
-    /// like sampling, where the normalization can be deferred.
+    /// Returns the inner product of the state and another state, i.e. ⟨self|other⟩.
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use num_complex::Complex64;
     ///
-    /// let mut circuit = QuantumCircuit::new(2);
-    /// circuit.apply_h(0);
-    /// circuit.apply_cx(0, 1);
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
-    ///
-    /// state.project_unnormalized(0, false).unwrap(); // Project qubit 0 onto |0>
-    /// let statevector = state.to_statevector().unwrap();
+    /// let mut circuit1 = QuantumCircuit::new(1);
+    /// circuit1.apply_h(0);
+    /// let state1 = QuantumState::from_circuit(&circuit1).unwrap();
     ///
-    /// // The norm is not 1 after unnormalized projection
-    /// assert!((state.norm().unwrap() - 0.70710678).abs() < 1e-6);
+    /// let mut circuit2 = QuantumCircuit::new(1);
+    /// circuit2.apply_x(0);
+    /// let state2 = QuantumState::from_circuit(&circuit2).unwrap();
     ///
-    /// // You can sample from the unnormalized state
-    /// let shots = 1000;
-    /// let samples = state.sample(&[0, 1], shots, Some([42; 32])).unwrap();
-    /// assert!(samples.iter().all(|(outcome, _count)|
-    ///    outcome == &vec![false, false]
-    /// ));
+    /// let inner_prod = state1.inner_product(&state2).unwrap();
+    /// assert!((inner_prod - Complex64::new(0.70710678, 0.0)).norm() < 1e-6);
     /// ```
     ///
-    /// The operation applies a projection operator `Π` to each stabilizer component `|ψ_i>`
-    /// of the state `|φ> = Σ_i c_i |ψ_i>`. The projector for qubit `j` and outcome `o ∈ {0, 1}` is:
-    /// $$
-    /// \Pi_j^{(o)} = \frac{I + (-1)^o Z_j}{2}
-    /// $$
-    /// The resulting unnormalized state is:
-    /// $$
-    /// \Pi_j^{(o)}|\phi\rangle = \sum_i c_i (\Pi_j^{(o)}|\psi_i\rangle)
-    /// $$
-    ///
-    /// ## Argument
-    /// * `qubit` - The index of the qubit to project.
-    /// * `outcome` - The desired computational basis state to project onto: `false` for `|0>` and
-    ///   `true` for `|1>`.
+    /// ## Arguments
+    /// * `other` - A reference to another [`QuantumState`] to compute the inner product with.
     ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success. Unlike
-    /// [`project_normalized`](Self::project_normalized), this function will not return an error
-    /// even if the projection results in a zero-norm state.
-    pub fn project_unnormalized(&mut self, qubit: usize, outcome: bool) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => {
-                state.project_unnormalized(qubit, outcome)
+    /// A [`Result`] containing the inner product as `Complex64` or an
+    /// [`Error`](crate::error::Error).
+    pub fn inner_product(&self, other: &Self) -> Result<num_complex::Complex64> {
+        match (&self.internal_state, &other.internal_state) {
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => state1.inner_product(state2),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.inner_product(state2),
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.to_complex_coefficients().inner_product(state2),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => {
+                let state2 = &state2.to_complex_coefficients();
+                state1.inner_product(state2)
             }
         }
     }
 
-    /// Removes a qubit from the quantum state, reducing the system size.
-    ///
-    /// This operation decreases the total number of qubits by one and modifies the
-    /// state in place.
+    /// Returns the fidelity between the state and another state, i.e. |⟨self|other⟩|^2.
     ///
-    /// ## Important
-    ///
-    /// This function **must** only be called on a qubit that has been projected to the `|0>` state
-    /// and is disentangled from all other qubits. The behavior is undefined if this precondition is
-    /// not met.
-    ///
-    /// For performance reasons, this function does not verify the qubit's state before discarding
-    /// it. The caller is responsible for ensuring this precondition is met, for example, by using
-    /// [`project_normalized`](Self::project_normalized) beforehand.
+    /// Both states are normalized internally, so the result lies in `[0, 1]` regardless of the
+    /// norms of the inputs.
     ///
     /// ## Examples
     /// ```rust
     /// use necstar::prelude::{QuantumCircuit, QuantumState};
     ///
-    /// let mut circuit = QuantumCircuit::new(2);
+    /// let mut circuit = QuantumCircuit::new(1);
     /// circuit.apply_h(0);
-    /// circuit.apply_cx(0, 1);
-    /// circuit.apply_t(0);
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
-    ///
-    /// state.project_normalized(0, false).unwrap(); // Project qubit 0 onto |0>
-    /// state.discard(0).unwrap(); // Discard qubit 0
+    /// let plus = QuantumState::from_circuit(&circuit).unwrap();
+    /// let zero = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
     ///
-    /// assert_eq!(state.num_qubits(), 1);
-    /// let statevector = state.to_statevector().unwrap();
-    /// assert!((statevector[0] - 1.0).norm() < 1e-6); // |0>
+    /// assert!((plus.fidelity(&zero).unwrap() - 0.5).abs() < 1e-10);
+    /// assert!((plus.fidelity(&plus).unwrap() - 1.0).abs() < 1e-10);
     /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to discard.
+    /// * `other` - The other quantum state.
     ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn discard(&mut self, qubit: usize) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.discard(qubit),
+    /// A [`Result`] containing the fidelity or an [`Error`](crate::error::Error) if the qubit
+    /// counts differ or either state has zero norm.
+    pub fn fidelity(&self, other: &Self) -> Result<f64> {
+        match (&self.internal_state, &other.internal_state) {
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => state1.fidelity(state2),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.fidelity(state2),
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.to_complex_coefficients().fidelity(state2),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => {
+                let state2 = &state2.to_complex_coefficients();
+                state1.fidelity(state2)
+            }
         }
     }
 
-    // ===== Gate Applications =====
-
-    /// Applies a [`QuantumGate`] to the quantum state.
-    /// Note: Only Clifford gates are supported for direct application.
+    /// Checks whether the state is equal to another state up to a global phase and
+    /// normalization, i.e. whether |⟨self|other⟩| / (‖self‖·‖other‖) is 1 within `tol`.
     ///
     /// ## Examples
     /// ```rust
-    /// use necstar::prelude::{QuantumCircuit, QuantumState, QuantumGate};
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
     ///
-    /// let mut circuit = QuantumCircuit::new(2);
-    /// circuit.apply_h(0);
+    /// let mut circuit1 = QuantumCircuit::new(1);
+    /// circuit1.apply_h(0);
+    /// let state1 = QuantumState::from_circuit(&circuit1).unwrap();
     ///
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
-    /// let gate = QuantumGate::CX(0, 1);
-    /// state.apply_gate(&gate).unwrap();
+    /// // Y|+> = -i|->, so Z Y|+> = -i|+>
+    /// let mut circuit2 = QuantumCircuit::new(1);
+    /// circuit2.apply_h(0);
+    /// circuit2.apply_y(0);
+    /// circuit2.apply_z(0);
+    /// let state2 = QuantumState::from_circuit(&circuit2).unwrap();
     ///
-    /// let statevector = state.to_statevector().unwrap();
-    /// assert!((statevector[0] - 0.70710678).norm() < 1e-6);
-    /// assert!(statevector[1].norm() < 1e-6);
-    /// assert!(statevector[2].norm() < 1e-6);
-    /// assert!((statevector[3] - 0.70710678).norm() < 1e-6);
+    /// assert!(state1.approx_eq(&state2, 1e-8).unwrap());
     /// ```
     ///
     /// ## Arguments
-    /// * `gate` - A reference to the [`QuantumGate`] to apply.
+    /// * `other` - A reference to another [`QuantumState`] to compare with.
+    /// * `tol` - The numerical tolerance.
     ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_gate(&mut self, gate: &QuantumGate) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_gate(gate),
+    /// A [`Result`] containing `true` if the states are equal up to a global phase, or an
+    /// [`Error`](crate::error::Error).
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> Result<bool> {
+        match (&self.internal_state, &other.internal_state) {
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => state1.approx_eq(state2, tol),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.approx_eq(state2, tol),
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.to_complex_coefficients().approx_eq(state2, tol),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => {
+                let state2 = &state2.to_complex_coefficients();
+                state1.approx_eq(state2, tol)
+            }
         }
     }
 
-    /// Applies a sequence of [`QuantumGate`]s to the quantum state.
-    /// Note: Only Clifford gates are supported for direct application.
+    /// Checks whether the state is equal to another state as a vector up to normalization,
+    /// including the global phase, i.e. whether ⟨self|other⟩ / (‖self‖·‖other‖) is 1 within
+    /// `tol`. Unlike [`QuantumState::approx_eq`], this distinguishes |ψ⟩ from −|ψ⟩.
     ///
     /// ## Examples
     /// ```rust
-    /// use necstar::prelude::{QuantumCircuit, QuantumState, QuantumGate};
-    /// use num_complex::Complex64;
-    ///
-    /// let mut circuit = QuantumCircuit::new(2);
-    /// circuit.apply_h(0);
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
     ///
-    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let mut circuit1 = QuantumCircuit::new(1);
+    /// circuit1.apply_h(0);
+    /// let state1 = QuantumState::from_circuit(&circuit1).unwrap();
     ///
-    /// let gates = vec![
-    ///     QuantumGate::CX(0, 1),
-    ///     QuantumGate::S(1),
-    /// ];
-    /// state.apply_gates(&gates).unwrap();
+    /// // Z X Z X = -I
+    /// let mut circuit2 = QuantumCircuit::new(1);
+    /// circuit2.apply_h(0);
+    /// for _ in 0..2 {
+    ///     circuit2.apply_z(0);
+    ///     circuit2.apply_x(0);
+    /// }
+    /// let state2 = QuantumState::from_circuit(&circuit2).unwrap();
     ///
-    /// let statevector = state.to_statevector().unwrap();
-    /// assert!((statevector[0] - Complex64::new(0.70710678, 0.0)).norm() < 1e-6);
-    /// assert!(statevector[1].norm() < 1e-6);
-    /// assert!(statevector[2].norm() < 1e-6);
-    /// assert!((statevector[3] - Complex64::new(0.0, 0.70710678)).norm() < 1e-6);
+    /// assert!(state1.approx_eq(&state2, 1e-8).unwrap());
+    /// assert!(!state1.approx_eq_with_phase(&state2, 1e-8).unwrap());
     /// ```
+    ///
     /// ## Arguments
-    /// * `gates` - A slice of [`QuantumGate`]s to apply.
+    /// * `other` - A reference to another [`QuantumState`] to compare with.
+    /// * `tol` - The numerical tolerance.
     ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_gates(&mut self, gates: &[QuantumGate]) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_gates(gates),
+    /// A [`Result`] containing `true` if the states are equal including the global phase, or an
+    /// [`Error`](crate::error::Error).
+    pub fn approx_eq_with_phase(&self, other: &Self, tol: f64) -> Result<bool> {
+        match (&self.internal_state, &other.internal_state) {
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => state1.approx_eq_with_phase(state2, tol),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1.approx_eq_with_phase(state2, tol),
+            (
+                InternalState::StabilizerDecomposedStateScalar(state1),
+                InternalState::StabilizerDecomposedStateComplex(state2),
+            ) => state1
+                .to_complex_coefficients()
+                .approx_eq_with_phase(state2, tol),
+            (
+                InternalState::StabilizerDecomposedStateComplex(state1),
+                InternalState::StabilizerDecomposedStateScalar(state2),
+            ) => {
+                let state2 = &state2.to_complex_coefficients();
+                state1.approx_eq_with_phase(state2, tol)
+            }
         }
     }
 
-    /// Applies a Pauli-X gate to the specified qubit.
-    /// Time complexity: `O(χn)`
+    /// Measure the specified qubits in the computational basis and return the measurement results.
+    /// The state gets collapsed according to the measurement results.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// let result = state.measure(&[0, 1], Some([42; 32])).unwrap();
+    /// // For the Bell state, the possible outcomes are |00> or |11>
+    /// assert!(result == vec![false, false] || result == vec![true, true]);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// * `qargs` - A slice of qubit indices to measure.
+    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
     ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_x(&mut self, qubit: usize) -> Result<()> {
+    /// A [`Result`] containing a vector of boolean measurement results or an
+    /// [`Error`](crate::error::Error). The length of the vector corresponds to `qargs.len()`.
+    /// The `i`-th element in the vector corresponds to the result of the qubit specified by
+    /// `qargs[i]`. `false` represents the `|0>` outcome, and `true` represents the `|1>` outcome.
+    pub fn measure(&mut self, qargs: &[usize], seed: Option<[u8; 32]>) -> Result<Vec<bool>> {
         match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_x(qubit),
+            InternalState::StabilizerDecomposedStateScalar(state) => state.measure(qargs, seed),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.measure(qargs, seed),
         }
     }
 
-    /// Applies a Pauli-Y gate to the specified qubit.
-    /// Time complexity: `O(χn)`
+    /// Same as [`measure`](Self::measure), but draws the random numbers from a caller-provided
+    /// random number generator instead of seeding a new one. This allows interleaving the draws
+    /// of the simulator with those of an external random stream.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let result = state.measure_with_rng(&[0, 1], &mut rng).unwrap();
+    /// assert!(result == vec![false, false] || result == vec![true, true]);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// * `qargs` - A slice of qubit indices to measure.
+    /// * `rng` - The random number generator to draw from.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_y(&mut self, qubit: usize) -> Result<()> {
+    /// A [`Result`] containing a vector of boolean measurement results or an
+    /// [`Error`](crate::error::Error), as in [`measure`](Self::measure).
+    pub fn measure_with_rng<R: RngCore + ?Sized>(
+        &mut self,
+        qargs: &[usize],
+        rng: &mut R,
+    ) -> Result<Vec<bool>> {
         match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_y(qubit),
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.measure_with_rng(qargs, rng)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.measure_with_rng(qargs, rng)
+            }
         }
     }
 
-    /// Applies a Pauli-Z gate to the specified qubit.
-    /// Time complexity: `O(χ)`
+    /// Measure all qubits in the computational basis and return the measurement results.
+    /// The state gets collapsed according to the measurement results.
     ///
-    /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
-    /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_z(&mut self, qubit: usize) -> Result<()> {
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// let result = state.measure_all(Some([42; 32])).unwrap();
+    /// // For the Bell state, the possible outcomes are |00> or |11>
+    /// assert!(result == vec![false, false] || result == vec![true, true]);
+    /// ```
+    /// ## Arguments
+    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing a vector of boolean measurement results or an
+    /// [`Error`](crate::error::Error). The length of the vector corresponds to the number of qubits
+    /// in the state. The `i`-th element in the vector corresponds to the result of the qubit
+    /// specified by index `i`. `false` represents the `|0>` outcome, and `true` represents the
+    /// `|1>` outcome.
+    pub fn measure_all(&mut self, seed: Option<[u8; 32]>) -> Result<Vec<bool>> {
         match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_z(qubit),
+            InternalState::StabilizerDecomposedStateScalar(state) => state.measure_all(seed),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.measure_all(seed),
         }
     }
 
-    /// Applies a Hadamard gate to the specified qubit.
-    /// Time complexity: `O(χn^2)`
+    /// Measure all qubits in the computational basis and return the outcome packed into an
+    /// integer. The state gets collapsed according to the measurement results.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// circuit.apply_x(0);
+    /// circuit.apply_x(2);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // |101>
     ///
+    /// assert_eq!(state.measure_all_int(Some([42; 32])).unwrap(), 0b101);
+    /// ```
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_h(&mut self, qubit: usize) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_h(qubit),
+    /// A [`Result`] containing the measurement outcome, whose `i`-th bit is the result of qubit
+    /// `i`, or [`Error::MeasurementTooManyQubits`](crate::error::Error::MeasurementTooManyQubits)
+    /// if the state has more than 128 qubits. In that case the state is left unmeasured.
+    pub fn measure_all_int(&mut self, seed: Option<[u8; 32]>) -> Result<u128> {
+        if self.num_qubits() > 128 {
+            return Err(crate::error::Error::MeasurementTooManyQubits);
         }
+        let outcome = self.measure_all(seed)?;
+        Ok(outcome
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &bit)| acc | ((bit as u128) << i)))
     }
 
-    /// Applies an S gate to the specified qubit.
-    /// Time complexity: `O(χn)`
+    /// Measures the Hermitian Pauli observable `pauli_string` and returns its eigenvalue
+    /// (`1` or `-1`). The state collapses onto the corresponding eigenspace.
+    ///
+    /// This generalizes [`measure`](Self::measure): measuring `Z_q` is equivalent to measuring
+    /// qubit `q` in the computational basis.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let circuit = QuantumCircuit::new(1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // |0>
+    ///
+    /// let x = PauliString::from_str("X").unwrap();
+    /// let eigenvalue = state.measure_observable(&x, Some([42; 32])).unwrap();
+    /// // The state collapsed to |+> or |->
+    /// assert!((state.exp_value(&x).unwrap() - eigenvalue as f64).abs() < 1e-10);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// * `pauli_string` - The [`PauliString`] observable to measure.
+    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_s(&mut self, qubit: usize) -> Result<()> {
+    /// A [`Result`] containing the measured eigenvalue or an [`Error`](crate::error::Error).
+    pub fn measure_observable(
+        &mut self,
+        pauli_string: &PauliString,
+        seed: Option<[u8; 32]>,
+    ) -> Result<i8> {
         match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_s(qubit),
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.measure_observable(pauli_string, seed)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.measure_observable(pauli_string, seed)
+            }
         }
     }
 
-    /// Applies an Sdg gate to the specified qubit.
-    /// Time complexity: `O(χn)`
+    /// Resets the specified qubit to `|0>`, regardless of its current state.
+    ///
+    /// The qubit is measured in the computational basis and flipped with `X` if the outcome was
+    /// `1`, so the rest of the state is conditioned on the (discarded) outcome.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// state.reset(0, Some([42; 32])).unwrap();
+    /// let z0 = PauliString::from_str("Z0").unwrap();
+    /// assert!((state.exp_value(&z0).unwrap() - 1.0).abs() < 1e-10);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
-    /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_sdg(&mut self, qubit: usize) -> Result<()> {
+    /// * `qubit` - The index of the qubit to reset.
+    /// * `seed` - An optional seed for the random number generator used by the measurement.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    pub fn reset(&mut self, qubit: usize, seed: Option<[u8; 32]>) -> Result<()> {
         match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_sdg(qubit),
+            InternalState::StabilizerDecomposedStateScalar(state) => state.reset(qubit, seed),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.reset(qubit, seed),
         }
     }
 
-    /// Applies a SqrtX gate to the specified qubit.
-    /// Time complexity: `O(χn^2)`
+    /// Measures the specified qubit in the computational basis, returns the outcome, and then
+    /// resets the qubit to `|0>` by applying `X` if the outcome was `1`.
+    ///
+    /// This is the same as [`reset`](Self::reset) with the same seed, but keeps the outcome,
+    /// e.g. to reuse a qubit after a mid-circuit measurement.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// let outcome = state.measure_and_reset(0, Some([42; 32])).unwrap();
+    /// let z0 = PauliString::from_str("Z0").unwrap();
+    /// let z1 = PauliString::from_str("Z1").unwrap();
+    /// assert!((state.exp_value(&z0).unwrap() - 1.0).abs() < 1e-10);
+    /// let expected_z1 = if outcome { -1.0 } else { 1.0 };
+    /// assert!((state.exp_value(&z1).unwrap() - expected_z1).abs() < 1e-10);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// * `qubit` - The index of the qubit to measure and reset.
+    /// * `seed` - An optional seed for the random number generator used by the measurement.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_sqrt_x(&mut self, qubit: usize) -> Result<()> {
+    /// A [`Result`] containing the measurement outcome (`false` for `|0>`, `true` for `|1>`), or
+    /// an [`Error`](crate::error::Error) if the qubit index is out of bounds.
+    pub fn measure_and_reset(&mut self, qubit: usize, seed: Option<[u8; 32]>) -> Result<bool> {
         match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_sqrt_x(qubit),
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.measure_and_reset(qubit, seed)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.measure_and_reset(qubit, seed)
+            }
         }
     }
 
-    /// Applies a SqrtXdg gate to the specified qubit.
-    /// Time complexity: `O(χn^2)`
+    /// Enumerates the possible outcomes of measuring a qubit without collapsing the quantum state.
+    ///
+    /// This is useful for exploring measurement trees exactly: each branch carries the
+    /// post-measurement state, which can be measured further.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let branches = state.measured_branches(0).unwrap();
+    /// assert_eq!(branches.len(), 2);
+    /// for (outcome, probability, post_state) in &branches {
+    ///     assert!((probability - 0.5).abs() < 1e-10);
+    ///     let sv = post_state.to_statevector().unwrap();
+    ///     assert!((sv[*outcome as usize].norm() - 1.0).abs() < 1e-10);
+    /// }
+    /// ```
     ///
     /// ## Arguments
-    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// * `qubit` - The index of the qubit to measure.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_sqrt_xdg(&mut self, qubit: usize) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_sqrt_xdg(qubit),
+    /// A [`Result`] containing a vector of branches or an [`Error`](crate::error::Error).
+    /// Each branch is a tuple of:
+    /// 1. `bool`: The measurement outcome, where `false` for `|0>` and `true` for `|1>`.
+    /// 2. `f64`: The probability of this outcome.
+    /// 3. [`QuantumState`]: The normalized post-measurement state.
+    ///
+    /// Outcomes with zero probability are omitted, so a deterministic qubit yields a single branch.
+    pub fn measured_branches(&self, qubit: usize) -> Result<Vec<(bool, f64, QuantumState)>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => Ok(state
+                .measured_branches(qubit)?
+                .into_iter()
+                .map(|(outcome, probability, branch)| {
+                    let post_state = QuantumState {
+                        internal_state: InternalState::StabilizerDecomposedStateScalar(branch),
+                    };
+                    (outcome, probability, post_state)
+                })
+                .collect()),
+            InternalState::StabilizerDecomposedStateComplex(state) => Ok(state
+                .measured_branches(qubit)?
+                .into_iter()
+                .map(|(outcome, probability, branch)| {
+                    let post_state = QuantumState {
+                        internal_state: InternalState::StabilizerDecomposedStateComplex(branch),
+                    };
+                    (outcome, probability, post_state)
+                })
+                .collect()),
         }
     }
 
-    /// Applies a CX (CNOT) gate.
-    /// Time complexity: `O(χn)`
+    /// Samples measurement outcomes for the specified qubits without collapsing the quantum state.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let shots = 1000;
+    /// let samples = state.sample(&[0, 1], shots, Some([42; 32])).unwrap();
+    /// // For the Bell state, the possible outcomes are |00> or |11>
+    /// assert!(samples.iter().all(|(outcome, _count)|
+    ///     outcome == &vec![false, false] || outcome == &vec![true, true]
+    /// ));
+    /// ```
     ///
     /// ## Arguments
-    /// * `control` - The index of the control qubit.
-    /// * `target` - The index of the target qubit.
+    /// * `qargs` - A slice of qubit indices to sample.
+    /// * `shots` - The number of measurement samples to generate.
+    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_cx(&mut self, control: usize, target: usize) -> Result<()> {
-        match &mut self.internal_state {
+    /// A [`Result`] containing a [`ShotCount`], which is a vector of tuples.
+    /// Each tuple consists of:
+    /// 1. `Vec<bool>`: A unique measurement outcome. The `i`-th element
+    ///    corresponds to the qubit at `qargs[i]`, where `false` for `|0>` and `true` for `|1>`.
+    /// 2. `usize`: The frequency (count) of this specific outcome across the total `shots`.
+    ///
+    /// The sum of all `usize` values in the returned vector equals `shots`. The outcomes are
+    /// sorted in ascending order of their little-endian integer representation, so the result
+    /// is stable for identical inputs and seed.
+    pub fn sample(
+        &self,
+        qargs: &[usize],
+        shots: usize,
+        seed: Option<[u8; 32]>,
+    ) -> Result<ShotCount> {
+        match &self.internal_state {
             InternalState::StabilizerDecomposedStateScalar(state) => {
-                state.apply_cx(control, target)
+                state.sample(qargs, shots, seed)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.sample(qargs, shots, seed)
             }
         }
     }
 
-    /// Applies a CZ gate.
-    /// Time complexity: `O(χn)`
+    /// Draws a single measurement outcome of the specified qubits without collapsing the state.
+    ///
+    /// This is a shorthand for [`sample`](Self::sample) with `shots = 1`.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let outcome = state.sample_one(&[0, 1], Some([42; 32])).unwrap();
+    /// assert!(outcome == vec![false, false] || outcome == vec![true, true]);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qarg1` - The index of the first qubit.
-    /// * `qarg2` - The index of the second qubit.
+    /// * `qargs` - A slice of qubit indices to sample.
+    /// * `seed` - An optional seed for the random number generator to ensure reproducibility.
+    ///   If `None` is provided, a seed will be generated from system entropy.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_cz(&mut self, qarg1: usize, qarg2: usize) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_cz(qarg1, qarg2),
-        }
+    /// A [`Result`] containing the outcome, where the `i`-th element corresponds to the qubit
+    /// at `qargs[i]`.
+    pub fn sample_one(&self, qargs: &[usize], seed: Option<[u8; 32]>) -> Result<Vec<bool>> {
+        let mut shot_count = self.sample(qargs, 1, seed)?;
+        debug_assert_eq!(shot_count.len(), 1);
+        Ok(shot_count
+            .pop()
+            .map(|(outcome, _)| outcome)
+            .unwrap_or_default())
     }
 
-    /// Applies a SWAP gate.
-    /// Time complexity: `O(χn)`
+    /// Same as [`sample`](Self::sample), but draws the random numbers from a caller-provided
+    /// random number generator instead of seeding a new one.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let samples = state.sample_with_rng(&[0, 1], 100, &mut StdRng::seed_from_u64(7)).unwrap();
+    /// let again = state.sample_with_rng(&[0, 1], 100, &mut StdRng::seed_from_u64(7)).unwrap();
+    /// assert_eq!(samples, again);
+    /// ```
     ///
     /// ## Arguments
-    /// * `qarg1` - The index of the first qubit.
-    /// * `qarg2` - The index of the second qubit.
+    /// * `qargs` - A slice of qubit indices to sample.
+    /// * `shots` - The number of measurement samples to generate.
+    /// * `rng` - The random number generator to draw from.
+    ///
     /// ## Returns
-    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
-    pub fn apply_swap(&mut self, qarg1: usize, qarg2: usize) -> Result<()> {
-        match &mut self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_swap(qarg1, qarg2),
+    /// A [`Result`] containing a [`ShotCount`] as in [`sample`](Self::sample).
+    pub fn sample_with_rng<R: RngCore + ?Sized>(
+        &self,
+        qargs: &[usize],
+        shots: usize,
+        rng: &mut R,
+    ) -> Result<ShotCount> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.sample_with_rng(qargs, shots, rng)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.sample_with_rng(qargs, shots, rng)
+            }
         }
     }
 
-    /// Returns the number of qubits in the quantum state.
+    /// Computes the exact measurement probabilities of the specified qubits as a dense vector.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let probs = state.probability_vector(&[0, 1]).unwrap();
+    /// // For the Bell state, only |00> and |11> have non-zero probability
+    /// assert!((probs[0] - 0.5).abs() < 1e-10);
+    /// assert!(probs[1].abs() < 1e-10);
+    /// assert!(probs[2].abs() < 1e-10);
+    /// assert!((probs[3] - 0.5).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qargs` - A slice of qubit indices whose outcome probabilities are computed.
     ///
     /// ## Returns
-    /// * `usize` - The number of qubits.
-    pub fn num_qubits(&self) -> usize {
+    /// A [`Result`] containing a vector of length `2^k` (`k = qargs.len()`) or an
+    /// [`Error`](crate::error::Error). The vector is indexed by the integer outcome in
+    /// little-endian order: the `i`-th bit of the index corresponds to the qubit `qargs[i]`.
+    /// The probabilities are computed exactly (not sampled) and sum to 1. Note that at most 24
+    /// qubits are supported, since the vector grows exponentially with `k`.
+    pub fn probability_vector(&self, qargs: &[usize]) -> Result<Vec<f64>> {
         match &self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.num_qubits,
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.probability_vector(qargs)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.probability_vector(qargs)
+            }
         }
     }
 
-    /// Returns the stabilizer rank χ (the number of stabilizer states in the decomposition)
-    /// of the internal stabilizer decomposed state.
+    /// Computes the exact (not sampled) distribution of the measurement outcomes of the specified
+    /// qubits.
+    ///
+    /// Unlike [`probability_vector`](Self::probability_vector), only the outcomes with non-zero
+    /// probability are returned, so that the size of the result is bounded by the support of the
+    /// state rather than `2^k`.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let distribution = state.probabilities(&[0, 1]).unwrap();
+    /// assert_eq!(distribution.len(), 2);
+    /// assert_eq!(distribution[0].0, vec![false, false]);
+    /// assert_eq!(distribution[1].0, vec![true, true]);
+    /// assert!((distribution[0].1 - 0.5).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qargs` - A slice of qubit indices whose outcome distribution is computed.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing pairs of outcomes and probabilities or an
+    /// [`Error`](crate::error::Error). The `i`-th element of each outcome corresponds to the
+    /// qubit `qargs[i]`, and the outcomes are sorted in ascending order of their little-endian
+    /// integer representation. At most 128 qubits are supported.
+    pub fn probabilities(&self, qargs: &[usize]) -> Result<Distribution> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.probabilities(qargs),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.probabilities(qargs),
+        }
+    }
+
+    /// Returns the `k` most probable measurement outcomes of the specified qubits with their
+    /// exact probabilities.
+    ///
+    /// The outcomes are found by a best-first search over the qubits: a partial outcome is only
+    /// extended while its probability, which bounds the probabilities of all its completions, can
+    /// still enter the top `k`. For a peaked distribution this visits far fewer branches than
+    /// [`probabilities`](Self::probabilities).
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
+    /// circuit.apply_h(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let top = state.most_probable(&[0, 1], 1).unwrap();
+    /// assert_eq!(top[0].0, vec![false, false]);
+    /// assert!((top[0].1 - 0.8535533905932737).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qargs` - A slice of qubit indices to measure.
+    /// * `k` - The number of outcomes to return.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing up to `k` pairs of outcomes and probabilities in descending order
+    /// of probability, or an [`Error`](crate::error::Error). The `i`-th element of each outcome
+    /// corresponds to the qubit `qargs[i]`. Fewer than `k` pairs are returned if fewer outcomes
+    /// have non-zero probability.
+    pub fn most_probable(&self, qargs: &[usize], k: usize) -> Result<Vec<(Vec<bool>, f64)>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.most_probable(qargs, k),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.most_probable(qargs, k),
+        }
+    }
+
+    /// Returns the expectation value of a given observable represented as a [`PauliString`].
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let observable = PauliString::from_str("ZZ").unwrap();
+    /// let exp_val = state.exp_value(&observable).unwrap();
+    /// assert!((exp_val - 1.0).abs() < 1e-6);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `pauli_string` - A reference to a [`PauliString`] representing the observable.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the expectation value as `f64` or an [`Error`](crate::error::Error).
+    /// Since the observable is Hermitian, the value is real; an imaginary part of `1e-8` or more
+    /// is reported as [`Error::NonRealExpectationValue`](crate::error::Error::NonRealExpectationValue)
+    /// instead of being discarded.
+    pub fn exp_value(&self, pauli_string: &PauliString) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.exp_value(pauli_string),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.exp_value(pauli_string),
+        }
+    }
+
+    /// Returns the expectation value `<P>` of a [`PauliString`] `P` together with its variance
+    /// `Var(P) = <P²> - <P>²`, e.g. to estimate the number of shots needed to measure `<P>`.
+    ///
+    /// Since a Pauli string is Hermitian and unitary, `P² = I` and the variance reduces to
+    /// `1 - <P>²`.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap(); // |+>
+    ///
+    /// let (mean, variance) = state
+    ///     .exp_value_with_variance(&PauliString::from_str("Z").unwrap())
+    ///     .unwrap();
+    /// assert!(mean.abs() < 1e-10);
+    /// assert!((variance - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `pauli_string` - A reference to a [`PauliString`] representing the observable.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the pair `(<P>, Var(P))` or an [`Error`](crate::error::Error).
+    pub fn exp_value_with_variance(&self, pauli_string: &PauliString) -> Result<(f64, f64)> {
+        let mean = self.exp_value(pauli_string)?;
+        // Clamp rounding errors so that the variance is never negative.
+        Ok((mean, (1.0 - mean * mean).max(0.0)))
+    }
+
+    /// Computes the expectation value of a Pauli observable acting only on the qubits `qargs`,
+    /// together with the distribution of the measurement outcomes of `qargs`.
+    ///
+    /// The observable uses the qubit indices of the full register. If it consists of `Z`
+    /// operators only, the expectation value is read off the distribution as the average of
+    /// `(-1)^parity`, so the state is traversed once; otherwise it is computed as in
+    /// [`exp_value`](Self::exp_value).
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    ///
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 2);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let zz = PauliString::from_str("Z0 Z2").unwrap();
+    /// let (exp_value, distribution) = state.marginal_expectation(&[0, 2], &zz).unwrap();
+    /// assert!((exp_value - 1.0).abs() < 1e-10);
+    /// assert_eq!(distribution.len(), 2);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qargs` - A slice of qubit indices whose outcome distribution is computed.
+    /// * `observable` - The Pauli observable, which must act as the identity outside `qargs`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the expectation value and the distribution as returned by
+    /// [`probabilities`](Self::probabilities), or
+    /// [`Error::PauliOutsideQubitSubset`](crate::error::Error::PauliOutsideQubitSubset) if the
+    /// observable acts on a qubit not in `qargs`.
+    #[doc(alias = "measure_expectation_subset")]
+    pub fn marginal_expectation(
+        &self,
+        qargs: &[usize],
+        observable: &PauliString,
+    ) -> Result<(f64, Distribution)> {
+        let PauliString::Sparse(terms) = observable.to_sparse() else {
+            unreachable!("to_sparse always returns a sparse Pauli string");
+        };
+        let mut positions = Vec::with_capacity(terms.len());
+        let mut diagonal = true;
+        for term in &terms {
+            let Some(position) = qargs.iter().position(|&q| q == term.qubit) else {
+                return Err(crate::error::Error::PauliOutsideQubitSubset(term.qubit));
+            };
+            positions.push(position);
+            diagonal &= term.op == Pauli::Z;
+        }
+
+        let distribution = self.probabilities(qargs)?;
+        let exp_value = if diagonal {
+            distribution
+                .iter()
+                .map(|(outcome, probability)| {
+                    let parity = positions.iter().filter(|&&i| outcome[i]).count() % 2;
+                    if parity == 1 {
+                        -probability
+                    } else {
+                        *probability
+                    }
+                })
+                .sum()
+        } else {
+            self.exp_value(observable)?
+        };
+        Ok((exp_value, distribution))
+    }
+
+    /// Calculates the expectation value of a weighted sum of Pauli strings, e.g. a Hamiltonian
+    /// `H = Σ_k w_k P_k` given as pairs `(w_k, P_k)`.
+    ///
+    /// This is equivalent to summing [`exp_value`](Self::exp_value) over the terms, but each
+    /// stabilizer component is evolved by every Pauli string only once and reused for all the
+    /// inner products.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let hamiltonian = vec![
+    ///     (0.5, PauliString::from_str("ZZ").unwrap()),
+    ///     (-2.0, PauliString::from_str("XX").unwrap()),
+    ///     (1.0, PauliString::from_str("ZI").unwrap()),
+    /// ];
+    /// let energy = state.exp_value_sum(&hamiltonian).unwrap();
+    /// assert!((energy - (-1.5)).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `observable` - The pairs of weights and Pauli strings.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the expectation value as `f64` or an [`Error`](crate::error::Error)
+    /// if any Pauli string does not fit the number of qubits.
+    pub fn exp_value_sum(&self, observable: &[(f64, PauliString)]) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.exp_value_sum(observable)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.exp_value_sum(observable)
+            }
+        }
+    }
+
+    /// Returns the expectation values of several observables, in the same order as `paulis`.
+    ///
+    /// This is equivalent to calling [`exp_value`](Self::exp_value) for each Pauli string, but
+    /// each stabilizer component is evolved by every Pauli string only once and the pairs of
+    /// components are traversed once for all the observables.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use std::str::FromStr;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let paulis = vec![
+    ///     PauliString::from_str("ZZ").unwrap(),
+    ///     PauliString::from_str("ZI").unwrap(),
+    ///     PauliString::from_str("YY").unwrap(),
+    /// ];
+    /// let exp_vals = state.exp_value_batch(&paulis).unwrap();
+    /// assert!((exp_vals[0] - 1.0).abs() < 1e-10);
+    /// assert!(exp_vals[1].abs() < 1e-10);
+    /// assert!((exp_vals[2] + 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `paulis` - The observables.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the expectation values or an [`Error`](crate::error::Error) if any
+    /// Pauli string does not fit the number of qubits.
+    pub fn exp_value_batch(&self, paulis: &[PauliString]) -> Result<Vec<f64>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.exp_value_batch(paulis),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.exp_value_batch(paulis),
+        }
+    }
+
+    /// Returns the reduced density matrix `ρ_A = Tr_B |ψ><ψ|` over the given qubits, obtained by
+    /// tracing out the rest of the qubits. The state is normalized first.
+    ///
+    /// The matrix is built from the `4^k` Pauli expectation values on the `k` qubits, so at most
+    /// 8 qubits are supported.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// // One half of a Bell pair is maximally mixed.
+    /// let rho = state.reduced_density_matrix(&[0]).unwrap();
+    /// assert!((rho[[0, 0]].re - 0.5).abs() < 1e-10);
+    /// assert!((rho[[1, 1]].re - 0.5).abs() < 1e-10);
+    /// assert!(rho[[0, 1]].norm() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qubits` - The qubit indices of the subsystem to keep.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the `2^k × 2^k` density matrix, whose row and column indices have
+    /// the `i`-th bit (little-endian) corresponding to the qubit `qubits[i]`, or an
+    /// [`Error`](crate::error::Error) if the qubit indices are invalid or there are more than 8.
+    pub fn reduced_density_matrix(&self, qubits: &[usize]) -> Result<Array2<Complex64>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.reduced_density_matrix(qubits)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.reduced_density_matrix(qubits)
+            }
+        }
+    }
+
+    /// Returns the von Neumann entropy `-Tr ρ_A ln ρ_A` of the reduced state `ρ_A` on the given
+    /// qubits, i.e. the entanglement entropy between them and the rest of the qubits.
+    ///
+    /// The reduced density matrix of the smaller of the subsystem and its complement is built
+    /// from `4^k` Pauli expectation values, so this is feasible only if one of them has at most
+    /// 8 qubits.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let entropy = state.entanglement_entropy(&[0]).unwrap();
+    /// assert!((entropy - std::f64::consts::LN_2).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qubits` - The qubit indices of the subsystem.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the entropy in nats, or an [`Error`](crate::error::Error) if the
+    /// qubit indices are invalid or both the subsystem and its complement are too large.
+    pub fn entanglement_entropy(&self, qubits: &[usize]) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.entanglement_entropy(qubits)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.entanglement_entropy(qubits)
+            }
+        }
+    }
+
+    /// Returns the expectation value of a single-qubit Pauli operator acting on `qubit`.
+    ///
+    /// This is a fast path of [`exp_value`](Self::exp_value) that applies only the single-qubit
+    /// gate to each stabilizer component instead of a full-width [`PauliString`].
+    /// Note that [`exp_value`](Self::exp_value) automatically uses this path for Pauli strings
+    /// with a single non-identity factor.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::Pauli;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let exp_val = state.single_qubit_exp_value(1, Pauli::X).unwrap();
+    /// assert!((exp_val - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit the Pauli operator acts on.
+    /// * `pauli` - The single-qubit [`Pauli`] operator.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the expectation value as `f64` or an [`Error`](crate::error::Error).
+    pub fn single_qubit_exp_value(&self, qubit: usize, pauli: Pauli) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.single_qubit_exp_value(qubit, pauli)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.single_qubit_exp_value(qubit, pauli)
+            }
+        }
+    }
+
+    /// Returns the expectation value of a diagonal observable `diag(f)`, i.e.
+    /// `Σ_x |<x|ψ>|^2 f(x)`, where `f` is an arbitrary function of the computational basis state.
+    ///
+    /// The computational basis states with non-zero probability are enumerated exactly, so the
+    /// cost grows with the size of the support of the state.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// // Expected Hamming weight of the Bell state: (0 + 2) / 2 = 1
+    /// let hamming_weight = |bits: &[bool]| bits.iter().filter(|&&b| b).count() as f64;
+    /// let exp_val = state.diagonal_expectation(hamming_weight).unwrap();
+    /// assert!((exp_val - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `f` - A function mapping a computational basis state to its diagonal entry. The `i`-th
+    ///   element of the slice corresponds to qubit `i`, where `false` for `|0>` and `true` for `|1>`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the expectation value as `f64` or an [`Error`](crate::error::Error).
+    pub fn diagonal_expectation(&self, f: impl Fn(&[bool]) -> f64) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.diagonal_expectation(f),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.diagonal_expectation(f),
+        }
+    }
+
+    /// Projects the state onto a computational basis state (`|0>` or `|1>`) for a specific qubit,
+    /// then normalizes the entire quantum state.
+    ///
+    /// This operation is equivalent to a projective measurement in the Z-basis. The state is
+    /// modified in place. If the projection is impossible (e.g., projecting a definite `|0>` state
+    /// onto `|1>`), an error is returned. The resulting state after successful projection is
+    /// normalized to have a total norm of 1. If the projection fails, the behavior of the state is
+    /// undefined.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// state.project_normalized(0, false).unwrap();
+    /// assert!((state.norm().unwrap() - 1.0).abs() < 1e-6);
+    ///
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[0] - 1.0).norm() < 1e-6); // |00>
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to project.
+    /// * `outcome` - The desired computational basis state to project onto: `false` for `|0>`
+    ///   (the +1 eigenspace of Pauli Z) and `true` for `|1>` (the -1 eigenspace of Pauli Z).
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error) if the
+    /// projection is impossible.
+    pub fn project_normalized(&mut self, qubit: usize, outcome: bool) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.project_normalized(qubit, outcome)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.project_normalized(qubit, outcome)
+            }
+        }
+    }
+
+    #[cfg_attr(doc, katexit::katexit)]
+    /// Projects the state onto a computational basis state (`|0>` or `|1>`) for a specific qubit,
+    /// without normalizing the resulting state.
+    ///
+    /// The state is modified in place. After this operation, the total norm of the quantum state
+    /// will generally not equal 1. This method is useful for intermediate steps in algorithms
+    /// like sampling, where the normalization can be deferred.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// state.project_unnormalized(0, false).unwrap(); // Project qubit 0 onto |0>
+    /// let statevector = state.to_statevector().unwrap();
+    ///
+    /// // The norm is not 1 after unnormalized projection
+    /// assert!((state.norm().unwrap() - 0.70710678).abs() < 1e-6);
+    ///
+    /// // You can sample from the unnormalized state
+    /// let shots = 1000;
+    /// let samples = state.sample(&[0, 1], shots, Some([42; 32])).unwrap();
+    /// assert!(samples.iter().all(|(outcome, _count)|
+    ///    outcome == &vec![false, false]
+    /// ));
+    /// ```
+    ///
+    /// The operation applies a projection operator `Π` to each stabilizer component `|ψ_i>`
+    /// of the state `|φ> = Σ_i c_i |ψ_i>`. The projector for qubit `j` and outcome `o ∈ {0, 1}` is:
+    /// $$
+    /// \Pi_j^{(o)} = \frac{I + (-1)^o Z_j}{2}
+    /// $$
+    /// The resulting unnormalized state is:
+    /// $$
+    /// \Pi_j^{(o)}|\phi\rangle = \sum_i c_i (\Pi_j^{(o)}|\psi_i\rangle)
+    /// $$
+    ///
+    /// ## Argument
+    /// * `qubit` - The index of the qubit to project.
+    /// * `outcome` - The desired computational basis state to project onto: `false` for `|0>` and
+    ///   `true` for `|1>`.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success. Unlike
+    /// [`project_normalized`](Self::project_normalized), this function will not return an error
+    /// even if the projection results in a zero-norm state.
+    pub fn project_unnormalized(&mut self, qubit: usize, outcome: bool) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.project_unnormalized(qubit, outcome)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.project_unnormalized(qubit, outcome)
+            }
+        }
+    }
+
+    /// Projects several qubits onto computational basis states at once, optionally normalizing
+    /// the state at the end.
+    ///
+    /// This is equivalent to calling [`project_unnormalized`](Self::project_unnormalized) for
+    /// each qubit, followed by a single normalization, so the norm is computed only once instead
+    /// of after every projection as with [`project_normalized`](Self::project_normalized). If
+    /// the projection fails, the behavior of the state is undefined.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_cx(0, 2);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // GHZ state
+    ///
+    /// state.project_multi(&[(0, true), (2, true)], true).unwrap();
+    /// assert!((state.to_statevector().unwrap()[0b111].norm() - 1.0).abs() < 1e-10);
+    /// assert!(state.project_multi(&[(1, false)], true).is_err());
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `outcomes` - Pairs of a distinct qubit index and the desired outcome: `false` for `|0>`
+    ///   and `true` for `|1>`.
+    /// * `normalize` - Whether to normalize the projected state.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error) if an
+    /// index is out of bounds or duplicated. With `normalize`, a zero-norm result fails with
+    /// [`Error::ImpossibleProjection`](crate::error::Error::ImpossibleProjection) naming the
+    /// first qubit after which no stabilizer component is left; if the components cancel out
+    /// instead, the last qubit of `outcomes` is named.
+    pub fn project_multi(&mut self, outcomes: &[(usize, bool)], normalize: bool) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.project_multi(outcomes, normalize)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.project_multi(outcomes, normalize)
+            }
+        }
+    }
+
+    /// Computes the probability that post-selecting the given qubits onto the given values
+    /// succeeds, without modifying the state.
+    ///
+    /// This is the Born-rule probability of the partial assignment, i.e. the marginal
+    /// probability that measuring the given qubits yields the given values, which is useful
+    /// e.g. for importance sampling. The expected number of repetitions until the post-selection
+    /// succeeds is the inverse of this probability.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap(); // Bell state
+    ///
+    /// let p = state.postselection_probability(&[(0, false), (1, false)]).unwrap();
+    /// assert!((p - 0.5).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `conditions` - Pairs of `(qubit, outcome)` to post-select on. `true` represents
+    ///   outcome `1`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the success probability or an [`Error`](crate::error::Error).
+    pub fn postselection_probability(&self, conditions: &[(usize, bool)]) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.postselection_probability(conditions)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.postselection_probability(conditions)
+            }
+        }
+    }
+
+    /// Removes a qubit from the quantum state, reducing the system size.
+    ///
+    /// This operation decreases the total number of qubits by one and modifies the
+    /// state in place.
+    ///
+    /// ## Important
+    ///
+    /// This function **must** only be called on a qubit that has been projected to the `|0>` state
+    /// and is disentangled from all other qubits. The behavior is undefined if this precondition is
+    /// not met.
+    ///
+    /// For performance reasons, this function does not verify the qubit's state before discarding
+    /// it. The caller is responsible for ensuring this precondition is met, for example, by using
+    /// [`project_normalized`](Self::project_normalized) beforehand.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_t(0);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// state.project_normalized(0, false).unwrap(); // Project qubit 0 onto |0>
+    /// state.discard(0).unwrap(); // Discard qubit 0
+    ///
+    /// assert_eq!(state.num_qubits(), 1);
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[0] - 1.0).norm() < 1e-6); // |0>
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to discard.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn discard(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.discard(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.discard(qubit),
+        }
+    }
+
+    /// Appends fresh ancilla qubits in the `|0>` state to the quantum state.
+    ///
+    /// This is the counterpart of [`discard`](Self::discard). The new qubits are unentangled with
+    /// the existing ones and get the indices `num_qubits()..num_qubits() + count`.
+    /// The stabilizer rank χ is unchanged.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_x(0);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // |1>
+    ///
+    /// state.add_qubits(1).unwrap(); // |0> ⊗ |1>
+    ///
+    /// assert_eq!(state.num_qubits(), 2);
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[1] - 1.0).norm() < 1e-6); // |01>
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `count` - The number of qubits to append.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn add_qubits(&mut self, count: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.add_qubits(count),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.add_qubits(count),
+        }
+    }
+
+    // ===== Gate Applications =====
+
+    /// Applies a [`QuantumGate`] to the quantum state.
+    /// Note: Only Clifford gates are supported for direct application.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState, QuantumGate};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    ///
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let gate = QuantumGate::CX(0, 1);
+    /// state.apply_gate(&gate).unwrap();
+    ///
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[0] - 0.70710678).norm() < 1e-6);
+    /// assert!(statevector[1].norm() < 1e-6);
+    /// assert!(statevector[2].norm() < 1e-6);
+    /// assert!((statevector[3] - 0.70710678).norm() < 1e-6);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `gate` - A reference to the [`QuantumGate`] to apply.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_gate(&mut self, gate: &QuantumGate) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_gate(gate),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_gate(gate),
+        }
+    }
+
+    /// Applies a [`QuantumGate`] to the quantum state only if `condition` is `true`, e.g. a
+    /// correction conditioned on a previous measurement outcome.
+    /// Note: Only Clifford gates are supported for direct application.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState, QuantumGate};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    ///
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let outcome = state.measure(&[0], Some([42; 32])).unwrap();
+    /// // Copy the measured bit to qubit 1.
+    /// state.apply_gate_if(&QuantumGate::X(1), outcome[0]).unwrap();
+    ///
+    /// assert_eq!(state.measure(&[1], None).unwrap(), outcome);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `gate` - A reference to the [`QuantumGate`] to apply.
+    /// * `condition` - Whether the gate is applied.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error). The gate
+    /// is only validated when it is applied.
+    pub fn apply_gate_if(&mut self, gate: &QuantumGate, condition: bool) -> Result<()> {
+        if condition {
+            self.apply_gate(gate)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Applies a sequence of [`QuantumGate`]s to the quantum state.
+    /// Note: Only Clifford gates are supported for direct application.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState, QuantumGate};
+    /// use num_complex::Complex64;
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    ///
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let gates = vec![
+    ///     QuantumGate::CX(0, 1),
+    ///     QuantumGate::S(1),
+    /// ];
+    /// state.apply_gates(&gates).unwrap();
+    ///
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[0] - Complex64::new(0.70710678, 0.0)).norm() < 1e-6);
+    /// assert!(statevector[1].norm() < 1e-6);
+    /// assert!(statevector[2].norm() < 1e-6);
+    /// assert!((statevector[3] - Complex64::new(0.0, 0.70710678)).norm() < 1e-6);
+    /// ```
+    /// ## Arguments
+    /// * `gates` - A slice of [`QuantumGate`]s to apply.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_gates(&mut self, gates: &[QuantumGate]) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_gates(gates),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_gates(gates),
+        }
+    }
+
+    /// Applies the gates of a Clifford [`QuantumCircuit`] to the quantum state in place.
+    ///
+    /// Unlike [`apply_gates`](Self::apply_gates), all the gates are checked before the state is
+    /// modified, so a circuit containing a non-Clifford gate (e.g. `T` or `CCX`) leaves the state
+    /// unchanged. Measurements recorded in the circuit are ignored.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut state = QuantumState::from_circuit(&QuantumCircuit::new(2)).unwrap();
+    ///
+    /// let mut bell = QuantumCircuit::new(2);
+    /// bell.apply_h(0);
+    /// bell.apply_cx(0, 1);
+    /// state.apply_circuit(&bell).unwrap();
+    ///
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[0].re - 0.70710678).abs() < 1e-6);
+    /// assert!((statevector[3].re - 0.70710678).abs() < 1e-6);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `circuit` - A Clifford circuit on the same number of qubits as the state.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or
+    /// [`Error::QubitCountMismatch`](crate::error::Error::QubitCountMismatch) if the qubit counts
+    /// differ, or
+    /// [`Error::NonCliffordGateApplication`](crate::error::Error::NonCliffordGateApplication) if
+    /// the circuit contains a non-Clifford gate.
+    pub fn apply_circuit(&mut self, circuit: &QuantumCircuit) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_circuit(circuit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_circuit(circuit),
+        }
+    }
+
+    /// Relabels the qubits of the state so that the new qubit `i` is the old qubit `perm[i]`.
+    /// This acts directly on every stabilizer component and is cheaper than a chain of SWAP
+    /// gates.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(3);
+    /// circuit.apply_x(0);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap(); // |001>
+    ///
+    /// // The new qubit 2 is the old qubit 0.
+    /// state.permute_qubits(&[1, 2, 0]).unwrap();
+    /// assert_eq!(state.measure_all(None).unwrap(), vec![false, false, true]);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `perm` - A permutation of `[0, 1, ..., num_qubits - 1]`.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error) if `perm`
+    /// has the wrong length or is not a bijection, in which case the state is left unchanged.
+    pub fn permute_qubits(&mut self, perm: &[usize]) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.permute_qubits(perm),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.permute_qubits(perm),
+        }
+    }
+
+    /// Applies the Pauli operator represented by a [`PauliString`] to the state.
+    /// Time complexity: `O(χn)` per non-identity factor
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use necstar::types::PauliString;
+    /// use num_complex::Complex64;
+    /// use std::str::FromStr;
+    ///
+    /// let mut state = QuantumState::from_circuit(&QuantumCircuit::new(2)).unwrap();
+    /// state.apply_pauli_string(&PauliString::from_str("XX").unwrap()).unwrap();
+    ///
+    /// let statevector = state.to_statevector().unwrap();
+    /// assert!((statevector[3] - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `pauli_string` - The Pauli operator to apply. A dense string must have exactly
+    ///   `num_qubits` characters, and a sparse string must act only on existing qubits.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_pauli_string(&mut self, pauli_string: &PauliString) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.apply_pauli_string(pauli_string)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.apply_pauli_string(pauli_string)
+            }
+        }
+    }
+
+    /// Applies a Pauli-X gate to the specified qubit.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_x(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_x(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_x(qubit),
+        }
+    }
+
+    /// Applies a Pauli-Y gate to the specified qubit.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_y(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_y(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_y(qubit),
+        }
+    }
+
+    /// Applies a Pauli-Z gate to the specified qubit.
+    /// Time complexity: `O(χ)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_z(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_z(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_z(qubit),
+        }
+    }
+
+    /// Applies a Hadamard gate to the specified qubit.
+    /// Time complexity: `O(χn^2)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_h(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_h(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_h(qubit),
+        }
+    }
+
+    /// Applies an S gate to the specified qubit.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_s(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_s(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_s(qubit),
+        }
+    }
+
+    /// Applies an Sdg gate to the specified qubit.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_sdg(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_sdg(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_sdg(qubit),
+        }
+    }
+
+    /// Applies a SqrtX gate to the specified qubit.
+    /// Time complexity: `O(χn^2)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_sqrt_x(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_sqrt_x(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_sqrt_x(qubit),
+        }
+    }
+
+    /// Applies a SqrtXdg gate to the specified qubit.
+    /// Time complexity: `O(χn^2)`
+    ///
+    /// ## Arguments
+    /// * `qubit` - The index of the qubit to apply the gate to.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_sqrt_xdg(&mut self, qubit: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_sqrt_xdg(qubit),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_sqrt_xdg(qubit),
+        }
+    }
+
+    /// Applies a CX (CNOT) gate.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `control` - The index of the control qubit.
+    /// * `target` - The index of the target qubit.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_cx(&mut self, control: usize, target: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.apply_cx(control, target)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.apply_cx(control, target)
+            }
+        }
+    }
+
+    /// Applies a CZ gate.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `qarg1` - The index of the first qubit.
+    /// * `qarg2` - The index of the second qubit.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_cz(&mut self, qarg1: usize, qarg2: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_cz(qarg1, qarg2),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.apply_cz(qarg1, qarg2),
+        }
+    }
+
+    /// Applies a SWAP gate.
+    /// Time complexity: `O(χn)`
+    ///
+    /// ## Arguments
+    /// * `qarg1` - The index of the first qubit.
+    /// * `qarg2` - The index of the second qubit.
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn apply_swap(&mut self, qarg1: usize, qarg2: usize) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.apply_swap(qarg1, qarg2),
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.apply_swap(qarg1, qarg2)
+            }
+        }
+    }
+
+    /// Returns the number of qubits in the quantum state.
+    ///
+    /// ## Returns
+    /// * `usize` - The number of qubits.
+    pub fn num_qubits(&self) -> usize {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.num_qubits,
+            InternalState::StabilizerDecomposedStateComplex(state) => state.num_qubits,
+        }
+    }
+
+    /// Returns the stabilizer rank χ (the number of stabilizer states in the decomposition)
+    /// of the internal stabilizer decomposed state.
+    ///
+    /// ## Returns
+    /// * `usize` - The stabilizer rank.
+    pub fn stabilizer_rank(&self) -> usize {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.stabilizers.len(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.stabilizers.len(),
+        }
+    }
+
+    /// Returns the terms `(|φ_i>, c_i)` of the decomposition `|ψ> = Σ_i c_i |φ_i>`.
+    ///
+    /// The stabilizer components are cloned, so the decomposition of the state cannot be
+    /// modified through them. The coefficients include the global factor of the state.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    /// use num_complex::Complex64;
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// let amplitude: Complex64 = state
+    ///     .terms()
+    ///     .iter()
+    ///     .map(|(stab, c)| c * stab.amplitude(&[true]).unwrap())
+    ///     .sum();
+    /// assert!((amplitude - state.amplitude(&[true]).unwrap()).norm() < 1e-10);
+    /// ```
+    ///
+    /// ## Returns
+    /// A vector of `stabilizer_rank()` pairs of a [`StabilizerCHForm`] and its coefficient.
+    pub fn terms(&self) -> Vec<(StabilizerCHForm, Complex64)> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.cloned_terms(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.cloned_terms(),
+        }
+    }
+
+    /// Returns the magnitudes `|c_i|` of the coefficients of the decomposition
+    /// `|ψ> = Σ_i c_i |φ_i>`, in the order of [`terms`](Self::terms).
+    ///
+    /// By the triangle inequality, their sum bounds the [`norm`](Self::norm) from above.
+    ///
+    /// ## Returns
+    /// A vector of `stabilizer_rank()` non-negative magnitudes.
+    pub fn coefficient_magnitudes(&self) -> Vec<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.coefficient_magnitudes().collect()
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.coefficient_magnitudes().collect()
+            }
+        }
+    }
+
+    /// Returns the contribution `c_i <bitstring|φ_i>` of each term of the decomposition to the
+    /// amplitude `<bitstring|ψ>`, in the order of [`terms`](Self::terms).
+    ///
+    /// ## Arguments
+    /// * `bitstring` - The basis state, where the `i`-th element corresponds to qubit `i`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the contributions, which sum to
+    /// [`amplitude`](Self::amplitude), or
+    /// [`Error::InvalidBitstringLength`](crate::error::Error::InvalidBitstringLength) if the
+    /// length of `bitstring` differs from the number of qubits.
+    pub fn term_amplitudes(&self, bitstring: &[bool]) -> Result<Vec<Complex64>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                state.term_amplitudes(bitstring)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.term_amplitudes(bitstring)
+            }
+        }
+    }
+
+    /// Compresses the stabilizer decomposition in place by merging the stabilizer components
+    /// that are equal up to global phase and dropping the components with zero coefficients.
+    ///
+    /// The represented state is unchanged, while the stabilizer rank χ, and thus the cost of
+    /// the subsequent operations, may decrease.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// // Teleporting the three T gates produces two components equal to |0> up to phase
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// for _ in 0..3 {
+    ///     circuit.apply_t(0);
+    /// }
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let original = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// state.compress().unwrap();
+    /// assert!(state.stabilizer_rank() < original.stabilizer_rank());
+    /// assert!(state.approx_eq_with_phase(&original, 1e-8).unwrap());
+    /// ```
     ///
     /// ## Returns
-    /// * `usize` - The stabilizer rank.
-    pub fn stabilizer_rank(&self) -> usize {
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn compress(&mut self) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.deduplicate(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.deduplicate(),
+        }
+    }
+
+    /// Recompresses the stabilizer decomposition in place by re-expressing the state exactly in
+    /// a linearly independent subset of its stabilizer components.
+    ///
+    /// Unlike [`compress`](Self::compress), which only merges components equal up to global
+    /// phase, this also removes components that are linear combinations of the others, so χ
+    /// becomes at most `2^n`. Finding the dependencies requires the χ×χ Gram matrix of the
+    /// components, i.e. O(χ²) inner products. The coefficients of the result are stored as
+    /// [`CoefficientType::Complex64`].
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// for _ in 0..6 {
+    ///     circuit.apply_t(0);
+    ///     circuit.apply_h(0);
+    /// }
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    /// let original = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// state.recompress().unwrap();
+    /// assert!(state.stabilizer_rank() <= 2);
+    /// assert!(state.approx_eq_with_phase(&original, 1e-8).unwrap());
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error).
+    pub fn recompress(&mut self) -> Result<()> {
+        let recompressed = match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.recompress()?,
+            InternalState::StabilizerDecomposedStateComplex(state) => state.recompress()?,
+        };
+        self.internal_state = InternalState::StabilizerDecomposedStateComplex(recompressed);
+        Ok(())
+    }
+
+    /// Checks whether the quantum state is a stabilizer state, i.e. equal to a single stabilizer
+    /// state up to global phase, even if its stabilizer rank χ is larger than 1.
+    ///
+    /// If χ = 1, this returns `true` immediately. Otherwise, χ is reduced with the Gram matrix of
+    /// the components as in [`recompress`](Self::recompress), which costs `O(χ²)` inner products
+    /// and returns `true` if a single component is left. Only if several components remain is the
+    /// statevector inspected, in which case the same qubit limit as
+    /// [`to_statevector`](Self::to_statevector) applies (28 qubits).
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    /// assert!(!state.is_stabilizer_state(1e-8).unwrap());
+    ///
+    /// // T^4 = Z, so T^4 |+> = |-> is a stabilizer state (with χ = 2)
+    /// for _ in 0..3 {
+    ///     circuit.apply_t(0);
+    /// }
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    /// assert!(state.is_stabilizer_state(1e-8).unwrap());
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `tol` - The numerical tolerance used when comparing amplitudes.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing `true` if the state is a stabilizer state, or an
+    /// [`Error`](crate::error::Error).
+    pub fn is_stabilizer_state(&self, tol: f64) -> Result<bool> {
         match &self.internal_state {
-            InternalState::StabilizerDecomposedStateScalar(state) => state.stabilizers.len(),
+            InternalState::StabilizerDecomposedStateScalar(state) => state.is_stabilizer_state(tol),
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                state.is_stabilizer_state(tol)
+            }
+        }
+    }
+
+    /// Checks whether the stabilizer decomposition consists of a single stabilizer state after
+    /// merging the components equal up to global phase as in [`compress`](Self::compress).
+    ///
+    /// Unlike [`is_stabilizer_state`](Self::is_stabilizer_state), this does not inspect the
+    /// statevector, so it scales to any number of qubits but misses stabilizer states that are
+    /// only represented as superpositions of several distinct components.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// assert!(QuantumState::from_circuit(&circuit).unwrap().is_stabilizer());
+    ///
+    /// circuit.apply_t(0);
+    /// circuit.apply_h(0);
+    /// assert!(!QuantumState::from_circuit(&circuit).unwrap().is_stabilizer());
+    /// ```
+    pub fn is_stabilizer(&self) -> bool {
+        self.compressed_stabilizers()
+            .is_ok_and(|stabilizers| stabilizers.len() == 1)
+    }
+
+    /// Returns the `n` stabilizer generators of the state, which must be a single stabilizer state
+    /// after compression (see [`is_stabilizer`](Self::is_stabilizer)).
+    ///
+    /// The generators are returned in a canonical form: their symplectic representations are in
+    /// reduced row echelon form over the columns `x_0, ..., x_{n-1}, z_0, ..., z_{n-1}`, so two
+    /// states equal up to global phase have the same generators.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_x(0);
+    /// circuit.apply_h(1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap(); // |+> ⊗ |1>
+    ///
+    /// let generators = state.stabilizer_generators().unwrap();
+    /// assert!(!generators[0].0);
+    /// assert_eq!(generators[0].1.to_string(), "XI");
+    /// assert!(generators[1].0);
+    /// assert_eq!(generators[1].1.to_string(), "IZ");
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] containing pairs `(negative, pauli)` of signs and dense Pauli strings as in
+    /// [`StabilizerCHForm::canonical_stabilizers`], each representing the stabilizer
+    /// `(-1)^negative · pauli`, or
+    /// [`Error::NotStabilizerState`](crate::error::Error::NotStabilizerState) if the compressed
+    /// state has more than one component.
+    pub fn stabilizer_generators(&self) -> Result<Vec<(bool, PauliString)>> {
+        let stabilizers = self.compressed_stabilizers()?;
+        if stabilizers.len() != 1 {
+            return Err(crate::error::Error::NotStabilizerState(stabilizers.len()));
+        }
+        Ok(stabilizers[0].canonical_stabilizers())
+    }
+
+    /// Returns the stabilizer components left after [`compress`](Self::compress), without
+    /// modifying the state.
+    fn compressed_stabilizers(&self) -> Result<Vec<StabilizerCHForm>> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => {
+                let mut state = state.clone();
+                state.deduplicate()?;
+                Ok(state.stabilizers)
+            }
+            InternalState::StabilizerDecomposedStateComplex(state) => {
+                let mut state = state.clone();
+                state.deduplicate()?;
+                Ok(state.stabilizers)
+            }
         }
     }
 
@@ -702,6 +2675,735 @@ impl QuantumState {
     pub fn norm(&self) -> Result<f64> {
         match &self.internal_state {
             InternalState::StabilizerDecomposedStateScalar(state) => state.norm(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.norm(),
+        }
+    }
+
+    /// Returns the purity `Tr(ρ²)` of the normalized density matrix `ρ = |ψ><ψ| / <ψ|ψ>`.
+    ///
+    /// The simulation is exact, so the purity is 1 up to rounding errors. It is computed from
+    /// [`inner_product`](Self::inner_product) of the state with itself and from
+    /// [`norm`](Self::norm), which evaluate the Gram matrix of the decomposition differently, so
+    /// a deviation from 1 points to a numerical problem in the coefficients.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
+    /// circuit.apply_cx(0, 1);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    /// assert!((state.purity().unwrap() - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the purity, or
+    /// [`Error::ZeroNormState`](crate::error::Error::ZeroNormState) if the state has zero norm.
+    pub fn purity(&self) -> Result<f64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.purity(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.purity(),
+        }
+    }
+
+    /// Returns the global phase of the state as a unit complex number.
+    ///
+    /// The global phase of a superposition is only meaningful relative to a reference, so it is
+    /// defined as the phase of the amplitude `<x|ψ>` of a reference basis state `|x>`, as returned
+    /// by [`to_statevector`](Self::to_statevector). The reference is chosen from the state
+    /// itself: starting from the highest qubit, each qubit is fixed to its more likely outcome
+    /// given the previous ones, preferring `0` on ties. This takes one projection and norm
+    /// computation per qubit.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(1);
+    /// circuit.apply_x(0);
+    /// circuit.apply_z(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap(); // -|1>
+    /// assert!((state.global_phase().unwrap().re + 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the phase, or
+    /// [`Error::ZeroNormState`](crate::error::Error::ZeroNormState) for a zero-norm state.
+    pub fn global_phase(&self) -> Result<Complex64> {
+        match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.global_phase(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.global_phase(),
+        }
+    }
+
+    /// Multiplies the state by a phase so that [`global_phase`](Self::global_phase) returns
+    /// `phase`.
+    ///
+    /// ## Arguments
+    /// * `phase` - A unit complex number representing the desired global phase.
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or an [`Error`](crate::error::Error) if `phase`
+    /// is not a unit complex number or the state has zero norm.
+    pub fn set_global_phase(&mut self, phase: Complex64) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.set_global_phase(phase),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.set_global_phase(phase),
+        }
+    }
+
+    /// Normalizes the state in place by dividing its global factor by the current
+    /// [`norm`](Self::norm), e.g. after [`project_unnormalized`](Self::project_unnormalized).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_cx(0, 1);
+    /// let mut state = QuantumState::from_circuit(&circuit).unwrap();
+    ///
+    /// state.project_unnormalized(0, false).unwrap();
+    /// state.normalize().unwrap();
+    /// assert!((state.norm().unwrap() - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] which is `Ok(())` on success, or
+    /// [`Error::ZeroNormState`](crate::error::Error::ZeroNormState) if the state has zero norm.
+    pub fn normalize(&mut self) -> Result<()> {
+        match &mut self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state.normalize(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state.normalize(),
+        }
+    }
+
+    /// Returns a one-line summary of the state with the number of qubits, the stabilizer rank,
+    /// the norm and the magnitudes of the first few coefficients (including the global factor).
+    /// This does not build the statevector, but computing the norm takes `O(χ²)` inner
+    /// products, so the cheaper [`Display`] implementation only shows the number of qubits and
+    /// the stabilizer rank.
+    ///
+    /// ## Examples
+    /// ```rust
+    /// use necstar::prelude::{QuantumCircuit, QuantumState};
+    ///
+    /// let mut circuit = QuantumCircuit::new(2);
+    /// circuit.apply_h(0);
+    /// circuit.apply_t(0);
+    /// let state = QuantumState::from_circuit(&circuit).unwrap();
+    /// println!("{}", state.summary());
+    /// // QuantumState(num_qubits=2, stabilizer_rank=2, norm=1.000000, coefficients=[...])
+    /// assert!(state.summary().starts_with("QuantumState(num_qubits=2, stabilizer_rank=2,"));
+    /// assert_eq!(state.to_string(), "QuantumState(num_qubits=2, stabilizer_rank=2)");
+    /// ```
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn summary(&self) -> String {
+        let norm = match self.norm() {
+            Ok(norm) => format!("{:.6}", norm),
+            Err(_) => "?".to_string(),
+        };
+        let magnitudes: Vec<f64> = match &self.internal_state {
+            InternalState::StabilizerDecomposedStateScalar(state) => state
+                .coefficient_magnitudes()
+                .take(SUMMARY_COEFFICIENTS)
+                .collect(),
+            InternalState::StabilizerDecomposedStateComplex(state) => state
+                .coefficient_magnitudes()
+                .take(SUMMARY_COEFFICIENTS)
+                .collect(),
+        };
+        let mut coefficients: Vec<String> =
+            magnitudes.iter().map(|m| format!("{:.4}", m)).collect();
+        if self.stabilizer_rank() > SUMMARY_COEFFICIENTS {
+            coefficients.push("...".to_string());
+        }
+        format!(
+            "QuantumState(num_qubits={}, stabilizer_rank={}, norm={}, coefficients=[{}])",
+            self.num_qubits(),
+            self.stabilizer_rank(),
+            norm,
+            coefficients.join(", ")
+        )
+    }
+}
+
+/// The number of coefficient magnitudes shown by [`QuantumState::summary`].
+const SUMMARY_COEFFICIENTS: usize = 4;
+
+impl fmt::Display for QuantumState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "QuantumState(num_qubits={}, stabilizer_rank={})",
+            self.num_qubits(),
+            self.stabilizer_rank()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+    use crate::circuit::QuantumCircuit;
+    use crate::state::{CoefficientType, QuantumState};
+    use crate::test_utils::{assert_eq_complex, random_circuit_with_t_gate};
+
+    fn scalar_and_complex(circuit: &QuantumCircuit) -> (QuantumState, QuantumState) {
+        (
+            QuantumState::from_circuit(circuit).unwrap(),
+            QuantumState::from_circuit_with_coefficient(circuit, CoefficientType::Complex64)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_complex_measure() {
+        for i in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 50, 6, Some(7 + i));
+            let (mut scalar, mut complex) = scalar_and_complex(&circuit);
+            let seed = Some([i as u8; 32]);
+            assert_eq!(
+                scalar.measure(&[0, 2], seed).unwrap(),
+                complex.measure(&[0, 2], seed).unwrap()
+            );
+            assert!(complex.approx_eq_with_phase(&scalar, 1e-8).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_complex_sample() {
+        let circuit = random_circuit_with_t_gate(4, 50, 6, Some(11));
+        let (scalar, complex) = scalar_and_complex(&circuit);
+        let seed = Some([3u8; 32]);
+        assert_eq!(
+            complex.sample(&[0, 1, 3], 1000, seed).unwrap(),
+            scalar.sample(&[0, 1, 3], 1000, seed).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_complex_exp_value() {
+        for i in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 50, 6, Some(21 + i));
+            let (scalar, complex) = scalar_and_complex(&circuit);
+            for pauli in ["ZIII", "IXYI", "YZXZ"] {
+                let pauli = PauliString::from_str(pauli).unwrap();
+                let expected = scalar.exp_value(&pauli).unwrap();
+                let actual = complex.exp_value(&pauli).unwrap();
+                assert!((actual - expected).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_complex_inner_product() {
+        for i in 0..5 {
+            let (scalar_1, complex_1) =
+                scalar_and_complex(&random_circuit_with_t_gate(4, 50, 6, Some(31 + i)));
+            let (scalar_2, complex_2) =
+                scalar_and_complex(&random_circuit_with_t_gate(4, 50, 6, Some(41 + i)));
+            let expected = scalar_1.inner_product(&scalar_2).unwrap();
+            assert_eq_complex(complex_1.inner_product(&complex_2).unwrap(), expected);
+            assert_eq_complex(complex_1.inner_product(&scalar_2).unwrap(), expected);
+            assert_eq_complex(scalar_1.inner_product(&complex_2).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_complex_compress() {
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        for _ in 0..3 {
+            circuit.apply_t(0);
+        }
+        let (scalar, mut complex) = scalar_and_complex(&circuit);
+        complex.compress().unwrap();
+        assert!(complex.stabilizer_rank() < scalar.stabilizer_rank());
+        assert!(complex.approx_eq_with_phase(&scalar, 1e-8).unwrap());
+    }
+
+    #[test]
+    fn test_tensor() {
+        let mut circuit_a = QuantumCircuit::new(1);
+        circuit_a.apply_h(0);
+        circuit_a.apply_t(0);
+        let mut circuit_b = QuantumCircuit::new(1);
+        circuit_b.apply_x(0);
+        circuit_b.apply_h(0);
+        circuit_b.apply_t(0);
+        circuit_b.apply_s(0);
+        let expected = QuantumState::from_circuit(&circuit_a.tensor(&circuit_b))
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+
+        let (scalar_a, complex_a) = scalar_and_complex(&circuit_a);
+        let (scalar_b, complex_b) = scalar_and_complex(&circuit_b);
+        for (a, b) in [
+            (&scalar_a, &scalar_b),
+            (&complex_a, &complex_b),
+            (&scalar_a, &complex_b),
+            (&complex_a, &scalar_b),
+        ] {
+            let state = a.tensor(b).unwrap();
+            assert_eq!(state.num_qubits(), 2);
+            assert_eq!(
+                state.stabilizer_rank(),
+                a.stabilizer_rank() * b.stabilizer_rank()
+            );
+            let statevector = state.to_statevector().unwrap();
+            for (x, y) in statevector.iter().zip(expected.iter()) {
+                assert_eq_complex(*x, *y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stabilizer_generators() {
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_cx(1, 2);
+        let (scalar, complex) = scalar_and_complex(&circuit);
+        for state in [&scalar, &complex] {
+            assert!(state.is_stabilizer());
+            let mut generators: Vec<(String, bool)> = state
+                .stabilizer_generators()
+                .unwrap()
+                .into_iter()
+                .map(|(negative, pauli)| (pauli.to_string(), negative))
+                .collect();
+            generators.sort();
+            assert_eq!(
+                generators,
+                vec![
+                    ("XXX".to_string(), false),
+                    ("ZIZ".to_string(), false),
+                    ("ZZI".to_string(), false),
+                ]
+            );
+        }
+
+        // (|000> - |111>) / sqrt(2) is stabilized by -XXX.
+        circuit.apply_z(0);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let generators = state.stabilizer_generators().unwrap();
+        assert!(generators.contains(&(true, PauliString::from_str("XXX").unwrap())));
+
+        circuit.apply_t(0);
+        circuit.apply_h(0);
+        let (scalar, complex) = scalar_and_complex(&circuit);
+        for state in [&scalar, &complex] {
+            assert!(!state.is_stabilizer());
+            assert!(matches!(
+                state.stabilizer_generators(),
+                Err(crate::error::Error::NotStabilizerState(2))
+            ));
         }
     }
+
+    #[test]
+    fn test_measure_all_int() {
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_x(0);
+        circuit.apply_x(2);
+        circuit.apply_h(1);
+        circuit.apply_t(1);
+        circuit.apply_h(1);
+        let (mut scalar, mut complex) = scalar_and_complex(&circuit);
+        for state in [&mut scalar, &mut complex] {
+            let outcome = state.measure_all_int(Some([7; 32])).unwrap();
+            assert_eq!(outcome & 0b101, 0b101);
+            // The state has collapsed onto the measured basis state.
+            assert_eq!(state.measure_all_int(None).unwrap(), outcome);
+        }
+
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_x(0);
+        circuit.apply_x(2);
+        let mut state = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq!(state.measure_all_int(None).unwrap(), 0b101);
+
+        let mut state = QuantumState::from_circuit(&QuantumCircuit::new(129)).unwrap();
+        assert!(matches!(
+            state.measure_all_int(None),
+            Err(crate::error::Error::MeasurementTooManyQubits)
+        ));
+    }
+
+    #[test]
+    fn test_apply_gate_if_teleportation() {
+        // Teleport the magic state T|+> from qubit 0 to qubit 2.
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_h(1);
+        circuit.apply_cx(1, 2);
+        circuit.apply_cx(0, 1);
+        circuit.apply_h(0);
+
+        let x = PauliString::from_str("XII").unwrap();
+        let y = PauliString::from_str("YII").unwrap();
+        let z = PauliString::from_str("ZII").unwrap();
+        for seed in 0..8 {
+            let (mut scalar, mut complex) = scalar_and_complex(&circuit);
+            for state in [&mut scalar, &mut complex] {
+                let outcome = state.measure(&[0, 1], Some([seed; 32])).unwrap();
+                state
+                    .apply_gate_if(&crate::circuit::QuantumGate::X(2), outcome[1])
+                    .unwrap();
+                state
+                    .apply_gate_if(&crate::circuit::QuantumGate::Z(2), outcome[0])
+                    .unwrap();
+
+                assert!(
+                    (state.exp_value(&x).unwrap() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-8
+                );
+                assert!(
+                    (state.exp_value(&y).unwrap() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-8
+                );
+                assert!(state.exp_value(&z).unwrap().abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_permute_qubits_ghz() {
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_cx(1, 2);
+        circuit.apply_t(2);
+        let (mut scalar, mut complex) = scalar_and_complex(&circuit);
+        for state in [&mut scalar, &mut complex] {
+            let expected = state.to_statevector().unwrap();
+            for perm in [[1, 0, 2], [2, 1, 0], [1, 2, 0], [2, 0, 1]] {
+                state.permute_qubits(&perm).unwrap();
+                let statevector = state.to_statevector().unwrap();
+                for (x, y) in statevector.iter().zip(expected.iter()) {
+                    assert_eq_complex(*x, *y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_stabilizers() {
+        use num_complex::Complex64;
+        use stabilizer_ch_form_rust::StabilizerCHForm;
+
+        use crate::error::Error;
+
+        // (|00> + |11>) / sqrt(2)
+        let zero = StabilizerCHForm::new(2).unwrap();
+        let mut one = StabilizerCHForm::new(2).unwrap();
+        one.apply_x(0).unwrap();
+        one.apply_x(1).unwrap();
+        let coefficient = Complex64::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        let state = QuantumState::from_stabilizers(
+            2,
+            vec![zero.clone(), one.clone()],
+            vec![coefficient, coefficient],
+        )
+        .unwrap();
+        assert_eq!(state.stabilizer_rank(), 2);
+
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        let expected = QuantumState::from_circuit(&circuit)
+            .unwrap()
+            .to_statevector()
+            .unwrap();
+        let statevector = state.to_statevector().unwrap();
+        for (x, y) in statevector.iter().zip(expected.iter()) {
+            assert_eq_complex(*x, *y);
+        }
+
+        assert!(matches!(
+            QuantumState::from_stabilizers(2, vec![zero.clone(), one], vec![coefficient]),
+            Err(Error::InvalidDecomposition(_))
+        ));
+        assert!(matches!(
+            QuantumState::from_stabilizers(
+                2,
+                vec![zero, StabilizerCHForm::new(3).unwrap()],
+                vec![coefficient, coefficient]
+            ),
+            Err(Error::InvalidDecomposition(_))
+        ));
+        assert!(matches!(
+            QuantumState::from_stabilizers(0, vec![], vec![]),
+            Err(Error::InvalidNumQubits(0))
+        ));
+    }
+
+    #[test]
+    fn test_to_statevector_limit() {
+        let state = QuantumState::from_circuit(&QuantumCircuit::new(30)).unwrap();
+        assert!(matches!(
+            state.to_statevector(),
+            Err(crate::error::Error::StatevectorTooLarge(30))
+        ));
+
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_cx(0, 2);
+        let (scalar, complex) = scalar_and_complex(&circuit);
+        for state in [&scalar, &complex] {
+            let expected = state.to_statevector().unwrap();
+            for statevector in [
+                state.to_statevector_unchecked().unwrap(),
+                state.to_statevector_with_limit(3).unwrap(),
+            ] {
+                for (x, y) in statevector.iter().zip(expected.iter()) {
+                    assert_eq_complex(*x, *y);
+                }
+            }
+            assert!(matches!(
+                state.to_statevector_with_limit(2),
+                Err(crate::error::Error::StatevectorTooLarge(3))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        // |Bell> ⊗ T|+>, compiled to two components with coefficients of magnitude 1/sqrt(2)
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_h(2);
+        circuit.apply_t(2);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let rank = state.stabilizer_rank();
+        let summary = state.summary();
+        assert_eq!(
+            state.to_string(),
+            format!("QuantumState(num_qubits=3, stabilizer_rank={})", rank)
+        );
+        assert!(summary.starts_with(&format!(
+            "QuantumState(num_qubits=3, stabilizer_rank={}, norm=1.000000, coefficients=[",
+            rank
+        )));
+        assert_eq!(summary.matches(", ...").count(), 0);
+
+        let mut circuit = QuantumCircuit::new(6);
+        for q in 0..6 {
+            circuit.apply_h(q);
+            circuit.apply_t(q);
+        }
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(state.stabilizer_rank() > 4);
+        let summary = state.summary();
+        assert!(summary.contains("num_qubits=6"));
+        assert!(summary.contains(&format!("stabilizer_rank={}", state.stabilizer_rank())));
+        assert!(summary.ends_with(", ...])"));
+    }
+
+    #[test]
+    fn test_sample_one() {
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_cx(0, 2);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+
+        let mut seen = [false; 2];
+        for i in 0..50u8 {
+            let outcome = state.sample_one(&[2, 0], Some([i; 32])).unwrap();
+            assert!(outcome == vec![false, false] || outcome == vec![true, true]);
+            seen[outcome[0] as usize] = true;
+        }
+        assert!(seen[0] && seen[1]);
+
+        // The state is not collapsed, and the outcome matches a single-shot sample.
+        assert_eq!(state.stabilizer_rank(), 2);
+        let seed = Some([9; 32]);
+        assert_eq!(
+            state.sample_one(&[0, 1], seed).unwrap(),
+            state.sample(&[0, 1], 1, seed).unwrap()[0].0
+        );
+        assert!(state.sample_one(&[3], seed).is_err());
+    }
+
+    #[test]
+    fn test_to_sparse_statevector_ghz() {
+        let amplitude = num_complex::Complex64::new(0.5f64.sqrt(), 0.0);
+        for num_qubits in [3, 40, 128] {
+            let mut circuit = QuantumCircuit::new(num_qubits);
+            circuit.apply_h(0);
+            for q in 1..num_qubits {
+                circuit.apply_cx(q - 1, q);
+            }
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let sparse = state.to_sparse_statevector().unwrap();
+            assert_eq!(sparse.len(), 2);
+            assert_eq!(sparse[0].0, 0);
+            assert_eq!(sparse[1].0, u128::MAX >> (128 - num_qubits));
+            for (_, value) in sparse {
+                assert_eq_complex(value, amplitude);
+            }
+        }
+
+        // Check against the dense statevector for a non-stabilizer state.
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_cx(1, 2);
+        let (scalar, complex) = scalar_and_complex(&circuit);
+        for state in [scalar, complex] {
+            let dense = state.to_statevector().unwrap();
+            let sparse = state.to_sparse_statevector().unwrap();
+            assert_eq!(sparse.len(), 2);
+            for (index, value) in sparse {
+                assert_eq_complex(value, dense[index as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_marginal_expectation() {
+        use crate::error::Error;
+
+        let circuit = random_circuit_with_t_gate(5, 80, 6, Some(77));
+        let (scalar, complex) = scalar_and_complex(&circuit);
+        let qargs = [3, 0, 4];
+        for state in [scalar, complex] {
+            let expected_distribution = state.probabilities(&qargs).unwrap();
+            for pauli in ["Z0", "Z3 Z4", "Z0 Z3 Z4", "X0", "Y3 Z0", "X4 Y0 Z3", ""] {
+                let observable = PauliString::from_str(pauli).unwrap();
+                let (exp_value, distribution) =
+                    state.marginal_expectation(&qargs, &observable).unwrap();
+                assert!((exp_value - state.exp_value(&observable).unwrap()).abs() < 1e-10);
+                assert_eq!(distribution, expected_distribution);
+            }
+
+            let outside = PauliString::from_str("Z0 X1").unwrap();
+            assert!(matches!(
+                state.marginal_expectation(&qargs, &outside),
+                Err(Error::PauliOutsideQubitSubset(1))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_terms_and_coefficient_magnitudes() {
+        use num_complex::Complex64;
+
+        for seed in 0..3 {
+            let circuit = random_circuit_with_t_gate(4, 30, 4, Some(700 + seed));
+            let (scalar, complex) = scalar_and_complex(&circuit);
+            for state in [scalar, complex] {
+                let terms = state.terms();
+                let magnitudes = state.coefficient_magnitudes();
+                assert_eq!(terms.len(), state.stabilizer_rank());
+                assert_eq!(magnitudes.len(), state.stabilizer_rank());
+                for ((_, c), magnitude) in terms.iter().zip(&magnitudes) {
+                    assert!((c.norm() - magnitude).abs() < 1e-12);
+                }
+
+                // ||ψ||^2 = Σ_ij conj(c_i) c_j <φ_i|φ_j> ≤ (Σ_i |c_i|)^2
+                let mut norm_sqr = Complex64::new(0.0, 0.0);
+                for (stab_i, c_i) in &terms {
+                    for (stab_j, c_j) in &terms {
+                        norm_sqr += c_i.conj() * c_j * stab_i.inner_product(stab_j).unwrap();
+                    }
+                }
+                let norm = state.norm().unwrap();
+                assert!((norm_sqr.re - norm * norm).abs() < 1e-8);
+                assert!(norm_sqr.im.abs() < 1e-8);
+                assert!(norm <= magnitudes.iter().sum::<f64>() + 1e-10);
+
+                let bitstring = [true, false, true, true];
+                let contributions = state.term_amplitudes(&bitstring).unwrap();
+                let sum: Complex64 = contributions.iter().sum();
+                assert!((sum - state.amplitude(&bitstring).unwrap()).norm() < 1e-10);
+                assert!(matches!(
+                    state.term_amplitudes(&[true]),
+                    Err(crate::error::Error::InvalidBitstringLength {
+                        expected: 4,
+                        found: 1
+                    })
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_project_multi() {
+        use crate::error::Error;
+        use crate::test_utils::assert_eq_complex_array1;
+
+        let outcomes = [(3, true), (0, false), (2, true)];
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(5, 40, 5, Some(800 + seed));
+            for coefficient_type in [CoefficientType::Scalar, CoefficientType::Complex64] {
+                let state = || {
+                    QuantumState::from_circuit_with_coefficient(&circuit, coefficient_type).unwrap()
+                };
+                if state().postselection_probability(&outcomes).unwrap() < 1e-8 {
+                    continue;
+                }
+                let mut sequential = state();
+                for &(qubit, outcome) in &outcomes {
+                    sequential.project_normalized(qubit, outcome).unwrap();
+                }
+                let mut multi = state();
+                multi.project_multi(&outcomes, true).unwrap();
+                assert_eq_complex_array1(
+                    &multi.to_statevector().unwrap(),
+                    &sequential.to_statevector().unwrap(),
+                );
+
+                let mut sequential = state();
+                for &(qubit, outcome) in &outcomes {
+                    sequential.project_unnormalized(qubit, outcome).unwrap();
+                }
+                let mut multi = state();
+                multi.project_multi(&outcomes, false).unwrap();
+                assert_eq_complex_array1(
+                    &multi.to_statevector().unwrap(),
+                    &sequential.to_statevector().unwrap(),
+                );
+            }
+        }
+
+        // |0>|1>|0>: the projection of qubit 1 onto |0> is the first impossible one.
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_x(1);
+        let state = || QuantumState::from_circuit(&circuit).unwrap();
+        let mut projected = state();
+        assert!(matches!(
+            projected.project_multi(&[(0, false), (1, false), (2, true)], true),
+            Err(Error::ImpossibleProjection {
+                qubit_index: 1,
+                desired: false
+            })
+        ));
+        let mut projected = state();
+        projected
+            .project_multi(&[(0, false), (1, false)], false)
+            .unwrap();
+        assert!(projected.norm().unwrap() < 1e-12);
+        let mut projected = state();
+        assert!(matches!(
+            projected.project_multi(&[(0, false), (0, true)], true),
+            Err(Error::DuplicateQubitIndex(0))
+        ));
+        assert!(matches!(
+            projected.project_multi(&[(3, false)], true),
+            Err(Error::QubitIndexOutOfBounds(3, 3))
+        ));
+    }
 }
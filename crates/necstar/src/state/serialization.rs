@@ -0,0 +1,126 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::state::{Coefficient, InternalState, StabilizerDecomposedState};
+
+/// The version of the saved state format, bumped whenever the layout of the serialized
+/// [`InternalState`] changes.
+pub(crate) const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct SavedStateRef<'a> {
+    version: u32,
+    state: &'a InternalState,
+}
+
+#[derive(Deserialize)]
+struct SavedState {
+    version: u32,
+    state: InternalState,
+}
+
+/// Writes `state` as a versioned JSON document to `path`.
+pub(crate) fn save<P: AsRef<Path>>(state: &InternalState, path: P) -> Result<()> {
+    let writer = BufWriter::new(File::create(path)?);
+    let saved = SavedStateRef {
+        version: FORMAT_VERSION,
+        state,
+    };
+    serde_json::to_writer(writer, &saved)?;
+    Ok(())
+}
+
+/// Reads a state written by [`save`] from `path`.
+pub(crate) fn load<P: AsRef<Path>>(path: P) -> Result<InternalState> {
+    let reader = BufReader::new(File::open(path)?);
+    let saved: SavedState = serde_json::from_reader(reader)?;
+    if saved.version != FORMAT_VERSION {
+        return Err(Error::InvalidSavedState(format!(
+            "unsupported format version {} (expected {})",
+            saved.version, FORMAT_VERSION
+        )));
+    }
+    match &saved.state {
+        InternalState::StabilizerDecomposedStateScalar(state) => validate(state)?,
+        InternalState::StabilizerDecomposedStateComplex(state) => validate(state)?,
+    }
+    Ok(saved.state)
+}
+
+/// Checks that the components are consistent with each other.
+fn validate<T: Coefficient>(state: &StabilizerDecomposedState<T>) -> Result<()> {
+    if state.stabilizers.len() != state.coefficients.len() {
+        return Err(Error::InvalidSavedState(format!(
+            "{} stabilizers but {} coefficients",
+            state.stabilizers.len(),
+            state.coefficients.len()
+        )));
+    }
+    if let Some(stab) = state
+        .stabilizers
+        .iter()
+        .find(|stab| stab.num_qubits() != state.num_qubits)
+    {
+        return Err(Error::InvalidSavedState(format!(
+            "a stabilizer has {} qubits instead of {}",
+            stab.num_qubits(),
+            state.num_qubits
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::error::Error;
+    use crate::state::{CoefficientType, QuantumState};
+    use crate::test_utils::{assert_eq_complex_array1, random_circuit_with_t_gate};
+
+    #[test]
+    fn test_save_load() {
+        let dir = std::env::temp_dir();
+        for (i, coefficient_type) in [CoefficientType::Scalar, CoefficientType::Complex64]
+            .into_iter()
+            .enumerate()
+        {
+            let circuit = random_circuit_with_t_gate(4, 60, 6, Some(i as u64));
+            let state =
+                QuantumState::from_circuit_with_coefficient(&circuit, coefficient_type).unwrap();
+            let path = dir.join(format!("necstar_test_save_load_{}.json", i));
+            state.save(&path).unwrap();
+            let loaded = QuantumState::load(&path).unwrap();
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(loaded.stabilizer_rank(), state.stabilizer_rank());
+            assert_eq!(loaded.num_qubits(), state.num_qubits());
+            assert_eq_complex_array1(
+                &loaded.to_statevector().unwrap(),
+                &state.to_statevector().unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_rejects_other_versions() {
+        let state =
+            QuantumState::from_circuit(&random_circuit_with_t_gate(2, 10, 2, Some(3))).unwrap();
+        let path = std::env::temp_dir().join("necstar_test_load_version.json");
+        state.save(&path).unwrap();
+        let json = fs::read_to_string(&path).unwrap();
+        assert!(json.starts_with(r#"{"version":1,"#));
+        fs::write(
+            &path,
+            json.replacen(r#""version":1"#, r#""version":999"#, 1),
+        )
+        .unwrap();
+        let result = QuantumState::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(Error::InvalidSavedState(_))));
+    }
+}
@@ -0,0 +1,97 @@
+use num_complex::Complex64;
+
+use crate::error::{Error, Result};
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Computes the amplitude <s|ψ> for the computational basis state |s> without building the
+    /// full statevector.
+    /// The `i`-th element of `bitstring` corresponds to qubit `i`.
+    pub(crate) fn amplitude(&self, bitstring: &[bool]) -> Result<Complex64> {
+        if bitstring.len() != self.num_qubits {
+            return Err(Error::InvalidBitstringLength {
+                expected: self.num_qubits,
+                found: bitstring.len(),
+            });
+        }
+        let mut amplitude = Complex64::new(0.0, 0.0);
+        for (stab, coeff) in self.stabilizers.iter().zip(self.coefficients.iter()) {
+            let coeff_complex: Complex64 = (*coeff).into();
+            amplitude += coeff_complex * stab.amplitude(bitstring)?;
+        }
+        Ok(amplitude * self.global_factor)
+    }
+
+    /// Computes the contribution `global_factor * c_i <s|φ_i>` of each component to the
+    /// amplitude <s|ψ>, in the order of the components.
+    pub(crate) fn term_amplitudes(&self, bitstring: &[bool]) -> Result<Vec<Complex64>> {
+        if bitstring.len() != self.num_qubits {
+            return Err(Error::InvalidBitstringLength {
+                expected: self.num_qubits,
+                found: bitstring.len(),
+            });
+        }
+        self.stabilizers
+            .iter()
+            .zip(self.coefficients.iter())
+            .map(|(stab, coeff)| {
+                let coeff_complex: Complex64 = (*coeff).into();
+                Ok(coeff_complex * stab.amplitude(bitstring)? * self.global_factor)
+            })
+            .collect()
+    }
+
+    /// Computes the amplitude <index|ψ>, where the basis state is given as an integer in
+    /// little-endian order (qubit 0 is the least significant bit).
+    pub(crate) fn amplitude_at(&self, index: usize) -> Result<Complex64> {
+        if self.num_qubits < usize::BITS as usize && index >> self.num_qubits != 0 {
+            return Err(Error::BasisStateIndexOutOfBounds(index, self.num_qubits));
+        }
+        let bitstring: Vec<bool> = (0..self.num_qubits)
+            .map(|i| i < usize::BITS as usize && (index >> i) & 1 == 1)
+            .collect();
+        self.amplitude(&bitstring)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::test_utils::{assert_eq_complex, create_sample_stab_decomp_state};
+
+    #[test]
+    fn test_amplitude() {
+        use crate::state::QuantumState;
+        use crate::test_utils::random_circuit_with_t_gate;
+
+        let circuit = random_circuit_with_t_gate(5, 30, 5, Some(11));
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let sv = state.to_statevector().unwrap();
+        for index in [0, 1, 6, 19, 31] {
+            let bitstring: Vec<bool> = (0..5).map(|i| (index >> i) & 1 == 1).collect();
+            assert_eq_complex(state.amplitude(&bitstring).unwrap(), sv[index]);
+        }
+    }
+
+    #[test]
+    fn test_amplitude_at() {
+        // 1/2 (|000> + |001> + |010> + |111>)
+        let state = create_sample_stab_decomp_state();
+        let sv = state.to_statevector().unwrap();
+        for index in 0..8 {
+            assert_eq_complex(state.amplitude_at(index).unwrap(), sv[index]);
+        }
+
+        assert!(matches!(
+            state.amplitude_at(8),
+            Err(Error::BasisStateIndexOutOfBounds(8, 3))
+        ));
+        assert!(matches!(
+            state.amplitude(&[true, false]),
+            Err(Error::InvalidBitstringLength {
+                expected: 3,
+                found: 2
+            })
+        ));
+    }
+}
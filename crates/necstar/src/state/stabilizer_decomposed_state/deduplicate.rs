@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::StabilizerCHForm;
+
+use crate::error::Result;
+use crate::state::StabilizerDecomposedState;
+use crate::state::types::phase_factor::PhaseFactor;
+use crate::state::types::scalar::Scalar;
+
+impl StabilizerDecomposedState<Scalar> {
+    /// Merges the stabilizer components that are equal up to global phase and drops the
+    /// components with zero coefficients, which reduces the stabilizer rank χ without changing
+    /// the represented state.
+    ///
+    /// Components are grouped by their canonical stabilizer groups. For `|ψ_j> = e^{iθ} |ψ_i>`,
+    /// the coefficient `c_j e^{iθ}` is added to `c_i` if the sum is representable as a
+    /// [`Scalar`]; otherwise both components are kept.
+    // avoid let-chains for compatibility with older Rust toolchains
+    #[allow(clippy::collapsible_if)]
+    pub(crate) fn deduplicate(&mut self) -> Result<()> {
+        let mut representatives: HashMap<_, usize> = HashMap::new();
+        let mut stabilizers: Vec<StabilizerCHForm> = Vec::with_capacity(self.stabilizers.len());
+        let mut coefficients: Vec<Scalar> = Vec::with_capacity(self.coefficients.len());
+
+        for (stab, &coeff) in self.stabilizers.iter().zip(self.coefficients.iter()) {
+            if coeff == Scalar::Zero {
+                continue;
+            }
+            let key = stab.canonical_stabilizers();
+            if let Some(&index) = representatives.get(&key) {
+                // |stab> = <rep|stab> |rep>
+                let relative_phase = stabilizers[index].inner_product(stab)?;
+                if let Some(phase) = PhaseFactor::from_complex(relative_phase, 1e-8) {
+                    if let Some(sum) = coefficients[index] + coeff * phase {
+                        coefficients[index] = sum;
+                        continue;
+                    }
+                }
+            } else {
+                representatives.insert(key, stabilizers.len());
+            }
+            stabilizers.push(stab.clone());
+            coefficients.push(coeff);
+        }
+
+        let (stabilizers, coefficients): (Vec<_>, Vec<_>) = stabilizers
+            .into_iter()
+            .zip(coefficients)
+            .filter(|(_, coeff)| *coeff != Scalar::Zero)
+            .unzip();
+        self.stabilizers = stabilizers;
+        self.coefficients = coefficients;
+        Ok(())
+    }
+}
+
+impl StabilizerDecomposedState<Complex64> {
+    /// Merges the stabilizer components that are equal up to global phase and drops the
+    /// components whose coefficients vanish within floating-point precision.
+    ///
+    /// Unlike the [`Scalar`] case, every sum is representable, so all components with the same
+    /// canonical stabilizer group are merged.
+    pub(crate) fn deduplicate(&mut self) -> Result<()> {
+        const ZERO_TOL: f64 = 1e-12;
+
+        let mut representatives: HashMap<_, usize> = HashMap::new();
+        let mut stabilizers: Vec<StabilizerCHForm> = Vec::with_capacity(self.stabilizers.len());
+        let mut coefficients: Vec<Complex64> = Vec::with_capacity(self.coefficients.len());
+
+        for (stab, &coeff) in self.stabilizers.iter().zip(self.coefficients.iter()) {
+            let key = stab.canonical_stabilizers();
+            if let Some(&index) = representatives.get(&key) {
+                // |stab> = <rep|stab> |rep>
+                coefficients[index] += coeff * stabilizers[index].inner_product(stab)?;
+            } else {
+                representatives.insert(key, stabilizers.len());
+                stabilizers.push(stab.clone());
+                coefficients.push(coeff);
+            }
+        }
+
+        let (stabilizers, coefficients): (Vec<_>, Vec<_>) = stabilizers
+            .into_iter()
+            .zip(coefficients)
+            .filter(|(_, coeff)| coeff.norm() > ZERO_TOL)
+            .unzip();
+        self.stabilizers = stabilizers;
+        self.coefficients = coefficients;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex64;
+    use stabilizer_ch_form_rust::StabilizerCHForm;
+
+    use crate::circuit::QuantumCircuit;
+    use crate::state::types::scalar::Scalar;
+    use crate::state::{QuantumState, StabilizerDecomposedState};
+    use crate::test_utils::{assert_eq_complex_array1, random_circuit_with_t_gate};
+
+    #[test]
+    fn test_deduplicate() {
+        // |0> + (-|0>) + |1> = |1>, where the second |0> carries the global phase -1
+        let zero = StabilizerCHForm::new(1).unwrap();
+        let mut minus_zero = zero.clone();
+        minus_zero.set_global_phase(Complex64::new(-1.0, 0.0));
+        let mut one = zero.clone();
+        one.apply_x(0).unwrap();
+        let mut state =
+            StabilizerDecomposedState::new(1, vec![zero, minus_zero, one], vec![Scalar::ONE; 3]);
+        let expected = state.to_statevector().unwrap();
+        state.deduplicate().unwrap();
+        assert_eq!(state.stabilizers.len(), 1);
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_compress() {
+        // The two components |0> produced by teleporting T^3 = S T merge into one.
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        for _ in 0..3 {
+            circuit.apply_t(0);
+        }
+        let mut state = QuantumState::from_circuit(&circuit).unwrap();
+        let original = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq!(state.stabilizer_rank(), 3);
+        state.compress().unwrap();
+        assert_eq!(state.stabilizer_rank(), 2);
+        assert!(state.approx_eq_with_phase(&original, 1e-8).unwrap());
+
+        for seed in 0..20 {
+            let circuit = random_circuit_with_t_gate(3, 20, 6, Some(seed));
+            let mut state = QuantumState::from_circuit(&circuit).unwrap();
+            let original = QuantumState::from_circuit(&circuit).unwrap();
+            state.compress().unwrap();
+            assert!(state.stabilizer_rank() <= original.stabilizer_rank());
+            assert_eq_complex_array1(
+                &state.to_statevector().unwrap(),
+                &original.to_statevector().unwrap(),
+            );
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use stabilizer_ch_form_rust::StabilizerCHForm;
+
 use crate::error::Result;
 use crate::state::{Coefficient, StabilizerDecomposedState};
 
@@ -9,6 +11,20 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         self.num_qubits -= 1;
         Ok(())
     }
+
+    /// Appends `count` fresh qubits in the `|0>` state, which get the indices
+    /// `num_qubits..num_qubits + count`. This is the counterpart of [`discard`](Self::discard).
+    pub(crate) fn add_qubits(&mut self, count: usize) -> Result<()> {
+        if count == 0 {
+            return Ok(());
+        }
+        let ancillas = StabilizerCHForm::new(count)?;
+        for stab in self.stabilizers.iter_mut() {
+            *stab = stab.kron(&ancillas)?;
+        }
+        self.num_qubits += count;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -42,4 +58,30 @@ mod tests {
         let result = state.discard(3);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_add_qubits() {
+        // Bell state (|00> + |11>) / sqrt(2)
+        let mut state = crate::test_utils::create_all_zero_state(2);
+        state.apply_h(0).unwrap();
+        state.apply_cx(0, 1).unwrap();
+        let bell_sv = state.to_statevector().unwrap();
+
+        state.add_qubits(1).unwrap();
+        assert_eq!(state.num_qubits, 3);
+
+        // The new qubit 2 is in |0> and unentangled: |0> ⊗ |Bell>
+        let zero_sv = ndarray::array![
+            num_complex::Complex64::new(1.0, 0.0),
+            num_complex::Complex64::new(0.0, 0.0)
+        ];
+        let expected_sv = crate::test_utils::tensor_statevectors(&bell_sv, &zero_sv);
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &expected_sv);
+
+        // Adding zero qubits is a no-op, and the added qubit can be discarded again.
+        state.add_qubits(0).unwrap();
+        assert_eq!(state.num_qubits, 3);
+        state.discard(2).unwrap();
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &bell_sv);
+    }
 }
@@ -1,15 +1,51 @@
 use num_complex::Complex64;
-use stabilizer_ch_form_rust::types::pauli::PauliString;
+use stabilizer_ch_form_rust::StabilizerCHForm;
+use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::state::{Coefficient, StabilizerDecomposedState};
 impl<T: Coefficient> StabilizerDecomposedState<T> {
     pub(crate) fn exp_value(&self, pauli_string: &PauliString) -> Result<f64> {
-        // Validate Pauli string length
+        self.validate_pauli_string(pauli_string)?;
+
+        // Fast path: a single non-identity factor only needs a single-qubit gate per CH-form.
+        if let Some((qubit, pauli)) = single_qubit_factor(pauli_string) {
+            return self.single_qubit_exp_value(qubit, pauli);
+        }
+
+        self.exp_value_with(|stab| stab.apply_pauli(pauli_string))
+    }
+
+    /// Calculates the expectation value of the observable `Σ_k w_k P_k` given as pairs
+    /// `(w_k, P_k)`.
+    ///
+    /// Each stabilizer component is evolved by every `P_k` once, and the evolved component is
+    /// reused for all the inner products with the other components.
+    pub(crate) fn exp_value_sum(&self, observable: &[(f64, PauliString)]) -> Result<f64> {
+        for (_, pauli_string) in observable {
+            self.validate_pauli_string(pauli_string)?;
+        }
+        let weights: Vec<f64> = observable.iter().map(|(weight, _)| *weight).collect();
+        self.weighted_exp_value_with(&weights, |k, stab| stab.apply_pauli(&observable[k].1))
+    }
+
+    /// Calculates the expectation values of the Pauli strings `paulis`, in the same order.
+    ///
+    /// The stabilizer components are paired once for all the Pauli strings, and each component
+    /// is evolved by every Pauli string only once.
+    pub(crate) fn exp_value_batch(&self, paulis: &[PauliString]) -> Result<Vec<f64>> {
+        for pauli_string in paulis {
+            self.validate_pauli_string(pauli_string)?;
+        }
+        self.exp_values_with(paulis.len(), |k, stab| stab.apply_pauli(&paulis[k]))
+    }
+
+    /// Validates that the Pauli string acts only on qubits of the state.
+    pub(crate) fn validate_pauli_string(&self, pauli_string: &PauliString) -> Result<()> {
         match pauli_string {
             PauliString::Dense(ops) => {
                 if ops.len() != self.num_qubits {
-                    return Err(crate::error::Error::InvalidPauliStringLength {
+                    return Err(Error::InvalidPauliStringLength {
                         expected: self.num_qubits,
                         found: ops.len(),
                     });
@@ -18,15 +54,57 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
             PauliString::Sparse(terms) => {
                 let max_qubit = terms.iter().map(|term| term.qubit).max().unwrap_or(0);
                 if max_qubit >= self.num_qubits {
-                    return Err(crate::error::Error::InvalidPauliStringLength {
+                    return Err(Error::InvalidPauliStringLength {
                         expected: self.num_qubits,
                         found: max_qubit + 1,
                     });
                 }
             }
         }
+        Ok(())
+    }
+
+    /// Calculates the expectation value of a single-qubit Pauli operator `pauli` acting on
+    /// `qubit`. This applies only the single-qubit gate to each CH-form, i.e. `O(χn)` for the
+    /// gate applications instead of building and applying a full-width [`PauliString`].
+    pub(crate) fn single_qubit_exp_value(&self, qubit: usize, pauli: Pauli) -> Result<f64> {
+        if qubit >= self.num_qubits {
+            return Err(Error::QubitIndexOutOfBounds(qubit, self.num_qubits));
+        }
+        match pauli {
+            Pauli::I => self.exp_value_with(|_| Ok(())),
+            Pauli::X => self.exp_value_with(|stab| stab.apply_x(qubit)),
+            Pauli::Y => self.exp_value_with(|stab| stab.apply_y(qubit)),
+            Pauli::Z => self.exp_value_with(|stab| stab.apply_z(qubit)),
+        }
+    }
 
-        let mut exp_val = Complex64::new(0.0, 0.0);
+    /// Calculates <ψ|P|ψ>, where `apply_op` applies the (Hermitian) Pauli operator P to a
+    /// single CH-form.
+    fn exp_value_with<F>(&self, apply_op: F) -> Result<f64>
+    where
+        F: Fn(&mut StabilizerCHForm) -> stabilizer_ch_form_rust::error::Result<()>,
+    {
+        self.weighted_exp_value_with(&[1.0], |_, stab| apply_op(stab))
+    }
+
+    /// Calculates Σ_k w_k <ψ|P_k|ψ>, where `weights[k]` is w_k and `apply_op(k, stab)` applies
+    /// the (Hermitian) Pauli operator P_k to a single CH-form.
+    fn weighted_exp_value_with<F>(&self, weights: &[f64], apply_op: F) -> Result<f64>
+    where
+        F: Fn(usize, &mut StabilizerCHForm) -> stabilizer_ch_form_rust::error::Result<()>,
+    {
+        let exp_vals = self.exp_values_with(weights.len(), apply_op)?;
+        Ok(weights.iter().zip(exp_vals).map(|(w, v)| w * v).sum())
+    }
+
+    /// Calculates <ψ|P_k|ψ> for `k` in `0..num_ops`, where `apply_op(k, stab)` applies the
+    /// (Hermitian) Pauli operator P_k to a single CH-form.
+    fn exp_values_with<F>(&self, num_ops: usize, apply_op: F) -> Result<Vec<f64>>
+    where
+        F: Fn(usize, &mut StabilizerCHForm) -> stabilizer_ch_form_rust::error::Result<()>,
+    {
+        let mut exp_vals = vec![Complex64::new(0.0, 0.0); num_ops];
 
         // To avoid repeated zipping, create a vector of pairs (stabilizer, coefficient).
         let terms: Vec<_> = self
@@ -36,28 +114,75 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
             .collect();
 
         for (i, (stab_i, coeff_i)) in terms.iter().enumerate() {
-            // Apply Pauli P to |S_i> once per outer loop iteration.
-            let evolved_stab = {
-                let mut temp_stab = (*stab_i).clone();
-                temp_stab.apply_pauli(pauli_string)?;
-                temp_stab
-            };
-
-            // Diagonal term: c_i* c_i <S_i|P|S_i>.
-            let inner_prod_diag = stab_i.inner_product(&evolved_stab)?;
-            exp_val += (coeff_i.conj() * **coeff_i).into() * inner_prod_diag;
-
-            for (stab_j, coeff_j) in terms.iter().skip(i + 1) {
-                // Calculate the term for (i, j): c_j* c_i <S_j|P|S_i>.
-                let inner_prod_off_diag = stab_j.inner_product(&evolved_stab)?;
-                let term = (coeff_j.conj() * **coeff_i).into() * inner_prod_off_diag;
-
-                // Add the term and its complex conjugate, which covers the (j, i) case.
-                exp_val += term + term.conj();
+            for (k, exp_val) in exp_vals.iter_mut().enumerate() {
+                // Apply Pauli P_k to |S_i> once per outer loop iteration.
+                let evolved_stab = {
+                    let mut temp_stab = (*stab_i).clone();
+                    apply_op(k, &mut temp_stab)?;
+                    temp_stab
+                };
+
+                // Diagonal term: c_i* c_i <S_i|P_k|S_i>.
+                let inner_prod_diag = stab_i.inner_product(&evolved_stab)?;
+                let mut partial = (coeff_i.conj() * **coeff_i).into() * inner_prod_diag;
+
+                for (stab_j, coeff_j) in terms.iter().skip(i + 1) {
+                    // Calculate the term for (i, j): c_j* c_i <S_j|P_k|S_i>.
+                    let inner_prod_off_diag = stab_j.inner_product(&evolved_stab)?;
+                    let term = (coeff_j.conj() * **coeff_i).into() * inner_prod_off_diag;
+
+                    // Add the term and its complex conjugate, which covers the (j, i) case.
+                    partial += term + term.conj();
+                }
+                *exp_val += partial;
             }
         }
 
-        Ok(exp_val.re * self.global_factor.norm_sqr())
+        let norm_sqr = self.global_factor.norm_sqr();
+        exp_vals
+            .iter()
+            .map(|v| real_exp_value(v * norm_sqr))
+            .collect()
+    }
+}
+
+/// Tolerance on the imaginary part of an expectation value before it is reported as an error.
+const IMAG_TOL: f64 = 1e-8;
+
+/// Returns the real part of an expectation value of a Hermitian operator, or an error if its
+/// imaginary part is not negligible.
+///
+/// The off-diagonal terms are added together with their conjugates, so only the diagonal terms
+/// `|c_i|^2 <S_i|P|S_i>` can contribute an imaginary part. For a Hermitian `P` they are real, so
+/// a nonzero imaginary part indicates a bug rather than rounding.
+fn real_exp_value(value: Complex64) -> Result<f64> {
+    if value.im.abs() >= IMAG_TOL || value.im.is_nan() {
+        return Err(Error::NonRealExpectationValue(value.im));
+    }
+    Ok(value.re)
+}
+
+/// Returns the qubit and operator if the Pauli string has exactly one non-identity factor.
+fn single_qubit_factor(pauli_string: &PauliString) -> Option<(usize, Pauli)> {
+    let mut factors: Vec<(usize, Pauli)> = match pauli_string {
+        PauliString::Dense(ops) => ops
+            .iter()
+            .enumerate()
+            .filter(|(_, op)| **op != Pauli::I)
+            .map(|(qubit, &op)| (qubit, op))
+            .take(2)
+            .collect(),
+        PauliString::Sparse(terms) => terms
+            .iter()
+            .filter(|term| term.op != Pauli::I)
+            .map(|term| (term.qubit, term.op))
+            .take(2)
+            .collect(),
+    };
+    if factors.len() == 1 {
+        factors.pop()
+    } else {
+        None
     }
 }
 
@@ -89,11 +214,220 @@ mod test {
 
     #[test]
     fn test_exp_value_invalid_length() {
+        use crate::error::Error;
+        use stabilizer_ch_form_rust::types::pauli::PauliString;
+
         let sample_state = crate::test_utils::create_sample_stab_decomp_state();
-        let pauli_string =
-            stabilizer_ch_form_rust::types::pauli::PauliString::from_str("ZZII").unwrap();
-        let result = sample_state.exp_value(&pauli_string);
-        assert!(result.is_err());
+        // Too long, too short (both with a single non-identity factor to exercise the fast
+        // path), and a sparse string beyond the last qubit.
+        for (pauli, found) in [("ZZII", 4), ("ZIII", 4), ("XZ", 2), ("ZI", 2), ("Y3", 4)] {
+            let pauli_string = PauliString::from_str(pauli).unwrap();
+            assert!(matches!(
+                sample_state.exp_value(&pauli_string),
+                Err(Error::InvalidPauliStringLength { expected: 3, found: f }) if f == found
+            ));
+            assert!(matches!(
+                sample_state.exp_value_sum(&[(1.0, pauli_string.clone())]),
+                Err(Error::InvalidPauliStringLength { expected: 3, .. })
+            ));
+            let mut state = sample_state.clone();
+            assert!(matches!(
+                state.apply_pauli_string(&pauli_string),
+                Err(Error::InvalidPauliStringLength { expected: 3, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_single_qubit_exp_value() {
+        use crate::error::Error;
+        use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString};
+
+        let mut state = crate::test_utils::create_sample_stab_decomp_state();
+        state.apply_h(1).unwrap();
+        state.apply_s(2).unwrap();
+        state.apply_sqrt_x(0).unwrap();
+        let num_qubits = state.num_qubits;
+
+        for qubit in 0..num_qubits {
+            for (pauli, op_char) in [(Pauli::X, 'X'), (Pauli::Y, 'Y'), (Pauli::Z, 'Z')] {
+                // Padded dense Pauli string (little-endian: qubit 0 is the rightmost character)
+                let dense: String = (0..num_qubits)
+                    .rev()
+                    .map(|q| if q == qubit { op_char } else { 'I' })
+                    .collect();
+                let padded = PauliString::from_str(&dense).unwrap();
+                let reference = state
+                    .exp_value_with(|stab| stab.apply_pauli(&padded))
+                    .unwrap();
+
+                let fast = state.single_qubit_exp_value(qubit, pauli).unwrap();
+                assert!((fast - reference).abs() < 1e-10);
+                // `exp_value` dispatches to the fast path for both dense and sparse strings
+                let sparse = PauliString::from_str(&format!("{}{}", op_char, qubit)).unwrap();
+                assert!((state.exp_value(&padded).unwrap() - reference).abs() < 1e-10);
+                assert!((state.exp_value(&sparse).unwrap() - reference).abs() < 1e-10);
+            }
+        }
+
+        assert!(matches!(
+            state.single_qubit_exp_value(3, Pauli::Z),
+            Err(Error::QubitIndexOutOfBounds(3, 3))
+        ));
+    }
+
+    #[test]
+    fn test_exp_value_sum() {
+        use crate::test_utils::random_circuit_with_t_gate;
+        use crate::{error::Error, state::QuantumState};
+        use ndarray::Array1;
+        use num_complex::Complex64;
+        use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+        // Naive reference: Σ_k w_k <ψ|P_k|ψ> from the statevector
+        let naive_exp_value = |statevector: &Array1<Complex64>, pauli: &str| {
+            let ops: Vec<char> = pauli.chars().rev().collect();
+            let mut value = Complex64::new(0.0, 0.0);
+            for (x, amplitude) in statevector.iter().enumerate() {
+                // P|x> = phase |y>
+                let mut y = x;
+                let mut phase = Complex64::new(1.0, 0.0);
+                for (q, op) in ops.iter().enumerate() {
+                    let bit = (x >> q) & 1;
+                    match op {
+                        'X' => y ^= 1 << q,
+                        'Y' => {
+                            y ^= 1 << q;
+                            phase *= if bit == 0 {
+                                Complex64::new(0.0, 1.0)
+                            } else {
+                                Complex64::new(0.0, -1.0)
+                            };
+                        }
+                        'Z' if bit == 1 => phase = -phase,
+                        _ => {}
+                    }
+                }
+                value += statevector[y].conj() * phase * amplitude;
+            }
+            value.re
+        };
+
+        let paulis = ["ZZI", "XIY", "IYX", "ZXZ", "III", "YYY"];
+        let weights = [0.5, -1.25, 2.0, 0.75, -0.3, 1.5];
+        let observable: Vec<(f64, PauliString)> = weights
+            .iter()
+            .zip(paulis.iter())
+            .map(|(&w, p)| (w, PauliString::from_str(p).unwrap()))
+            .collect();
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(3, 20, 4, Some(seed));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let statevector = state.to_statevector().unwrap();
+            let expected: f64 = weights
+                .iter()
+                .zip(paulis.iter())
+                .map(|(w, p)| w * naive_exp_value(&statevector, p))
+                .sum();
+            assert!((state.exp_value_sum(&observable).unwrap() - expected).abs() < 1e-8);
+        }
+
+        let state = crate::test_utils::create_sample_stab_decomp_state();
+        assert!(state.exp_value_sum(&[]).unwrap().abs() < 1e-12);
+        let too_long = vec![
+            (1.0, PauliString::from_str("ZII").unwrap()),
+            (1.0, PauliString::from_str("ZIII").unwrap()),
+        ];
+        assert!(matches!(
+            state.exp_value_sum(&too_long),
+            Err(Error::InvalidPauliStringLength {
+                expected: 3,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_exp_value_batch() {
+        use crate::error::Error;
+        use crate::state::QuantumState;
+        use crate::test_utils::random_circuit_with_t_gate;
+        use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+        let paulis: Vec<PauliString> = ["ZZIY", "XIYI", "IIII", "Z2", "YXZX", "X0 Y3"]
+            .iter()
+            .map(|p| PauliString::from_str(p).unwrap())
+            .collect();
+        for i in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 60, 6, Some(200 + i));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let batch = state.exp_value_batch(&paulis).unwrap();
+            assert_eq!(batch.len(), paulis.len());
+            for (pauli, value) in paulis.iter().zip(batch) {
+                assert!((state.exp_value(pauli).unwrap() - value).abs() < 1e-10);
+            }
+        }
+
+        let state = crate::test_utils::create_sample_stab_decomp_state();
+        assert!(state.exp_value_batch(&[]).unwrap().is_empty());
+        assert!(matches!(
+            state.exp_value_batch(&[PauliString::from_str("ZZII").unwrap()]),
+            Err(Error::InvalidPauliStringLength {
+                expected: 3,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_real_exp_value() {
+        use super::real_exp_value;
+        use crate::error::Error;
+        use num_complex::Complex64;
+
+        assert_eq!(real_exp_value(Complex64::new(0.5, 1e-12)).unwrap(), 0.5);
+        assert!(matches!(
+            real_exp_value(Complex64::new(0.5, 1e-3)),
+            Err(Error::NonRealExpectationValue(im)) if im == 1e-3
+        ));
+        assert!(matches!(
+            real_exp_value(Complex64::new(0.0, f64::NAN)),
+            Err(Error::NonRealExpectationValue(_))
+        ));
+
+        // Valid Pauli strings on random states pass the check.
+        for i in 0..5 {
+            let circuit = crate::test_utils::random_circuit_with_t_gate(4, 60, 6, Some(400 + i));
+            let state = crate::state::QuantumState::from_circuit(&circuit).unwrap();
+            for pauli in ["YYXZ", "IZIX", "ZIII", "XYZY"] {
+                let pauli_string =
+                    stabilizer_ch_form_rust::types::pauli::PauliString::from_str(pauli).unwrap();
+                assert!(state.exp_value(&pauli_string).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_value_with_variance() {
+        use crate::state::QuantumState;
+        use crate::test_utils::random_circuit_with_t_gate;
+        use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+        let paulis: Vec<PauliString> = ["ZZIY", "XIYI", "IIII", "Z2", "YXZX"]
+            .iter()
+            .map(|p| PauliString::from_str(p).unwrap())
+            .collect();
+        for i in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 60, 6, Some(300 + i));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            for pauli in &paulis {
+                let expected = state.exp_value(pauli).unwrap();
+                let (mean, variance) = state.exp_value_with_variance(pauli).unwrap();
+                assert!((mean - expected).abs() < 1e-10);
+                assert!((variance - (1.0 - expected * expected)).abs() < 1e-10);
+                assert!(variance >= 0.0);
+            }
+        }
     }
 }
 // WIP: Add tests
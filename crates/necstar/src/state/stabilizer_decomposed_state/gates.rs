@@ -1,10 +1,14 @@
-use crate::circuit::QuantumGate;
-use crate::error::Result;
+use stabilizer_ch_form_rust::circuit::CliffordGate;
+use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+use crate::circuit::{QuantumCircuit, QuantumGate};
+use crate::error::{Error, Result};
 use crate::state::Coefficient;
 use crate::state::StabilizerDecomposedState;
 
 impl<T: Coefficient> StabilizerDecomposedState<T> {
     pub(crate) fn apply_gate(&mut self, gate: &QuantumGate) -> Result<()> {
+        gate.validate(self.num_qubits)?;
         match gate {
             QuantumGate::H(q) => self.apply_h(*q),
             QuantumGate::X(q) => self.apply_x(*q),
@@ -17,15 +21,34 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
             QuantumGate::CX(c, t) => self.apply_cx(*c, *t),
             QuantumGate::CZ(q1, q2) => self.apply_cz(*q1, *q2),
             QuantumGate::Swap(q1, q2) => self.apply_swap(*q1, *q2),
+            QuantumGate::ISwap(_, _)
+            | QuantumGate::ISwapdg(_, _)
+            | QuantumGate::Clifford1(_, _) => self.apply_gates(&gate.decompose()),
             QuantumGate::T(_) => Err(crate::error::Error::NonCliffordGateApplication(
                 gate.name().to_string(),
             )),
             QuantumGate::Tdg(_) => Err(crate::error::Error::NonCliffordGateApplication(
                 gate.name().to_string(),
             )),
-            QuantumGate::CCX(_, _, _) => Err(crate::error::Error::NonCliffordGateApplication(
+            QuantumGate::CS(_, _)
+            | QuantumGate::CSdg(_, _)
+            | QuantumGate::CCX(_, _, _)
+            | QuantumGate::CCZ(_, _, _)
+            | QuantumGate::CSwap(_, _, _) => Err(crate::error::Error::NonCliffordGateApplication(
                 gate.name().to_string(),
             )),
+            QuantumGate::Phase(_, _)
+            | QuantumGate::RZ(_, _)
+            | QuantumGate::RX(_, _)
+            | QuantumGate::RY(_, _) => {
+                if gate.is_clifford() {
+                    self.apply_gates(&gate.decompose())
+                } else {
+                    Err(crate::error::Error::NonCliffordGateApplication(
+                        gate.name().to_string(),
+                    ))
+                }
+            }
         }
     }
 
@@ -36,6 +59,40 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         Ok(())
     }
 
+    /// Applies the Clifford circuit `circuit` to every stabilizer component.
+    ///
+    /// The gates are converted to [`CliffordGate`]s once, so that a non-Clifford gate is reported
+    /// before the state is modified, and then applied component by component.
+    pub(crate) fn apply_circuit(&mut self, circuit: &QuantumCircuit) -> Result<()> {
+        if circuit.num_qubits != self.num_qubits {
+            return Err(Error::QubitCountMismatch {
+                operation: "apply_circuit",
+                left: self.num_qubits,
+                right: circuit.num_qubits,
+            });
+        }
+        let mut clifford_gates = Vec::with_capacity(circuit.gates.len());
+        for gate in &circuit.gates {
+            gate.validate(self.num_qubits)?;
+            push_clifford_gates(gate, &mut clifford_gates)?;
+        }
+        for stab in self.stabilizers.iter_mut() {
+            for gate in &clifford_gates {
+                stab.apply_gate(gate)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the Pauli operator `pauli_string` to every stabilizer component.
+    pub(crate) fn apply_pauli_string(&mut self, pauli_string: &PauliString) -> Result<()> {
+        self.validate_pauli_string(pauli_string)?;
+        for stab in self.stabilizers.iter_mut() {
+            stab.apply_pauli(pauli_string)?;
+        }
+        Ok(())
+    }
+
     // Single-qubit gates
     pub(crate) fn apply_x(&mut self, qarg: usize) -> Result<()> {
         for stab in self.stabilizers.iter_mut() {
@@ -115,3 +172,123 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         Ok(())
     }
 }
+
+/// Appends the [`CliffordGate`]s implementing `gate` to `out`, or returns an error if `gate` is
+/// not a Clifford gate.
+fn push_clifford_gates(gate: &QuantumGate, out: &mut Vec<CliffordGate>) -> Result<()> {
+    if let Ok(clifford_gate) = gate.to_clifford_gate() {
+        out.push(clifford_gate);
+        return Ok(());
+    }
+    if !gate.is_clifford() {
+        return Err(Error::NonCliffordGateApplication(gate.name().to_string()));
+    }
+    for sub_gate in gate.decompose() {
+        push_clifford_gates(&sub_gate, out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num_complex::Complex64;
+    use stabilizer_ch_form_rust::types::pauli::PauliString;
+
+    use crate::error::Error;
+    use crate::test_utils::{create_all_zero_state, create_sample_stab_decomp_state};
+
+    #[test]
+    fn test_apply_pauli_string() {
+        // XX |00> = |11>
+        let mut state = create_all_zero_state(2);
+        state
+            .apply_pauli_string(&PauliString::from_str("XX").unwrap())
+            .unwrap();
+        let sv = state.to_statevector().unwrap();
+        assert!((sv[3] - Complex64::new(1.0, 0.0)).norm() < 1e-10);
+        assert!(sv[0].norm() < 1e-10);
+
+        // Y0 Z2 on 1/2 (|000> + |001> + |010> + |111>), compared with single-qubit gates
+        let mut state = create_sample_stab_decomp_state();
+        let mut expected = state.clone();
+        state
+            .apply_pauli_string(&PauliString::from_str("Y0 Z2").unwrap())
+            .unwrap();
+        expected.apply_y(0).unwrap();
+        expected.apply_z(2).unwrap();
+        let sv = state.to_statevector().unwrap();
+        let expected_sv = expected.to_statevector().unwrap();
+        for (a, b) in sv.iter().zip(expected_sv.iter()) {
+            assert!((a - b).norm() < 1e-10);
+        }
+
+        assert!(matches!(
+            state.apply_pauli_string(&PauliString::from_str("XX").unwrap()),
+            Err(Error::InvalidPauliStringLength {
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            state.apply_pauli_string(&PauliString::from_str("X3").unwrap()),
+            Err(Error::InvalidPauliStringLength {
+                expected: 3,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_apply_circuit() {
+        use crate::circuit::{Clifford1Index, QuantumCircuit, QuantumGate};
+
+        let mut circuit = QuantumCircuit::random_clifford(3, Some([3; 32]));
+        circuit.apply_gates(&[
+            QuantumGate::RZ(0, 2),
+            QuantumGate::RX(1, 6),
+            QuantumGate::ISwap(0, 2),
+            QuantumGate::Clifford1(1, Clifford1Index::new(17).unwrap()),
+            QuantumGate::Swap(1, 2),
+        ]);
+
+        let mut state = create_sample_stab_decomp_state();
+        let mut expected = state.clone();
+        state.apply_circuit(&circuit).unwrap();
+        expected.apply_gates(&circuit.gates).unwrap();
+        let sv = state.to_statevector().unwrap();
+        let expected_sv = expected.to_statevector().unwrap();
+        for (a, b) in sv.iter().zip(expected_sv.iter()) {
+            assert!((a - b).norm() < 1e-10);
+        }
+
+        // A non-Clifford gate is rejected before any gate is applied.
+        let mut non_clifford = QuantumCircuit::new(3);
+        non_clifford.apply_h(0);
+        non_clifford.apply_ccx(0, 1, 2);
+        assert!(matches!(
+            state.apply_circuit(&non_clifford),
+            Err(Error::NonCliffordGateApplication(_))
+        ));
+        let mut non_clifford = QuantumCircuit::new(3);
+        non_clifford.apply_t(1);
+        assert!(matches!(
+            state.apply_circuit(&non_clifford),
+            Err(Error::NonCliffordGateApplication(_))
+        ));
+        let unchanged = state.to_statevector().unwrap();
+        for (a, b) in unchanged.iter().zip(sv.iter()) {
+            assert!((a - b).norm() < 1e-10);
+        }
+
+        assert!(matches!(
+            state.apply_circuit(&QuantumCircuit::new(2)),
+            Err(Error::QubitCountMismatch {
+                operation: "apply_circuit",
+                left: 3,
+                right: 2
+            })
+        ));
+    }
+}
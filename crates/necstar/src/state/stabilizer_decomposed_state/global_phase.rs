@@ -0,0 +1,146 @@
+use num_complex::Complex64;
+
+use crate::error::{Error, Result};
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+/// Relative probability difference below which both outcomes of a qubit are regarded as equally
+/// likely when choosing the reference basis state.
+const TIE_TOL: f64 = 1e-10;
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Returns the computational basis state used as the phase reference of
+    /// [`global_phase`](Self::global_phase).
+    ///
+    /// Starting from the highest qubit, each qubit is fixed to its more likely outcome given the
+    /// outcomes fixed so far, preferring `0` on ties. The result is therefore independent of the
+    /// decomposition and its amplitude satisfies `|<x|ψ>|² >= 2^-n <ψ|ψ>`.
+    /// The `i`-th element of the result corresponds to qubit `i`.
+    pub(crate) fn phase_reference(&self) -> Result<Vec<bool>> {
+        let mut remaining = self.clone();
+        let mut remaining_norm_sq = remaining.norm_squared()?;
+        if remaining_norm_sq < 1e-24 || remaining_norm_sq.is_nan() {
+            return Err(Error::ZeroNormState);
+        }
+
+        let mut bitstring = vec![false; self.num_qubits];
+        for qubit in (0..self.num_qubits).rev() {
+            let mut zero_branch = remaining.clone();
+            zero_branch.project_unnormalized(qubit, false)?;
+            let zero_norm_sq = zero_branch.norm_squared()?;
+            let one_norm_sq = remaining_norm_sq - zero_norm_sq;
+            if zero_norm_sq + TIE_TOL * remaining_norm_sq >= one_norm_sq {
+                remaining = zero_branch;
+                remaining_norm_sq = zero_norm_sq;
+            } else {
+                remaining.project_unnormalized(qubit, true)?;
+                remaining_norm_sq = one_norm_sq;
+                bitstring[qubit] = true;
+            }
+        }
+        Ok(bitstring)
+    }
+
+    /// Returns the global phase of the state, defined as the phase of the amplitude of the
+    /// basis state given by [`phase_reference`](Self::phase_reference).
+    pub(crate) fn global_phase(&self) -> Result<Complex64> {
+        let amplitude = self.amplitude(&self.phase_reference()?)?;
+        Ok(amplitude / amplitude.norm())
+    }
+
+    /// Multiplies the global factor by a phase so that [`global_phase`](Self::global_phase)
+    /// returns `phase`.
+    pub(crate) fn set_global_phase(&mut self, phase: Complex64) -> Result<()> {
+        if (phase.norm_sqr() - 1.0).abs() > 1e-8 {
+            return Err(Error::NonUnitGlobalPhase(phase.norm()));
+        }
+        let current = self.global_phase()?;
+        self.global_factor *= phase / current;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex64;
+
+    use crate::circuit::QuantumCircuit;
+    use crate::error::Error;
+    use crate::state::QuantumState;
+    use crate::test_utils::{assert_eq_complex, random_circuit_with_t_gate};
+
+    #[test]
+    fn test_global_phase() {
+        let zero = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
+        assert_eq_complex(zero.global_phase().unwrap(), Complex64::new(1.0, 0.0));
+
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_x(0);
+        let one = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq_complex(one.global_phase().unwrap(), Complex64::new(1.0, 0.0));
+
+        // Z|1> = -|1>
+        circuit.apply_z(0);
+        let minus_one = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq_complex(minus_one.global_phase().unwrap(), Complex64::new(-1.0, 0.0));
+
+        // S X |0> = i|1>
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_x(0);
+        circuit.apply_s(0);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq_complex(state.global_phase().unwrap(), Complex64::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_global_phase_matches_statevector() {
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 40, 5, Some(200 + seed));
+            let mut state = QuantumState::from_circuit(&circuit).unwrap();
+            let statevector = state.to_statevector().unwrap();
+
+            // The reference is found by fixing the qubits from the highest to the more likely
+            // outcome.
+            let mut candidates: Vec<usize> = (0..16).collect();
+            for qubit in (0..4).rev() {
+                let weight = |bit: usize| -> f64 {
+                    candidates
+                        .iter()
+                        .filter(|&&i| (i >> qubit) & 1 == bit)
+                        .map(|&i| statevector[i].norm_sqr())
+                        .sum()
+                };
+                let bit = if weight(0) + 1e-10 >= weight(1) { 0 } else { 1 };
+                candidates.retain(|&i| (i >> qubit) & 1 == bit);
+            }
+            let amplitude = statevector[candidates[0]];
+            assert_eq_complex(state.global_phase().unwrap(), amplitude / amplitude.norm());
+
+            // Setting the phase rotates the statevector accordingly.
+            let phase = Complex64::from_polar(1.0, 0.3);
+            state.set_global_phase(phase).unwrap();
+            assert_eq_complex(state.global_phase().unwrap(), phase);
+            let rotated = state.to_statevector().unwrap();
+            assert_eq_complex(
+                rotated[candidates[0]] / rotated[candidates[0]].norm(),
+                phase,
+            );
+            assert!(
+                state
+                    .fidelity(&QuantumState::from_circuit(&circuit).unwrap())
+                    .unwrap()
+                    > 1.0 - 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn test_global_phase_errors() {
+        let mut state = QuantumState::from_circuit(&QuantumCircuit::new(1)).unwrap();
+        assert!(matches!(
+            state.set_global_phase(Complex64::new(2.0, 0.0)),
+            Err(Error::NonUnitGlobalPhase(_))
+        ));
+        state.project_unnormalized(0, true).unwrap();
+        assert!(matches!(state.global_phase(), Err(Error::ZeroNormState)));
+    }
+}
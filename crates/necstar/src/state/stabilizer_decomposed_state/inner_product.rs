@@ -1,21 +1,102 @@
 use num_complex::Complex64;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use stabilizer_ch_form_rust::StabilizerCHForm;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::state::{Coefficient, StabilizerDecomposedState};
 
 impl<T: Coefficient> StabilizerDecomposedState<T> {
     /// Computes the inner product between two [`StabilizerDecomposedState`] instances.
     /// i.e. ⟨self|other⟩
+    ///
+    /// With the `parallel` feature, the rows of the χ₁×χ₂ sum are distributed over threads.
     pub(crate) fn inner_product(&self, other: &Self) -> Result<Complex64> {
-        let mut result = Complex64::new(0.0, 0.0);
+        #[cfg(feature = "parallel")]
+        let result = self.inner_product_sum_parallel(other)?;
+        #[cfg(not(feature = "parallel"))]
+        let result = self.inner_product_sum_serial(other)?;
+        Ok(result * self.global_factor.conj() * other.global_factor)
+    }
 
+    /// Computes Σ_i Σ_j c_i* d_j ⟨S_i|T_j⟩ without the global factors on a single thread.
+    #[cfg_attr(feature = "parallel", allow(dead_code))]
+    pub(crate) fn inner_product_sum_serial(&self, other: &Self) -> Result<Complex64> {
+        let mut result = Complex64::new(0.0, 0.0);
         for (stab1, coeff1) in self.stabilizers.iter().zip(self.coefficients.iter()) {
-            for (stab2, coeff2) in other.stabilizers.iter().zip(other.coefficients.iter()) {
-                let ip = stab1.inner_product(stab2)?;
-                result += (coeff1.conj() * *coeff2).into() * ip;
-            }
+            result += other.inner_product_row(stab1, coeff1)?;
+        }
+        Ok(result)
+    }
+
+    /// Computes Σ_i Σ_j c_i* d_j ⟨S_i|T_j⟩ without the global factors, summing the rows in
+    /// parallel.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn inner_product_sum_parallel(&self, other: &Self) -> Result<Complex64> {
+        self.stabilizers
+            .par_iter()
+            .zip(self.coefficients.par_iter())
+            .map(|(stab1, coeff1)| other.inner_product_row(stab1, coeff1))
+            .try_reduce(|| Complex64::new(0.0, 0.0), |a, b| Ok(a + b))
+    }
+
+    /// Computes Σ_j c* d_j ⟨stab|T_j⟩ for a single component `c |stab>` of the bra.
+    fn inner_product_row(&self, stab: &StabilizerCHForm, coeff: &T) -> Result<Complex64> {
+        let mut row = Complex64::new(0.0, 0.0);
+        for (stab2, coeff2) in self.stabilizers.iter().zip(self.coefficients.iter()) {
+            let ip = stab.inner_product(stab2)?;
+            row += (coeff.conj() * *coeff2).into() * ip;
+        }
+        Ok(row)
+    }
+
+    /// Computes the normalized overlap ⟨self|other⟩ / (‖self‖·‖other‖).
+    /// Returns `None` if either state has (numerically) zero norm.
+    fn normalized_overlap(&self, other: &Self, tol: f64) -> Result<Option<Complex64>> {
+        let inner_product = self.inner_product(other)?;
+        let norms = self.norm()? * other.norm()?;
+        if norms < tol {
+            return Ok(None);
+        }
+        Ok(Some(inner_product / norms))
+    }
+
+    /// Computes the fidelity |⟨self|other⟩|^2 / (‖self‖^2·‖other‖^2) between the two states.
+    /// The states are normalized internally, so unnormalized states are also accepted.
+    pub(crate) fn fidelity(&self, other: &Self) -> Result<f64> {
+        if self.num_qubits != other.num_qubits {
+            return Err(Error::QubitCountMismatch {
+                operation: "fidelity",
+                left: self.num_qubits,
+                right: other.num_qubits,
+            });
+        }
+        match self.normalized_overlap(other, 1e-12)? {
+            Some(overlap) => Ok(overlap.norm_sqr().clamp(0.0, 1.0)),
+            None => Err(Error::NotImplemented(
+                "Fidelity with a zero-norm state is not defined.".to_string(),
+            )),
+        }
+    }
+
+    /// Checks whether the two states are equal up to a global phase, i.e. whether
+    /// |⟨self|other⟩| / (‖self‖·‖other‖) is 1 within `tol`.
+    /// Two zero-norm states are considered equal.
+    pub(crate) fn approx_eq(&self, other: &Self, tol: f64) -> Result<bool> {
+        match self.normalized_overlap(other, tol)? {
+            Some(overlap) => Ok((overlap.norm() - 1.0).abs() < tol),
+            None => Ok(self.norm()? < tol && other.norm()? < tol),
+        }
+    }
+
+    /// Checks whether the two states are equal as vectors up to normalization, including the
+    /// global phase, i.e. whether ⟨self|other⟩ / (‖self‖·‖other‖) is 1 within `tol`.
+    /// Two zero-norm states are considered equal.
+    pub(crate) fn approx_eq_with_phase(&self, other: &Self, tol: f64) -> Result<bool> {
+        match self.normalized_overlap(other, tol)? {
+            Some(overlap) => Ok((overlap - 1.0).norm() < tol),
+            None => Ok(self.norm()? < tol && other.norm()? < tol),
         }
-        Ok(result * self.global_factor.conj() * other.global_factor)
     }
 }
 
@@ -25,6 +106,7 @@ mod tests {
     use stabilizer_ch_form_rust::StabilizerCHForm;
 
     use crate::{
+        error::Error,
         prelude::QuantumState,
         state::StabilizerDecomposedState,
         test_utils::{
@@ -80,5 +162,102 @@ mod tests {
             assert_eq_complex(inner_prod_naive, inner_prod_efficient);
         }
     }
+
+    #[test]
+    fn test_fidelity() {
+        let state = create_sample_stab_decomp_state();
+        assert!((state.fidelity(&state.clone()).unwrap() - 1.0).abs() < 1e-10);
+
+        // The global factor does not affect the fidelity
+        let mut scaled = state.clone();
+        scaled.global_factor *= Complex64::new(0.0, 3.0);
+        assert!((state.fidelity(&scaled).unwrap() - 1.0).abs() < 1e-10);
+
+        // X on a single qubit of |000> gives an orthogonal state
+        let zero = StabilizerDecomposedState::new(
+            3,
+            vec![StabilizerCHForm::new(3).unwrap()],
+            vec![Complex64::new(1.0, 0.0)],
+        );
+        let mut flipped = zero.clone();
+        flipped.apply_x(1).unwrap();
+        assert!(zero.fidelity(&flipped).unwrap().abs() < 1e-10);
+
+        // |<000|ψ>|^2 = 1/4
+        assert!((state.fidelity(&zero).unwrap() - 0.25).abs() < 1e-10);
+
+        let two_qubits = StabilizerDecomposedState::new(
+            2,
+            vec![StabilizerCHForm::new(2).unwrap()],
+            vec![Complex64::new(1.0, 0.0)],
+        );
+        assert!(matches!(
+            state.fidelity(&two_qubits),
+            Err(Error::QubitCountMismatch {
+                operation: "fidelity",
+                left: 3,
+                right: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_approx_eq_with_phase() {
+        let state = create_sample_stab_decomp_state();
+        let mut negated = state.clone();
+        negated.global_factor *= -1.0;
+
+        assert!(state.approx_eq(&negated, 1e-8).unwrap());
+        assert!(!state.approx_eq_with_phase(&negated, 1e-8).unwrap());
+        assert!(state.approx_eq_with_phase(&state.clone(), 1e-8).unwrap());
+
+        // i|ψ> is also different from |ψ> including the phase
+        let mut rotated = state.clone();
+        rotated.global_factor *= Complex64::new(0.0, 1.0);
+        assert!(state.approx_eq(&rotated, 1e-8).unwrap());
+        assert!(!state.approx_eq_with_phase(&rotated, 1e-8).unwrap());
+
+        // X_0 changes the state itself
+        let mut flipped = state.clone();
+        flipped.apply_x(0).unwrap();
+        assert!(!state.approx_eq(&flipped, 1e-8).unwrap());
+        assert!(!state.approx_eq_with_phase(&flipped, 1e-8).unwrap());
+    }
+
+    // This test is ignored by default due to its long runtime.
+    // Run with `cargo test --features parallel -- --ignored test_parallel_matches_serial`.
+    #[cfg(feature = "parallel")]
+    #[test]
+    #[ignore]
+    fn test_parallel_matches_serial() {
+        use std::time::Instant;
+
+        // χ = 2^10 = 1024
+        let base_state = create_sample_stab_decomp_state();
+        let mut state = base_state.clone();
+        for _ in 1..10 {
+            state = state.kron(&base_state).unwrap();
+        }
+        let mut other = state.clone();
+        other.apply_h(0).unwrap();
+        other.apply_s(3).unwrap();
+        assert_eq!(state.stabilizers.len(), 1024);
+
+        let start = Instant::now();
+        let serial = state.inner_product_sum_serial(&other).unwrap();
+        println!("inner_product (serial): {:?}", start.elapsed());
+        let start = Instant::now();
+        let parallel = state.inner_product_sum_parallel(&other).unwrap();
+        println!("inner_product (parallel): {:?}", start.elapsed());
+        assert_eq_complex(serial, parallel);
+
+        let start = Instant::now();
+        let serial = state.norm_squared_sum_serial().unwrap();
+        println!("norm_squared (serial): {:?}", start.elapsed());
+        let start = Instant::now();
+        let parallel = state.norm_squared_sum_parallel().unwrap();
+        println!("norm_squared (parallel): {:?}", start.elapsed());
+        assert_eq_complex(serial, parallel);
+    }
 }
 // WIP: Add simple case tests
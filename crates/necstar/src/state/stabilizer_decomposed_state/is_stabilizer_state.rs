@@ -0,0 +1,218 @@
+use num_complex::Complex64;
+
+use crate::error::Result;
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Checks whether the state equals a single stabilizer state up to global phase.
+    ///
+    /// A decomposition with a single term is trivially a stabilizer state. Otherwise, χ is first
+    /// reduced with the Gram matrix as in [`recompress`](Self::recompress), and the state is a
+    /// stabilizer state if a single component is left with a significant coefficient. Both steps
+    /// scale with χ rather than with `2^n`.
+    ///
+    /// If several components remain, the statevector is checked against the characterization of
+    /// stabilizer states by Dehaene and De Moor: the support must be an affine subspace
+    /// `x0 + span(v_1, ..., v_r)` with uniform magnitudes, and the relative phases must take the
+    /// form `i^{l(c)} (-1)^{q(c)}` for a linear `l` and a quadratic `q` in the coordinates `c`.
+    /// This fails with [`Error::StatevectorTooLarge`](crate::error::Error::StatevectorTooLarge)
+    /// for more than
+    /// [`MAX_QUBITS_FOR_STATEVECTOR`](super::statevector::MAX_QUBITS_FOR_STATEVECTOR) qubits.
+    pub(crate) fn is_stabilizer_state(&self, tol: f64) -> Result<bool> {
+        if self.stabilizers.len() == 1 {
+            return Ok(true);
+        }
+
+        let reduced = self.recompress()?;
+        let magnitudes: Vec<f64> = reduced
+            .coefficients
+            .iter()
+            .map(|c| (c * reduced.global_factor).norm())
+            .collect();
+        let max_magnitude = magnitudes.iter().cloned().fold(0.0, f64::max);
+        let num_significant = magnitudes
+            .iter()
+            .filter(|&&m| m > tol * max_magnitude)
+            .count();
+        if max_magnitude > 0.0 && num_significant == 1 {
+            return Ok(true);
+        }
+
+        let statevector = reduced.to_statevector()?;
+        let norm = statevector.iter().map(|a| a.norm_sqr()).sum::<f64>().sqrt();
+        if norm < tol {
+            return Ok(false);
+        }
+        let amplitudes: Vec<Complex64> = statevector.iter().map(|a| a / norm).collect();
+
+        // Support of the state
+        let support: Vec<usize> = amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.norm_sqr() > tol)
+            .map(|(x, _)| x)
+            .collect();
+        if !support.len().is_power_of_two() {
+            return Ok(false);
+        }
+        let magnitude = 1.0 / (support.len() as f64).sqrt();
+        if support
+            .iter()
+            .any(|&x| (amplitudes[x].norm() - magnitude).abs() > tol)
+        {
+            return Ok(false);
+        }
+
+        // The support must be an affine subspace: x0 + span(basis).
+        // Since all shifted elements lie in the span, it suffices to compare the sizes.
+        let x0 = support[0];
+        let mut basis: Vec<usize> = Vec::new();
+        for &x in &support {
+            let mut v = x ^ x0;
+            for &b in &basis {
+                v = v.min(v ^ b);
+            }
+            if v != 0 {
+                basis.push(v);
+                basis.sort_unstable_by(|a, b| b.cmp(a));
+            }
+        }
+        let rank = basis.len();
+        if 1 << rank != support.len() {
+            return Ok(false);
+        }
+
+        // Relative phase at the coordinates `c` of the affine subspace.
+        let phase_at = |c: usize| -> Complex64 {
+            let x = (0..rank)
+                .filter(|&i| (c >> i) & 1 == 1)
+                .fold(x0, |acc, i| acc ^ basis[i]);
+            let ratio = amplitudes[x] / amplitudes[x0];
+            ratio / ratio.norm()
+        };
+
+        // Linear part: each basis direction must carry a power of `i`.
+        let linear: Vec<Complex64> = (0..rank).map(|i| phase_at(1 << i)).collect();
+        let quarter_phases = [
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.0, 1.0),
+            Complex64::new(-1.0, 0.0),
+            Complex64::new(0.0, -1.0),
+        ];
+        if linear
+            .iter()
+            .any(|p| quarter_phases.iter().all(|q| (p - q).norm() > tol))
+        {
+            return Ok(false);
+        }
+
+        // Quadratic part: each pair of directions must contribute a sign.
+        let mut quadratic = vec![vec![Complex64::new(1.0, 0.0); rank]; rank];
+        for i in 0..rank {
+            for j in (i + 1)..rank {
+                let sign = phase_at((1 << i) | (1 << j)) / (linear[i] * linear[j]);
+                if (sign - 1.0).norm() > tol && (sign + 1.0).norm() > tol {
+                    return Ok(false);
+                }
+                quadratic[i][j] = Complex64::new(sign.re.signum(), 0.0);
+                quadratic[j][i] = quadratic[i][j];
+            }
+        }
+
+        // Every phase must follow from the linear and quadratic parts.
+        // `predicted[c]` is built from `predicted[c without its highest bit]`.
+        let mut predicted = vec![Complex64::new(1.0, 0.0); 1 << rank];
+        for c in 1..(1usize << rank) {
+            let h = usize::BITS as usize - 1 - c.leading_zeros() as usize;
+            let rest = c ^ (1 << h);
+            let mut p = predicted[rest] * linear[h];
+            for (j, q) in quadratic[h].iter().enumerate().take(h) {
+                if (rest >> j) & 1 == 1 {
+                    p *= q;
+                }
+            }
+            predicted[c] = p;
+            if (phase_at(c) - p).norm() > tol {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex64;
+    use stabilizer_ch_form_rust::StabilizerCHForm;
+
+    use crate::circuit::QuantumCircuit;
+    use crate::error::Error;
+    use crate::state::{QuantumState, StabilizerDecomposedState};
+    use crate::test_utils::create_sample_stab_decomp_state;
+
+    #[test]
+    fn test_is_stabilizer_state() {
+        // Output of a Clifford circuit
+        let circuit = QuantumCircuit::random_clifford(4, Some([3; 32]));
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(state.is_stabilizer_state(1e-8).unwrap());
+
+        // T|+> is not a stabilizer state
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(!state.is_stabilizer_state(1e-8).unwrap());
+
+        // T^4 |+> = |-> is a stabilizer state although χ > 1
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_h(0);
+        for _ in 0..4 {
+            circuit.apply_t(0);
+        }
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(state.stabilizer_rank() > 1);
+        assert!(state.is_stabilizer_state(1e-8).unwrap());
+
+        // T|0> = |0> up to phase
+        let mut circuit = QuantumCircuit::new(1);
+        circuit.apply_t(0);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(state.is_stabilizer_state(1e-8).unwrap());
+
+        // T|+> ⊗ T|+> is not a stabilizer state
+        let mut circuit = QuantumCircuit::new(2);
+        for q in 0..2 {
+            circuit.apply_h(q);
+            circuit.apply_t(q);
+        }
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(!state.is_stabilizer_state(1e-8).unwrap());
+
+        // 1/2 (|000> + |001> + |010> + |111>): the support is not an affine subspace
+        let state = create_sample_stab_decomp_state();
+        assert!(!state.is_stabilizer_state(1e-8).unwrap());
+
+        // Beyond the statevector limit, |0...0> + i|0...0> is reduced to χ = 1 with the Gram
+        // matrix, while |0...0> + |10...0> = |+0...0> would need the statevector.
+        let zero = StabilizerCHForm::new(40).unwrap();
+        let mut one = zero.clone();
+        one.apply_x(0).unwrap();
+        let state = StabilizerDecomposedState::new(
+            40,
+            vec![zero.clone(), zero.clone()],
+            vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)],
+        );
+        assert!(state.is_stabilizer_state(1e-8).unwrap());
+        let state = StabilizerDecomposedState::new(
+            40,
+            vec![zero, one],
+            vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)],
+        );
+        assert!(matches!(
+            state.is_stabilizer_state(1e-8),
+            Err(Error::StatevectorTooLarge(40))
+        ));
+    }
+}
@@ -14,17 +14,20 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
                 new_coefficients.push(*coeff1 * *coeff2);
             }
         }
-        Ok(StabilizerDecomposedState::new(
+        let mut state = StabilizerDecomposedState::new(
             self.num_qubits + other.num_qubits,
             new_stabilizers,
             new_coefficients,
-        ))
+        );
+        state.global_factor = self.global_factor * other.global_factor;
+        Ok(state)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test_utils::{assert_eq_complex_array1, create_sample_stab_decomp_state};
+    use num_complex::Complex64;
 
     #[test]
     fn test_kron() {
@@ -41,4 +44,20 @@ mod tests {
         };
         assert_eq_complex_array1(&sv, &expected_sv);
     }
+
+    #[test]
+    fn test_kron_global_factor() {
+        let mut state1 = create_sample_stab_decomp_state();
+        let mut state2 = create_sample_stab_decomp_state();
+        state1.amplify_global_factor(Complex64::new(0.0, 2.0));
+        state2.amplify_global_factor(Complex64::new(0.5, 0.0));
+        let kron_state = state1.kron(&state2).unwrap();
+        assert_eq!(kron_state.global_factor, Complex64::new(0.0, 1.0));
+
+        let expected_sv = crate::test_utils::tensor_statevectors(
+            &state1.to_statevector().unwrap(),
+            &state2.to_statevector().unwrap(),
+        );
+        assert_eq_complex_array1(&kron_state.to_statevector().unwrap(), &expected_sv);
+    }
 }
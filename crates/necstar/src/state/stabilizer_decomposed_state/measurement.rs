@@ -2,21 +2,31 @@ use crate::error::{Error, Result};
 use crate::state::{Coefficient, StabilizerDecomposedState};
 use num_complex::Complex64;
 use rand::rngs::StdRng;
-use rand::{Rng, SeedableRng};
+use rand::{Rng, RngCore, SeedableRng};
+use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString};
 
 impl<T: Coefficient> StabilizerDecomposedState<T> {
     /// Measures the specified qubits and returns the measurement outcomes as a vector of booleans.
     /// `true` represents outcome `1`, and `false` represents outcome `0`.
     pub(crate) fn measure(&mut self, qargs: &[usize], seed: Option<[u8; 32]>) -> Result<Vec<bool>> {
-        self.validate_qargs(qargs)?;
         let mut rng = match seed {
             Some(s) => rand::rngs::StdRng::from_seed(s),
             None => rand::rngs::StdRng::from_entropy(),
         };
+        self.measure_with_rng(qargs, &mut rng)
+    }
+
+    /// Same as [`measure`](Self::measure), but draws the random numbers from `rng`.
+    pub(crate) fn measure_with_rng<R: RngCore + ?Sized>(
+        &mut self,
+        qargs: &[usize],
+        rng: &mut R,
+    ) -> Result<Vec<bool>> {
+        self.validate_qargs(qargs)?;
         let mut outcomes = Vec::with_capacity(qargs.len());
 
         for &qubit in qargs {
-            let outcome = self.measure_single_qubit(qubit, &mut rng)?;
+            let outcome = self.measure_single_qubit(qubit, rng)?;
             outcomes.push(outcome);
         }
 
@@ -31,7 +41,87 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         self.measure(&qargs, seed)
     }
 
-    fn measure_single_qubit(&mut self, qubit: usize, rng: &mut StdRng) -> Result<bool> {
+    /// Resets `qubit` to `|0>` by measuring it in the computational basis and flipping it back
+    /// with `X` if the outcome was `1`. The remaining qubits are conditioned on the outcome.
+    pub(crate) fn reset(&mut self, qubit: usize, seed: Option<[u8; 32]>) -> Result<()> {
+        self.measure_and_reset(qubit, seed).map(|_| ())
+    }
+
+    /// Same as [`reset`](Self::reset), but returns the measurement outcome.
+    pub(crate) fn measure_and_reset(
+        &mut self,
+        qubit: usize,
+        seed: Option<[u8; 32]>,
+    ) -> Result<bool> {
+        let outcome = self.measure(&[qubit], seed)?[0];
+        if outcome {
+            self.apply_x(qubit)?;
+        }
+        Ok(outcome)
+    }
+
+    /// Measures the Hermitian Pauli observable `pauli_string` and returns its eigenvalue (`1` or
+    /// `-1`). The state collapses onto the corresponding eigenspace.
+    ///
+    /// The observable is rotated onto a single `Z` by a Clifford circuit `U` (basis changes to
+    /// `Z` followed by a CX ladder collecting the parity on the last qubit of the support), the
+    /// qubit is measured, and `U†` is applied to rotate the state back.
+    pub(crate) fn measure_observable(
+        &mut self,
+        pauli_string: &PauliString,
+        seed: Option<[u8; 32]>,
+    ) -> Result<i8> {
+        self.validate_pauli_string(pauli_string)?;
+        let factors = non_identity_factors(pauli_string)?;
+        // The identity has the eigenvalue +1 on every state.
+        let Some(&(parity_qubit, _)) = factors.last() else {
+            return Ok(1);
+        };
+        let mut rng = match seed {
+            Some(s) => StdRng::from_seed(s),
+            None => StdRng::from_entropy(),
+        };
+
+        // U P U† = Z_{parity_qubit}
+        for &(qubit, op) in &factors {
+            match op {
+                Pauli::X => self.apply_h(qubit)?,
+                Pauli::Y => {
+                    self.apply_sdg(qubit)?;
+                    self.apply_h(qubit)?;
+                }
+                _ => {}
+            }
+        }
+        for &(qubit, _) in &factors[..factors.len() - 1] {
+            self.apply_cx(qubit, parity_qubit)?;
+        }
+
+        let outcome = self.measure_single_qubit(parity_qubit, &mut rng)?;
+
+        // Apply U† to rotate back.
+        for &(qubit, _) in factors[..factors.len() - 1].iter().rev() {
+            self.apply_cx(qubit, parity_qubit)?;
+        }
+        for &(qubit, op) in &factors {
+            match op {
+                Pauli::X => self.apply_h(qubit)?,
+                Pauli::Y => {
+                    self.apply_h(qubit)?;
+                    self.apply_s(qubit)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(if outcome { -1 } else { 1 })
+    }
+
+    fn measure_single_qubit<R: RngCore + ?Sized>(
+        &mut self,
+        qubit: usize,
+        rng: &mut R,
+    ) -> Result<bool> {
         let mut state_zero = self.clone();
         let mut state_one = self.clone();
 
@@ -101,7 +191,61 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
 
         Ok(measurement_outcome)
     }
+
+    /// Returns every possible outcome of measuring `qubit` together with its probability and the
+    /// resulting normalized post-measurement state, without modifying `self`.
+    /// Outcomes with vanishing probability are omitted, so a deterministic qubit yields a single
+    /// branch.
+    pub(crate) fn measured_branches(&self, qubit: usize) -> Result<Vec<(bool, f64, Self)>> {
+        if qubit >= self.num_qubits {
+            return Err(Error::QubitIndexOutOfBounds(qubit, self.num_qubits));
+        }
+        let total_norm_sq = self.norm_squared()?;
+        if total_norm_sq.abs() < 1e-12 {
+            return Err(Error::NotImplemented(
+                "Measurement on a zero-norm state is not possible.".to_string(),
+            ));
+        }
+
+        let mut branches = Vec::with_capacity(2);
+        for outcome in [false, true] {
+            let mut branch = self.clone();
+            branch.project_unnormalized(qubit, outcome)?;
+            let norm_sq = branch.norm_squared()?;
+            let probability = (norm_sq / total_norm_sq).clamp(0.0, 1.0);
+            if probability < 1e-12 {
+                continue;
+            }
+            branch.amplify_global_factor(Complex64::new(1.0 / norm_sq.sqrt(), 0.0));
+            branches.push((outcome, probability, branch));
+        }
+
+        Ok(branches)
+    }
+}
+/// Returns the non-identity factors `(qubit, pauli)` of the Pauli string.
+fn non_identity_factors(pauli_string: &PauliString) -> Result<Vec<(usize, Pauli)>> {
+    let factors: Vec<(usize, Pauli)> = match pauli_string {
+        PauliString::Dense(ops) => ops
+            .iter()
+            .enumerate()
+            .filter(|(_, op)| **op != Pauli::I)
+            .map(|(qubit, &op)| (qubit, op))
+            .collect(),
+        PauliString::Sparse(terms) => terms
+            .iter()
+            .filter(|term| term.op != Pauli::I)
+            .map(|term| (term.qubit, term.op))
+            .collect(),
+    };
+    for (i, (qubit, _)) in factors.iter().enumerate() {
+        if factors[..i].iter().any(|(q, _)| q == qubit) {
+            return Err(Error::DuplicateQubitIndex(*qubit));
+        }
+    }
+    Ok(factors)
 }
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -112,6 +256,184 @@ mod tests {
     use crate::test_utils::create_all_zero_state;
     use crate::test_utils::create_sample_stab_decomp_state;
 
+    #[test]
+    fn test_measured_branches() {
+        // |+> state: two equally likely branches
+        let mut state = create_all_zero_state(1);
+        state.apply_h(0).unwrap();
+        let branches = state.measured_branches(0).unwrap();
+        assert_eq!(branches.len(), 2);
+        for (outcome, probability, branch) in &branches {
+            assert!((probability - 0.5).abs() < 1e-10);
+            assert!((branch.norm().unwrap() - 1.0).abs() < 1e-10);
+            let sv = branch.to_statevector().unwrap();
+            let index = if *outcome { 1 } else { 0 };
+            assert!((sv[index].norm() - 1.0).abs() < 1e-10);
+        }
+        // The original state is untouched
+        assert_eq!(state.stabilizers.len(), 1);
+        assert!((state.to_statevector().unwrap()[1].norm_sqr() - 0.5).abs() < 1e-10);
+
+        // Deterministic qubit: a single branch
+        let branches = create_all_zero_state(2).measured_branches(1).unwrap();
+        assert_eq!(branches.len(), 1);
+        assert!(!branches[0].0);
+        assert!((branches[0].1 - 1.0).abs() < 1e-10);
+
+        // 1/2 (|000> + |001> + |010> + |111>): P(q2 = 1) = 1/4
+        let state = create_sample_stab_decomp_state();
+        let branches = state.measured_branches(2).unwrap();
+        assert_eq!(branches.len(), 2);
+        assert!((branches[0].1 - 0.75).abs() < 1e-10);
+        assert!((branches[1].1 - 0.25).abs() < 1e-10);
+
+        assert!(matches!(
+            state.measured_branches(3),
+            Err(Error::QubitIndexOutOfBounds(3, 3))
+        ));
+    }
+
+    #[test]
+    fn test_measure_observable() {
+        use std::str::FromStr;
+
+        // X on |0>: ±1 with equal probability, collapsing to |±>
+        let x = PauliString::from_str("X").unwrap();
+        let mut counts = HashMap::new();
+        for i in 0..100 {
+            let mut state = create_all_zero_state(1);
+            let eigenvalue = state.measure_observable(&x, Some([i as u8; 32])).unwrap();
+            *counts.entry(eigenvalue).or_insert(0) += 1;
+
+            let sv = state.to_statevector().unwrap();
+            let amp = 1.0 / 2f64.sqrt();
+            assert!((sv[0] - Complex64::new(amp, 0.0)).norm() < 1e-10);
+            assert!((sv[1] - Complex64::new(amp * eigenvalue as f64, 0.0)).norm() < 1e-10);
+            assert!((state.exp_value(&x).unwrap() - eigenvalue as f64).abs() < 1e-10);
+        }
+        assert_eq!(counts.len(), 2);
+        assert!(counts[&1] > 30 && counts[&-1] > 30);
+
+        // Bell state (|00> + |11>)/√2 is a +1 eigenstate of XX and ZZ, and a -1 eigenstate of YY
+        let mut bell = create_all_zero_state(2);
+        bell.apply_h(0).unwrap();
+        bell.apply_cx(0, 1).unwrap();
+        let original = bell.to_statevector().unwrap();
+        for (observable, expected) in [("XX", 1), ("ZZ", 1), ("YY", -1), ("X0 X1", 1)] {
+            let pauli = PauliString::from_str(observable).unwrap();
+            let mut state = bell.clone();
+            let eigenvalue = state.measure_observable(&pauli, Some([7; 32])).unwrap();
+            assert_eq!(eigenvalue, expected);
+            let sv = state.to_statevector().unwrap();
+            for (a, b) in sv.iter().zip(original.iter()) {
+                assert!((a - b).norm() < 1e-10);
+            }
+        }
+
+        // ZI on the Bell state is random, and both qubits agree with it afterwards
+        let zi = PauliString::from_str("ZI").unwrap();
+        let mut counts = HashMap::new();
+        for i in 0..100 {
+            let mut state = bell.clone();
+            let eigenvalue = state.measure_observable(&zi, Some([i as u8; 32])).unwrap();
+            *counts.entry(eigenvalue).or_insert(0) += 1;
+            assert_eq!(state.measure_observable(&zi, None).unwrap(), eigenvalue);
+            let outcome = eigenvalue == -1;
+            assert_eq!(
+                state.measure(&[0, 1], None).unwrap(),
+                vec![outcome, outcome]
+            );
+        }
+        assert!(counts[&1] > 30 && counts[&-1] > 30);
+
+        // Identity: +1 without changing the state
+        let mut state = create_sample_stab_decomp_state();
+        let identity = PauliString::from_str("III").unwrap();
+        assert_eq!(state.measure_observable(&identity, None).unwrap(), 1);
+
+        assert!(matches!(
+            state.measure_observable(&PauliString::from_str("XX").unwrap(), None),
+            Err(Error::InvalidPauliStringLength { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reset() {
+        use std::str::FromStr;
+
+        let z0 = PauliString::from_str("Z0").unwrap();
+        let z1 = PauliString::from_str("Z1").unwrap();
+        for i in 0..20 {
+            // Bell state: resetting qubit 0 leaves qubit 1 in the measured basis state
+            let mut state = create_all_zero_state(2);
+            state.apply_h(0).unwrap();
+            state.apply_cx(0, 1).unwrap();
+            let mut measured = state.clone();
+            let outcome = measured.measure(&[0], Some([i as u8; 32])).unwrap()[0];
+
+            state.reset(0, Some([i as u8; 32])).unwrap();
+            assert!((state.norm().unwrap() - 1.0).abs() < 1e-10);
+            assert!((state.exp_value(&z0).unwrap() - 1.0).abs() < 1e-10);
+            let expected_z1 = if outcome { -1.0 } else { 1.0 };
+            assert!((state.exp_value(&z1).unwrap() - expected_z1).abs() < 1e-10);
+
+            // 1/2 (|000> + |001> + |010> + |111>)
+            let mut state = create_sample_stab_decomp_state();
+            state.reset(0, Some([i as u8; 32])).unwrap();
+            assert!((state.exp_value(&z0).unwrap() - 1.0).abs() < 1e-10);
+        }
+
+        // |1> is reset deterministically
+        let mut state = create_all_zero_state(1);
+        state.apply_x(0).unwrap();
+        state.reset(0, None).unwrap();
+        let sv = state.to_statevector().unwrap();
+        assert!((sv[0].norm() - 1.0).abs() < 1e-10);
+
+        assert!(matches!(
+            state.reset(1, None),
+            Err(Error::QubitIndexOutOfBounds(1, 1))
+        ));
+    }
+
+    #[test]
+    fn test_measure_and_reset() {
+        use std::str::FromStr;
+
+        let z = |q: usize| PauliString::from_str(&format!("Z{}", q)).unwrap();
+        let mut seen = [false; 2];
+        for i in 0..20 {
+            // GHZ state: the other qubits collapse onto the returned outcome
+            let mut state = create_all_zero_state(3);
+            state.apply_h(0).unwrap();
+            state.apply_cx(0, 1).unwrap();
+            state.apply_cx(0, 2).unwrap();
+            let mut measured = state.clone();
+            let expected = measured.measure(&[1], Some([i as u8; 32])).unwrap()[0];
+
+            let outcome = state.measure_and_reset(1, Some([i as u8; 32])).unwrap();
+            assert_eq!(outcome, expected);
+            seen[outcome as usize] = true;
+            assert!((state.norm().unwrap() - 1.0).abs() < 1e-10);
+            assert!((state.exp_value(&z(1)).unwrap() - 1.0).abs() < 1e-10);
+            let expected_z = if outcome { -1.0 } else { 1.0 };
+            for q in [0, 2] {
+                assert!((state.exp_value(&z(q)).unwrap() - expected_z).abs() < 1e-10);
+            }
+        }
+        assert_eq!(seen, [true, true]);
+
+        // |1> always returns `true`
+        let mut state = create_all_zero_state(1);
+        state.apply_x(0).unwrap();
+        assert!(state.measure_and_reset(0, None).unwrap());
+        assert!((state.exp_value(&z(0)).unwrap() - 1.0).abs() < 1e-10);
+        assert!(matches!(
+            state.measure_and_reset(1, None),
+            Err(Error::QubitIndexOutOfBounds(1, 1))
+        ));
+    }
+
     #[test]
     fn test_measure_deterministic() {
         for i in 0..10 {
@@ -259,4 +581,27 @@ mod tests {
         let res_empty = state.measure(&[], None);
         assert!(matches!(res_empty, Err(Error::EmptyQubitIndices)));
     }
+
+    #[test]
+    fn test_measure_with_rng() {
+        let mut rng1 = StdRng::seed_from_u64(99);
+        let mut rng2 = StdRng::seed_from_u64(99);
+        for _ in 0..10 {
+            let mut state1 = create_sample_stab_decomp_state();
+            let mut state2 = create_sample_stab_decomp_state();
+            let outcome1 = state1.measure_with_rng(&[2, 0, 1], &mut rng1).unwrap();
+            let outcome2 = state2.measure_with_rng(&[2, 0, 1], &mut rng2).unwrap();
+            assert_eq!(outcome1, outcome2);
+        }
+
+        let seed = [17u8; 32];
+        let mut state1 = create_sample_stab_decomp_state();
+        let mut state2 = create_sample_stab_decomp_state();
+        assert_eq!(
+            state1.measure_all(Some(seed)).unwrap(),
+            state2
+                .measure_with_rng(&[0, 1, 2], &mut StdRng::from_seed(seed))
+                .unwrap()
+        );
+    }
 }
@@ -1,11 +1,19 @@
+pub mod amplitude;
+pub mod deduplicate;
 pub mod discard;
 pub mod exp_value;
 pub mod gates;
+pub mod global_phase;
 pub mod inner_product;
+pub mod is_stabilizer_state;
 pub mod kron;
 pub mod measurement;
 pub mod norm;
+pub mod permute;
+pub mod probability;
 pub mod projection;
+pub mod recompress;
+pub mod reduced_density_matrix;
 pub mod sampling;
 pub mod statevector;
 
@@ -14,8 +22,17 @@ use stabilizer_ch_form_rust::StabilizerCHForm;
 
 use crate::error::{Error, Result};
 use crate::state::Coefficient;
+use crate::state::types::scalar::Scalar;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>"
+    ))
+)]
 pub(crate) struct StabilizerDecomposedState<T: Coefficient> {
     pub num_qubits: usize,
     pub stabilizers: Vec<StabilizerCHForm>,
@@ -66,8 +83,43 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         Ok(())
     }
 
+    /// Returns the magnitudes `|global_factor * c_i|` of the coefficients of the components.
+    pub(crate) fn coefficient_magnitudes(&self) -> impl Iterator<Item = f64> + '_ {
+        let scale = self.global_factor.norm();
+        self.coefficients
+            .iter()
+            .map(move |&c| Into::<Complex64>::into(c).norm() * scale)
+    }
+
+    /// Returns clones of the components with their coefficients `global_factor * c_i`, so that
+    /// the state is the sum of the scaled components.
+    pub(crate) fn cloned_terms(&self) -> Vec<(StabilizerCHForm, Complex64)> {
+        self.stabilizers
+            .iter()
+            .zip(self.coefficients.iter())
+            .map(|(stab, &c)| {
+                (
+                    stab.clone(),
+                    Into::<Complex64>::into(c) * self.global_factor,
+                )
+            })
+            .collect()
+    }
+
     /// Amplifies the global factor by the given complex number.
     pub(crate) fn amplify_global_factor(&mut self, factor: Complex64) {
         self.global_factor *= factor;
     }
 }
+
+impl StabilizerDecomposedState<Scalar> {
+    /// Converts the exact [`Scalar`] coefficients into `Complex64` ones.
+    pub(crate) fn to_complex_coefficients(&self) -> StabilizerDecomposedState<Complex64> {
+        StabilizerDecomposedState {
+            num_qubits: self.num_qubits,
+            stabilizers: self.stabilizers.clone(),
+            coefficients: self.coefficients.iter().map(|c| c.to_complex()).collect(),
+            global_factor: self.global_factor,
+        }
+    }
+}
@@ -1,36 +1,150 @@
 use num_complex::Complex64;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use stabilizer_ch_form_rust::StabilizerCHForm;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::state::{Coefficient, StabilizerDecomposedState};
 
 impl<T: Coefficient> StabilizerDecomposedState<T> {
     /// Calculates the squared norm of the state.
+    ///
+    /// With the `parallel` feature, the rows of the upper triangle are distributed over threads.
     pub(crate) fn norm_squared(&self) -> Result<f64> {
+        #[cfg(feature = "parallel")]
+        let sum = self.norm_squared_sum_parallel()?;
+        #[cfg(not(feature = "parallel"))]
+        let sum = self.norm_squared_sum_serial()?;
+        // The Gram matrix is Hermitian, so only rounding errors can make the sum complex.
+        debug_assert!(sum.im.abs() <= 1e-10 * sum.re.abs().max(1.0));
+        Ok(sum.re * self.global_factor.norm_sqr())
+    }
+
+    /// Computes Σ_{i,j} c_i* c_j ⟨S_i|S_j⟩ without the global factor on a single thread.
+    #[cfg_attr(feature = "parallel", allow(dead_code))]
+    pub(crate) fn norm_squared_sum_serial(&self) -> Result<Complex64> {
+        let terms = self.terms();
         let mut sum = Complex64::new(0.0, 0.0);
-        let terms: Vec<_> = self
-            .stabilizers
+        for i in 0..terms.len() {
+            sum += Self::norm_squared_row(&terms, i)?;
+        }
+        Ok(sum)
+    }
+
+    /// Computes Σ_{i,j} c_i* c_j ⟨S_i|S_j⟩ without the global factor, summing the rows in
+    /// parallel.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn norm_squared_sum_parallel(&self) -> Result<Complex64> {
+        let terms = self.terms();
+        (0..terms.len())
+            .into_par_iter()
+            .map(|i| Self::norm_squared_row(&terms, i))
+            .try_reduce(|| Complex64::new(0.0, 0.0), |a, b| Ok(a + b))
+    }
+
+    fn terms(&self) -> Vec<(&StabilizerCHForm, &T)> {
+        self.stabilizers
             .iter()
             .zip(self.coefficients.iter())
-            .collect();
-
-        for (i, (stab_i, coeff_i)) in terms.iter().enumerate() {
-            // Diagonal term (j == i)
-            let inner_prod_diag = stab_i.inner_product(stab_i)?;
-            sum += (coeff_i.conj() * **coeff_i).into() * inner_prod_diag;
-
-            // Off-diagonal terms (j > i)
-            for (stab_j, coeff_j) in terms.iter().skip(i + 1) {
-                let inner_prod_off_diag = stab_i.inner_product(stab_j)?;
-                let term = (coeff_i.conj() * **coeff_j).into() * inner_prod_off_diag;
-                sum += term + term.conj();
-            }
-        }
+            .collect()
+    }
 
-        Ok(sum.re * self.global_factor.norm_sqr())
+    /// Computes the contribution of row `i` of the Gram matrix: the diagonal term plus twice the
+    /// real part of the terms with `j > i`.
+    fn norm_squared_row(terms: &[(&StabilizerCHForm, &T)], i: usize) -> Result<Complex64> {
+        let (stab_i, coeff_i) = terms[i];
+
+        // Diagonal term (j == i)
+        let inner_prod_diag = stab_i.inner_product(stab_i)?;
+        let mut sum = (coeff_i.conj() * *coeff_i).into() * inner_prod_diag;
+
+        // Off-diagonal terms (j > i)
+        for (stab_j, coeff_j) in terms.iter().skip(i + 1) {
+            let inner_prod_off_diag = stab_i.inner_product(stab_j)?;
+            let term = (coeff_i.conj() * **coeff_j).into() * inner_prod_off_diag;
+            sum += term + term.conj();
+        }
+        Ok(sum)
     }
 
     /// Calculates the norm of the state.
     pub(crate) fn norm(&self) -> Result<f64> {
         Ok(self.norm_squared()?.sqrt())
     }
+
+    /// Calculates `Tr(ρ²) = |⟨ψ|ψ⟩|² / ‖ψ‖⁴` for `ρ = |ψ⟩⟨ψ| / ‖ψ‖²`.
+    ///
+    /// The numerator uses the full inner product while the denominator uses the symmetric sum of
+    /// [`norm_squared`](Self::norm_squared), so the result deviates from 1 if the two disagree.
+    pub(crate) fn purity(&self) -> Result<f64> {
+        let norm_squared = self.norm_squared()?;
+        if norm_squared < 1e-24 || norm_squared.is_nan() {
+            return Err(Error::ZeroNormState);
+        }
+        Ok(self.inner_product(self)?.norm_sqr() / (norm_squared * norm_squared))
+    }
+
+    /// Rescales the global factor so that the state has norm 1.
+    pub(crate) fn normalize(&mut self) -> Result<()> {
+        let norm = self.norm()?;
+        if norm < 1e-12 || norm.is_nan() {
+            return Err(Error::ZeroNormState);
+        }
+        self.global_factor /= norm;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circuit::QuantumCircuit;
+    use crate::error::Error;
+    use crate::prelude::QuantumState;
+    use crate::test_utils::{_norm_squared, random_circuit_with_t_gate};
+
+    #[test]
+    fn test_normalize() {
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_t(0);
+        circuit.apply_cx(0, 1);
+        let mut state = QuantumState::from_circuit(&circuit).unwrap();
+        state.project_unnormalized(1, true).unwrap();
+        assert!((state.norm().unwrap() - 0.5f64.sqrt()).abs() < 1e-10);
+
+        let unnormalized = state.to_statevector().unwrap();
+        state.normalize().unwrap();
+        assert!((state.norm().unwrap() - 1.0).abs() < 1e-10);
+        let normalized = state.to_statevector().unwrap();
+        for (a, b) in normalized.iter().zip(unnormalized.iter()) {
+            assert!((a - b * 2.0f64.sqrt()).norm() < 1e-10);
+        }
+
+        // |1> on qubit 1 has no |0> component.
+        state.project_unnormalized(1, false).unwrap();
+        assert!(matches!(state.normalize(), Err(Error::ZeroNormState)));
+    }
+
+    #[test]
+    fn test_purity_random() {
+        for i in 0..5 {
+            let circuit = random_circuit_with_t_gate(8, 1000, 10, Some(200 + i));
+            let mut state = QuantumState::from_circuit(&circuit).unwrap();
+            assert!((state.purity().unwrap() - 1.0).abs() < 1e-10);
+            state.project_unnormalized(0, false).unwrap();
+            if state.norm().unwrap() > 1e-6 {
+                assert!((state.purity().unwrap() - 1.0).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_norm_random() {
+        for i in 0..10 {
+            let circuit = random_circuit_with_t_gate(5, 80, 8, Some(100 + i));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let expected = _norm_squared(&state.to_statevector().unwrap()).sqrt();
+            assert!((state.norm().unwrap() - expected).abs() < 1e-10);
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use crate::error::Result;
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Relabels the qubits so that the new qubit `i` is the old qubit `perm[i]`.
+    pub(crate) fn permute_qubits(&mut self, perm: &[usize]) -> Result<()> {
+        // Permute copies first so that an invalid permutation leaves the state untouched.
+        let stabilizers = self
+            .stabilizers
+            .iter()
+            .map(|stab| stab.permuted(perm))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        self.stabilizers = stabilizers;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use stabilizer_ch_form_rust::error::Error as ChFormError;
+
+    use crate::error::Error;
+    use crate::test_utils::{assert_eq_complex_array1, create_sample_stab_decomp_state};
+
+    #[test]
+    fn test_permute_qubits() {
+        // (|000> + |010> + |101> + |111>) / 2
+        let mut state = create_sample_stab_decomp_state();
+        let sv = state.to_statevector().unwrap();
+
+        let perm = [2, 0, 1];
+        state.permute_qubits(&perm).unwrap();
+        let permuted_sv = state.to_statevector().unwrap();
+        for (old_index, amp) in sv.iter().enumerate() {
+            let new_index: usize = perm
+                .iter()
+                .enumerate()
+                .map(|(new_q, &old_q)| ((old_index >> old_q) & 1) << new_q)
+                .sum();
+            assert!((permuted_sv[new_index] - amp).norm() < 1e-10);
+        }
+
+        // Applying the inverse permutation restores the original state.
+        state.permute_qubits(&[1, 2, 0]).unwrap();
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &sv);
+    }
+
+    #[test]
+    fn test_permute_qubits_invalid() {
+        let mut state = create_sample_stab_decomp_state();
+        let sv = state.to_statevector().unwrap();
+
+        assert!(matches!(
+            state.permute_qubits(&[0, 1]),
+            Err(Error::ChForm(ChFormError::InvalidPermutationLength(2, 3)))
+        ));
+        assert!(matches!(
+            state.permute_qubits(&[0, 1, 1]),
+            Err(Error::ChForm(ChFormError::InvalidPermutation(_)))
+        ));
+        assert!(matches!(
+            state.permute_qubits(&[0, 1, 3]),
+            Err(Error::ChForm(ChFormError::InvalidPermutation(_)))
+        ));
+        assert_eq_complex_array1(&state.to_statevector().unwrap(), &sv);
+    }
+}
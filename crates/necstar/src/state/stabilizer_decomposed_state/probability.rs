@@ -0,0 +1,428 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::error::{Error, Result};
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+/// Maximum number of qubits for which a dense probability vector is computed.
+const MAX_QUBITS_FOR_PROBABILITY_VECTOR: usize = 24;
+
+/// Maximum number of qubits for which the outcome distribution is enumerated, same as sampling.
+const MAX_QUBITS_FOR_PROBABILITIES: usize = 128;
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Computes the exact outcome probabilities for the specified qubits as a dense vector of
+    /// length `2^k`, where `k = qargs.len()`.
+    /// The `i`-th bit (little-endian) of the vector index corresponds to the qubit `qargs[i]`.
+    pub(crate) fn probability_vector(&self, qargs: &[usize]) -> Result<Vec<f64>> {
+        self.validate_qargs(qargs)?;
+        if qargs.len() > MAX_QUBITS_FOR_PROBABILITY_VECTOR {
+            return Err(Error::ProbabilityVectorTooLarge(qargs.len()));
+        }
+
+        let mut probabilities = vec![0.0; 1 << qargs.len()];
+        self.for_each_outcome(qargs, &mut |outcome, probability| {
+            let index = outcome
+                .iter()
+                .enumerate()
+                .fold(0usize, |acc, (i, &bit)| acc | ((bit as usize) << i));
+            probabilities[index] = probability;
+            Ok(())
+        })?;
+        Ok(probabilities)
+    }
+
+    /// Computes the exact outcome probabilities for the specified qubits as pairs of outcomes
+    /// with non-zero probability and their probabilities.
+    /// The `i`-th element of each outcome corresponds to the qubit `qargs[i]`, and the outcomes
+    /// are sorted in ascending order of their little-endian integer representation.
+    pub(crate) fn probabilities(&self, qargs: &[usize]) -> Result<Vec<(Vec<bool>, f64)>> {
+        self.validate_qargs(qargs)?;
+        if qargs.len() > MAX_QUBITS_FOR_PROBABILITIES {
+            return Err(Error::SamplingTooManyQubits);
+        }
+
+        let mut distribution = Vec::new();
+        self.for_each_outcome(qargs, &mut |outcome, probability| {
+            distribution.push((outcome.to_vec(), probability));
+            Ok(())
+        })?;
+        distribution.sort_by(|(a, _), (b, _)| a.iter().rev().cmp(b.iter().rev()));
+        Ok(distribution)
+    }
+
+    /// Returns the `k` most probable outcomes of the specified qubits with their probabilities,
+    /// in descending order of probability.
+    ///
+    /// The projection tree is explored best-first: the probability of a partial outcome bounds
+    /// the probability of every completion, so the leaves leave the priority queue in descending
+    /// order and the search stops after `k` of them. Branches that cannot enter the top `k`
+    /// are never expanded.
+    pub(crate) fn most_probable(&self, qargs: &[usize], k: usize) -> Result<Vec<(Vec<bool>, f64)>> {
+        self.validate_qargs(qargs)?;
+        let total_norm_sq = self.norm_squared()?;
+        if total_norm_sq.abs() < 1e-12 {
+            return Err(Error::NotImplemented(
+                "Probabilities of a zero-norm state are not defined.".to_string(),
+            ));
+        }
+
+        // Same ordering as in `for_each_outcome`.
+        let mut sorted_qargs: Vec<(usize, usize)> = qargs
+            .iter()
+            .enumerate()
+            .map(|(bit_index, &qarg)| (qarg, bit_index))
+            .collect();
+        sorted_qargs.sort_by_key(|&(qarg, _)| std::cmp::Reverse(qarg));
+
+        let mut queue = BinaryHeap::new();
+        queue.push(Branch {
+            probability: 1.0,
+            depth: 0,
+            outcome: vec![false; qargs.len()],
+            state: self.clone(),
+        });
+
+        let mut result = Vec::new();
+        while result.len() < k {
+            let Some(branch) = queue.pop() else {
+                break;
+            };
+            if branch.depth == sorted_qargs.len() {
+                result.push((branch.outcome, branch.probability));
+                continue;
+            }
+
+            let (qarg, bit_pos) = sorted_qargs[branch.depth];
+            for value in [false, true] {
+                let mut state = branch.state.clone();
+                state.project_unnormalized(qarg, value)?;
+                if state.stabilizers.is_empty() {
+                    continue;
+                }
+                if value {
+                    state.apply_x(qarg)?; // |1> -> |0>
+                }
+                state.discard(qarg)?;
+                let probability = (state.norm_squared()? / total_norm_sq).clamp(0.0, 1.0);
+                if probability < 1e-12 {
+                    continue;
+                }
+                let mut outcome = branch.outcome.clone();
+                outcome[bit_pos] = value;
+                queue.push(Branch {
+                    probability,
+                    depth: branch.depth + 1,
+                    outcome,
+                    state,
+                });
+            }
+        }
+        Ok(result)
+    }
+
+    /// Calculates the expectation value of a diagonal observable `diag(f)`, i.e.
+    /// `Σ_x |<x|ψ>|^2 f(x)`, by enumerating the computational basis states in the support.
+    pub(crate) fn diagonal_expectation<F>(&self, f: F) -> Result<f64>
+    where
+        F: Fn(&[bool]) -> f64,
+    {
+        let qargs: Vec<usize> = (0..self.num_qubits).collect();
+        let mut exp_value = 0.0;
+        self.for_each_outcome(&qargs, &mut |outcome, probability| {
+            exp_value += probability * f(outcome);
+            Ok(())
+        })?;
+        Ok(exp_value)
+    }
+
+    /// Enumerates every outcome of the specified qubits with non-zero probability and calls
+    /// `visit` with the outcome and its (normalized) probability.
+    /// The `i`-th element of the outcome corresponds to the qubit `qargs[i]`.
+    pub(crate) fn for_each_outcome<F>(&self, qargs: &[usize], visit: &mut F) -> Result<()>
+    where
+        F: FnMut(&[bool], f64) -> Result<()>,
+    {
+        self.validate_qargs(qargs)?;
+
+        let total_norm_sq = self.norm_squared()?;
+        if total_norm_sq.abs() < 1e-12 {
+            return Err(Error::NotImplemented(
+                "Probabilities of a zero-norm state are not defined.".to_string(),
+            ));
+        }
+
+        // Same ordering as in sampling: process qubits in descending order so that discarding
+        // a qubit does not shift the indices of the remaining ones.
+        let mut sorted_qargs: Vec<(usize, usize)> = qargs
+            .iter()
+            .enumerate()
+            .map(|(bit_index, &qarg)| (qarg, bit_index))
+            .collect();
+        sorted_qargs.sort_by_key(|&(qarg, _)| std::cmp::Reverse(qarg));
+
+        let mut outcome = vec![false; qargs.len()];
+        self.recursive_for_each_outcome(&sorted_qargs, 0, total_norm_sq, &mut outcome, visit)
+    }
+
+    /// Recursively projects onto every outcome branch, discarding qubits as they are fixed,
+    /// and visits each leaf with non-zero probability.
+    fn recursive_for_each_outcome<F>(
+        &self,
+        qubit_indices: &[(usize, usize)], // (physical_qarg, bit_position)
+        current_idx: usize,
+        total_norm_sq: f64,
+        outcome: &mut [bool],
+        visit: &mut F,
+    ) -> Result<()>
+    where
+        F: FnMut(&[bool], f64) -> Result<()>,
+    {
+        // Prune branches without any remaining stabilizer or with vanishing probability.
+        if self.stabilizers.is_empty() {
+            return Ok(());
+        }
+        let probability = (self.norm_squared()? / total_norm_sq).clamp(0.0, 1.0);
+        if probability < 1e-12 {
+            return Ok(());
+        }
+        if current_idx == qubit_indices.len() {
+            return visit(outcome, probability);
+        }
+
+        let (qarg, bit_pos) = qubit_indices[current_idx];
+
+        let mut state_zero = self.clone();
+        state_zero.project_unnormalized(qarg, false)?;
+        state_zero.discard(qarg)?;
+        outcome[bit_pos] = false;
+        state_zero.recursive_for_each_outcome(
+            qubit_indices,
+            current_idx + 1,
+            total_norm_sq,
+            outcome,
+            visit,
+        )?;
+
+        let mut state_one = self.clone();
+        state_one.project_unnormalized(qarg, true)?;
+        state_one.apply_x(qarg)?; // |1> -> |0>
+        state_one.discard(qarg)?;
+        outcome[bit_pos] = true;
+        state_one.recursive_for_each_outcome(
+            qubit_indices,
+            current_idx + 1,
+            total_norm_sq,
+            outcome,
+            visit,
+        )?;
+        outcome[bit_pos] = false;
+
+        Ok(())
+    }
+}
+
+/// A partial outcome in the search of [`StabilizerDecomposedState::most_probable`], ordered by
+/// its probability.
+struct Branch<T: Coefficient> {
+    probability: f64,
+    /// The number of qubits already fixed.
+    depth: usize,
+    outcome: Vec<bool>,
+    /// The unnormalized projected state, with the fixed qubits discarded.
+    state: StabilizerDecomposedState<T>,
+}
+
+impl<T: Coefficient> PartialEq for Branch<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Coefficient> Eq for Branch<T> {}
+
+impl<T: Coefficient> PartialOrd for Branch<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Coefficient> Ord for Branch<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.probability.total_cmp(&other.probability)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::test_utils::{create_all_zero_state, create_sample_stab_decomp_state};
+
+    #[test]
+    fn test_probability_vector_bell_state() {
+        let mut state = create_all_zero_state(2);
+        state.apply_h(0).unwrap();
+        state.apply_cx(0, 1).unwrap();
+
+        let probs = state.probability_vector(&[0, 1]).unwrap();
+        let expected = [0.5, 0.0, 0.0, 0.5];
+        assert_eq!(probs.len(), expected.len());
+        for (p, e) in probs.iter().zip(expected.iter()) {
+            assert!((p - e).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_probability_vector_sample_state() {
+        // 1/2 (|000> + |001> + |010> + |111>) (Little Endian)
+        let state = create_sample_stab_decomp_state();
+        let sv = state.to_statevector().unwrap();
+
+        let probs = state.probability_vector(&[0, 1, 2]).unwrap();
+        assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-10);
+        for (i, p) in probs.iter().enumerate() {
+            assert!((p - sv[i].norm_sqr()).abs() < 1e-10);
+        }
+
+        // Marginal over qubits (2, 0): index bit 0 is qubit 2, bit 1 is qubit 0.
+        // P(q0=0, q2=0) = 1/2, P(q0=1, q2=0) = 1/4, P(q0=1, q2=1) = 1/4
+        let probs = state.probability_vector(&[2, 0]).unwrap();
+        let expected = [0.5, 0.0, 0.25, 0.25];
+        for (p, e) in probs.iter().zip(expected.iter()) {
+            assert!((p - e).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_probability_vector_invalid_qargs() {
+        let state = create_sample_stab_decomp_state();
+        assert!(matches!(
+            state.probability_vector(&[3]),
+            Err(Error::QubitIndexOutOfBounds(3, 3))
+        ));
+        assert!(matches!(
+            state.probability_vector(&[0, 0]),
+            Err(Error::DuplicateQubitIndex(0))
+        ));
+        assert!(matches!(
+            state.probability_vector(&[]),
+            Err(Error::EmptyQubitIndices)
+        ));
+    }
+
+    #[test]
+    fn test_probabilities() {
+        use crate::state::QuantumState;
+        use crate::test_utils::random_circuit_with_t_gate;
+
+        // 1/2 (|000> + |001> + |010> + |111>) (Little Endian)
+        let state = create_sample_stab_decomp_state();
+        let distribution = state.probabilities(&[0, 1, 2]).unwrap();
+        let outcomes: Vec<Vec<bool>> = distribution.iter().map(|(o, _)| o.clone()).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                vec![false, false, false],
+                vec![true, false, false],
+                vec![false, true, false],
+                vec![true, true, true],
+            ]
+        );
+        for (_, p) in &distribution {
+            assert!((p - 0.25).abs() < 1e-10);
+        }
+
+        // Full and marginal distributions agree with |amplitude|^2
+        for seed in 0..3 {
+            let circuit = random_circuit_with_t_gate(4, 20, 4, Some(seed));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let statevector = state.to_statevector().unwrap();
+            let norm_sq: f64 = statevector.iter().map(|a| a.norm_sqr()).sum();
+
+            for qargs in [vec![0, 1, 2, 3], vec![2, 0], vec![3]] {
+                let distribution = state.probabilities(&qargs).unwrap();
+                let total: f64 = distribution.iter().map(|(_, p)| p).sum();
+                assert!((total - 1.0).abs() < 1e-10);
+
+                let mut expected = vec![0.0; 1 << qargs.len()];
+                for (x, amplitude) in statevector.iter().enumerate() {
+                    let index = qargs
+                        .iter()
+                        .enumerate()
+                        .fold(0, |acc, (i, &q)| acc | (((x >> q) & 1) << i));
+                    expected[index] += amplitude.norm_sqr() / norm_sq;
+                }
+                let mut found = vec![0.0; 1 << qargs.len()];
+                for (outcome, p) in &distribution {
+                    assert!(*p > 0.0);
+                    let index = outcome
+                        .iter()
+                        .enumerate()
+                        .fold(0, |acc, (i, &bit)| acc | ((bit as usize) << i));
+                    found[index] = *p;
+                }
+                for (f, e) in found.iter().zip(expected.iter()) {
+                    assert!((f - e).abs() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_most_probable() {
+        use crate::state::QuantumState;
+        use crate::test_utils::random_circuit_with_t_gate;
+
+        // 1/2 (|000> + |001> + |010> + |111>)
+        let state = create_sample_stab_decomp_state();
+        assert!(state.most_probable(&[0, 1, 2], 0).unwrap().is_empty());
+        let top = state.most_probable(&[0, 1, 2], 10).unwrap();
+        assert_eq!(top.len(), 4);
+        for (_, p) in &top {
+            assert!((p - 0.25).abs() < 1e-10);
+        }
+
+        // Agrees with sorting the full distribution
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(5, 30, 5, Some(seed));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            for qargs in [vec![0, 1, 2, 3, 4], vec![3, 1, 4], vec![2]] {
+                let distribution = state.probabilities(&qargs).unwrap();
+                let mut expected: Vec<f64> = distribution.iter().map(|(_, p)| *p).collect();
+                expected.sort_by(|a, b| b.total_cmp(a));
+
+                for k in [1, 3, 40] {
+                    let top = state.most_probable(&qargs, k).unwrap();
+                    assert_eq!(top.len(), k.min(expected.len()));
+                    for (i, (outcome, p)) in top.iter().enumerate() {
+                        assert!((p - expected[i]).abs() < 1e-10);
+                        let (_, exact) = distribution.iter().find(|(o, _)| o == outcome).unwrap();
+                        assert!((p - exact).abs() < 1e-10);
+                    }
+                }
+            }
+        }
+
+        assert!(matches!(
+            create_sample_stab_decomp_state().most_probable(&[0, 3], 1),
+            Err(Error::QubitIndexOutOfBounds(3, 3))
+        ));
+    }
+
+    #[test]
+    fn test_diagonal_expectation_hamming_weight() {
+        // 1/2 (|000> + |001> + |010> + |111>): Hamming weights 0, 1, 1, 3
+        let state = create_sample_stab_decomp_state();
+        let hamming_weight = |bits: &[bool]| bits.iter().filter(|&&b| b).count() as f64;
+        let exp_value = state.diagonal_expectation(hamming_weight).unwrap();
+        assert!((exp_value - 5.0 / 4.0).abs() < 1e-10);
+
+        // |+>^3: the expected Hamming weight is 3/2
+        let mut state = create_all_zero_state(3);
+        for q in 0..3 {
+            state.apply_h(q).unwrap();
+        }
+        let exp_value = state.diagonal_expectation(hamming_weight).unwrap();
+        assert!((exp_value - 1.5).abs() < 1e-10);
+    }
+}
@@ -55,6 +55,73 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
 
         Ok(())
     }
+
+    /// Projects each qubit of `outcomes` onto the given value, normalizing the state only once
+    /// at the end if `normalize` is set.
+    ///
+    /// The impossible projection is attributed to the first qubit after which no component is
+    /// left. If the components instead cancel out, the intermediate norms are not known and the
+    /// last qubit of `outcomes` is reported.
+    pub(crate) fn project_multi(
+        &mut self,
+        outcomes: &[(usize, bool)],
+        normalize: bool,
+    ) -> Result<()> {
+        if !outcomes.is_empty() {
+            let qargs: Vec<usize> = outcomes.iter().map(|&(qubit, _)| qubit).collect();
+            self.validate_qargs(&qargs)?;
+        }
+        let mut first_impossible = None;
+        for &(qubit, outcome) in outcomes {
+            self.project_unnormalized(qubit, outcome)?;
+            if self.stabilizers.is_empty() {
+                first_impossible.get_or_insert((qubit, outcome));
+            }
+        }
+        if !normalize {
+            return Ok(());
+        }
+        let norm = self.norm()?;
+        if norm.abs() < 1e-12 || norm.is_nan() {
+            let (qubit_index, desired) = first_impossible
+                .or_else(|| outcomes.last().copied())
+                .ok_or(Error::ZeroNormState)?;
+            return Err(Error::ImpossibleProjection {
+                qubit_index,
+                desired,
+            });
+        }
+        self.global_factor /= norm;
+        Ok(())
+    }
+
+    /// Computes the probability that post-selecting each qubit of `conditions` onto the given
+    /// value succeeds, i.e. ‖Π|ψ>‖² / ‖|ψ>‖² for the product Π of the projectors.
+    /// The state itself is not modified.
+    pub(crate) fn postselection_probability(&self, conditions: &[(usize, bool)]) -> Result<f64> {
+        // Post-selecting on nothing always succeeds.
+        if conditions.is_empty() {
+            return Ok(1.0);
+        }
+        let qargs: Vec<usize> = conditions.iter().map(|&(qubit, _)| qubit).collect();
+        self.validate_qargs(&qargs)?;
+
+        let total_norm_sq = self.norm_squared()?;
+        if total_norm_sq.abs() < 1e-12 {
+            return Err(Error::NotImplemented(
+                "Post-selection on a zero-norm state is not possible.".to_string(),
+            ));
+        }
+
+        let mut projected = self.clone();
+        for &(qubit, outcome) in conditions {
+            projected.project_unnormalized(qubit, outcome)?;
+            if projected.stabilizers.is_empty() {
+                return Ok(0.0);
+            }
+        }
+        Ok((projected.norm_squared()? / total_norm_sq).clamp(0.0, 1.0))
+    }
 }
 
 #[cfg(test)]
@@ -271,7 +338,7 @@ mod tests {
                 desired,
             }) => {
                 assert_eq!(qubit_index, 1);
-                assert_eq!(desired, true);
+                assert!(desired);
             }
             _ => panic!("Expected ImpossibleProjection error."),
         }
@@ -321,4 +388,62 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_postselection_probability() {
+        // Bell state
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+
+        let p = state
+            .postselection_probability(&[(0, false), (1, false)])
+            .unwrap();
+        assert!((p - 0.5).abs() < 1e-10);
+        let p = state
+            .postselection_probability(&[(0, false), (1, true)])
+            .unwrap();
+        assert!(p.abs() < 1e-10);
+        let p = state.postselection_probability(&[(1, true)]).unwrap();
+        assert!((p - 0.5).abs() < 1e-10);
+        let p = state.postselection_probability(&[(0, false)]).unwrap();
+        assert!((p - 0.5).abs() < 1e-10);
+        assert_eq!(state.postselection_probability(&[]).unwrap(), 1.0);
+
+        // The state is untouched
+        assert!((state.norm().unwrap() - 1.0).abs() < 1e-10);
+
+        assert!(matches!(
+            state.postselection_probability(&[(0, false), (0, true)]),
+            Err(Error::DuplicateQubitIndex(0))
+        ));
+        assert!(matches!(
+            state.postselection_probability(&[(2, false)]),
+            Err(Error::QubitIndexOutOfBounds(2, 2))
+        ));
+    }
+
+    #[test]
+    fn test_postselection_probability_random() {
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(4, 30, 4, Some(seed));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let statevector = state.to_statevector().unwrap();
+            let norm_sq: f64 = statevector.iter().map(|a| a.norm_sqr()).sum();
+
+            // Post-select q1 = 1 and q3 = 0
+            let expected: f64 = statevector
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (i >> 1) & 1 == 1 && (i >> 3) & 1 == 0)
+                .map(|(_, a)| a.norm_sqr())
+                .sum::<f64>()
+                / norm_sq;
+            let p = state
+                .postselection_probability(&[(1, true), (3, false)])
+                .unwrap();
+            assert!((p - expected).abs() < 1e-10);
+        }
+    }
 }
@@ -0,0 +1,170 @@
+use ndarray::Array2;
+use num_complex::Complex64;
+
+use crate::error::Result;
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+/// Squared residual norm below which a component is regarded as linearly dependent on the
+/// components selected so far. Every CH-form is normalized, so this is a relative threshold.
+const DEPENDENCE_TOL: f64 = 1e-10;
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Re-expresses the state exactly in a linearly independent subset of its stabilizer
+    /// components, which reduces χ to at most the dimension of their span.
+    ///
+    /// With the Gram matrix `G_ij = <S_i|S_j>`, the subset `B` is chosen greedily by an
+    /// incremental Cholesky factorization `G_BB = L L†`, and the new coefficients solve
+    /// `G_BB d = G_B c`, i.e. they are those of the orthogonal projection onto span(B), which
+    /// contains the state. The new coefficients are in general not representable as
+    /// [`Scalar`](crate::state::types::scalar::Scalar)s, hence the `Complex64` result.
+    pub(crate) fn recompress(&self) -> Result<StabilizerDecomposedState<Complex64>> {
+        let chi = self.stabilizers.len();
+        let mut gram = Array2::<Complex64>::zeros((chi, chi));
+        for i in 0..chi {
+            for j in i..chi {
+                let ip = self.stabilizers[i].inner_product(&self.stabilizers[j])?;
+                gram[[i, j]] = ip;
+                gram[[j, i]] = ip.conj();
+            }
+        }
+
+        let mut cholesky = IncrementalCholesky::default();
+        let mut basis: Vec<usize> = Vec::new();
+        for k in 0..chi {
+            let column: Vec<Complex64> = basis.iter().map(|&b| gram[[b, k]]).collect();
+            if cholesky.try_push(&column, gram[[k, k]].re) {
+                basis.push(k);
+            }
+        }
+
+        let coefficients: Vec<Complex64> = self.coefficients.iter().map(|&c| c.into()).collect();
+        // y_b = <S_b|ψ> without the global factor
+        let projections: Vec<Complex64> = basis
+            .iter()
+            .map(|&b| {
+                (0..chi)
+                    .map(|j| gram[[b, j]] * coefficients[j])
+                    .sum::<Complex64>()
+            })
+            .collect();
+        let new_coefficients = cholesky.solve(&projections);
+
+        let mut state = StabilizerDecomposedState::new(
+            self.num_qubits,
+            basis.iter().map(|&b| self.stabilizers[b].clone()).collect(),
+            new_coefficients,
+        );
+        state.global_factor = self.global_factor;
+        Ok(state)
+    }
+}
+
+/// The Cholesky factor `L` of a Hermitian positive definite matrix `G = L L†` that grows by one
+/// row and column at a time. Row `m` of `L` holds its `m + 1` lower-triangular entries, and the
+/// diagonal entries are real and positive.
+#[derive(Default)]
+struct IncrementalCholesky {
+    rows: Vec<Vec<Complex64>>,
+}
+
+impl IncrementalCholesky {
+    /// Tries to extend `G` by the column `column` (the entries `G_{m,k}` for the current rows
+    /// `m`) and the diagonal entry `diagonal` (`G_{k,k}`).
+    ///
+    /// Returns `false` without modifying the factor if the new vector is linearly dependent on
+    /// the current ones within [`DEPENDENCE_TOL`].
+    fn try_push(&mut self, column: &[Complex64], diagonal: f64) -> bool {
+        // Solve L x = column; the new row is x†.
+        let x = self.forward_substitution(column);
+        let residual = diagonal - x.iter().map(|v| v.norm_sqr()).sum::<f64>();
+        if residual <= DEPENDENCE_TOL {
+            return false;
+        }
+        let mut row: Vec<Complex64> = x.iter().map(|v| v.conj()).collect();
+        row.push(Complex64::new(residual.sqrt(), 0.0));
+        self.rows.push(row);
+        true
+    }
+
+    /// Solves `L x = b`.
+    fn forward_substitution(&self, b: &[Complex64]) -> Vec<Complex64> {
+        let mut x: Vec<Complex64> = Vec::with_capacity(b.len());
+        for (m, row) in self.rows.iter().enumerate() {
+            let partial: Complex64 = (0..m).map(|p| row[p] * x[p]).sum();
+            x.push((b[m] - partial) / row[m]);
+        }
+        x
+    }
+
+    /// Solves `L L† x = b`.
+    fn solve(&self, b: &[Complex64]) -> Vec<Complex64> {
+        let z = self.forward_substitution(b);
+        let n = self.rows.len();
+        let mut x = vec![Complex64::new(0.0, 0.0); n];
+        for m in (0..n).rev() {
+            // (L†)_{m,p} = conj(L_{p,m})
+            let partial: Complex64 = ((m + 1)..n).map(|p| self.rows[p][m].conj() * x[p]).sum();
+            x[m] = (z[m] - partial) / self.rows[m][m];
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circuit::QuantumCircuit;
+    use crate::state::QuantumState;
+    use crate::test_utils::{assert_eq_complex_array1, random_circuit_with_t_gate};
+
+    fn assert_statevectors_close(a: &QuantumState, b: &QuantumState) {
+        let a = a.to_statevector().unwrap();
+        let b = b.to_statevector().unwrap();
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_recompress() {
+        // Eight T gates on two qubits: χ grows with the T count, but the state lives in a
+        // four-dimensional space.
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_h(1);
+        for _ in 0..4 {
+            circuit.apply_t(0);
+            circuit.apply_cx(0, 1);
+            circuit.apply_t(1);
+            circuit.apply_h(0);
+        }
+        let original = QuantumState::from_circuit(&circuit).unwrap();
+        let mut state = QuantumState::from_circuit(&circuit).unwrap();
+        state.recompress().unwrap();
+
+        let (before, after) = (original.stabilizer_rank(), state.stabilizer_rank());
+        assert!(after <= 4);
+        assert!(after < before);
+        assert_statevectors_close(&state, &original);
+    }
+
+    #[test]
+    fn test_recompress_random() {
+        for seed in 0..5 {
+            let circuit = random_circuit_with_t_gate(3, 40, 6, Some(seed));
+            let original = QuantumState::from_circuit(&circuit).unwrap();
+            let mut state = QuantumState::from_circuit(&circuit).unwrap();
+            state.recompress().unwrap();
+            assert!(state.stabilizer_rank() <= 8);
+            assert_statevectors_close(&state, &original);
+
+            // Recompressing again keeps the basis.
+            let rank = state.stabilizer_rank();
+            state.recompress().unwrap();
+            assert_eq!(state.stabilizer_rank(), rank);
+            assert_eq_complex_array1(
+                &state.to_statevector().unwrap(),
+                &original.to_statevector().unwrap(),
+            );
+        }
+    }
+}
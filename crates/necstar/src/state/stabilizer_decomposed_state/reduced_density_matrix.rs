@@ -0,0 +1,320 @@
+use ndarray::Array2;
+use num_complex::Complex64;
+use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString, PauliTerm};
+
+use crate::error::{Error, Result};
+use crate::state::{Coefficient, StabilizerDecomposedState};
+
+/// Maximum number of qubits for which a reduced density matrix is computed. The computation
+/// takes `4^k` Pauli expectation values.
+const MAX_QUBITS_FOR_DENSITY_MATRIX: usize = 8;
+
+/// Eigenvalues below this threshold do not contribute to the entropy.
+const EIGENVALUE_TOL: f64 = 1e-12;
+
+impl<T: Coefficient> StabilizerDecomposedState<T> {
+    /// Computes the reduced density matrix `ρ_A = Tr_B |ψ><ψ| / <ψ|ψ>` over the qubits
+    /// `qargs`, where the `i`-th bit (little-endian) of the row and column indices corresponds
+    /// to the qubit `qargs[i]`.
+    ///
+    /// The matrix is assembled from the Pauli expansion `ρ_A = 2^-k Σ_P <P> P` over all `4^k`
+    /// Pauli strings `P` on the `k` qubits.
+    pub(crate) fn reduced_density_matrix(&self, qargs: &[usize]) -> Result<Array2<Complex64>> {
+        self.validate_qargs(qargs)?;
+        let k = qargs.len();
+        if k > MAX_QUBITS_FOR_DENSITY_MATRIX {
+            return Err(Error::DensityMatrixTooLarge(k));
+        }
+
+        let total_norm_sq = self.norm_squared()?;
+        if total_norm_sq.abs() < 1e-12 {
+            return Err(Error::NotImplemented(
+                "Density matrices of a zero-norm state are not defined.".to_string(),
+            ));
+        }
+
+        // The `p`-th Pauli string has the operator PAULIS[(p >> 2i) & 3] on qubit `qargs[i]`.
+        const PAULIS: [Pauli; 4] = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z];
+        let ops_of = |p: usize| (0..k).map(move |i| PAULIS[(p >> (2 * i)) & 3]);
+        let paulis: Vec<PauliString> = (0..1usize << (2 * k))
+            .map(|p| {
+                PauliString::Sparse(
+                    ops_of(p)
+                        .zip(qargs)
+                        .filter(|&(op, _)| op != Pauli::I)
+                        .map(|(op, &qubit)| PauliTerm { op, qubit })
+                        .collect(),
+                )
+            })
+            .collect();
+        let exp_values = self.exp_value_batch(&paulis)?;
+
+        let dim = 1usize << k;
+        let scale = 1.0 / (dim as f64 * total_norm_sq);
+        let mut rho = Array2::<Complex64>::zeros((dim, dim));
+        for (p, &exp_value) in exp_values.iter().enumerate() {
+            if exp_value.abs() < 1e-14 {
+                continue;
+            }
+            // A Pauli string maps |r> to a phase times |r ^ x_mask>.
+            let x_mask = ops_of(p)
+                .enumerate()
+                .filter(|&(_, op)| op == Pauli::X || op == Pauli::Y)
+                .fold(0usize, |acc, (i, _)| acc | (1 << i));
+            for row in 0..dim {
+                let mut entry = Complex64::new(exp_value * scale, 0.0);
+                for (i, op) in ops_of(p).enumerate() {
+                    let bit = (row >> i) & 1 == 1;
+                    entry *= match (op, bit) {
+                        (Pauli::Z, true) => Complex64::new(-1.0, 0.0),
+                        // <0|Y|1> = -i, <1|Y|0> = i
+                        (Pauli::Y, false) => Complex64::new(0.0, -1.0),
+                        (Pauli::Y, true) => Complex64::new(0.0, 1.0),
+                        _ => Complex64::new(1.0, 0.0),
+                    };
+                }
+                rho[[row, row ^ x_mask]] += entry;
+            }
+        }
+        Ok(rho)
+    }
+
+    /// Computes the von Neumann entropy `-Tr ρ_A ln ρ_A` of the reduced state on the qubits
+    /// `qargs`. Since the state is pure, the smaller of the subsystem and its complement is
+    /// used.
+    pub(crate) fn entanglement_entropy(&self, qargs: &[usize]) -> Result<f64> {
+        self.validate_qargs(qargs)?;
+        let complement: Vec<usize> = (0..self.num_qubits)
+            .filter(|q| !qargs.contains(q))
+            .collect();
+        if complement.is_empty() {
+            return Ok(0.0);
+        }
+        let subsystem = if complement.len() < qargs.len() {
+            &complement[..]
+        } else {
+            qargs
+        };
+
+        let rho = self.reduced_density_matrix(subsystem)?;
+        Ok(hermitian_eigenvalues(&rho)
+            .into_iter()
+            .filter(|&lambda| lambda > EIGENVALUE_TOL)
+            .map(|lambda| -lambda * lambda.ln())
+            .sum())
+    }
+}
+
+/// Computes the eigenvalues of the Hermitian matrix `H = A + iB`.
+///
+/// The real symmetric matrix `[[A, -B], [B, A]]` has the eigenvalues of `H`, each twice, which
+/// are found by the cyclic Jacobi method.
+fn hermitian_eigenvalues(h: &Array2<Complex64>) -> Vec<f64> {
+    let n = h.nrows();
+    let mut m = Array2::<f64>::zeros((2 * n, 2 * n));
+    for i in 0..n {
+        for j in 0..n {
+            let (re, im) = (h[[i, j]].re, h[[i, j]].im);
+            m[[i, j]] = re;
+            m[[i + n, j + n]] = re;
+            m[[i, j + n]] = -im;
+            m[[i + n, j]] = im;
+        }
+    }
+
+    let size = 2 * n;
+    const MAX_SWEEPS: usize = 100;
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..size)
+            .flat_map(|p| ((p + 1)..size).map(move |q| (p, q)))
+            .map(|(p, q)| m[[p, q]] * m[[p, q]])
+            .sum();
+        if off_diagonal < 1e-24 {
+            break;
+        }
+        for p in 0..size {
+            for q in (p + 1)..size {
+                if m[[p, q]].abs() < 1e-300 {
+                    continue;
+                }
+                // Rotate in the (p, q) plane to annihilate m[p][q].
+                let theta = (m[[q, q]] - m[[p, p]]) / (2.0 * m[[p, q]]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for r in 0..size {
+                    let (mrp, mrq) = (m[[r, p]], m[[r, q]]);
+                    m[[r, p]] = c * mrp - s * mrq;
+                    m[[r, q]] = s * mrp + c * mrq;
+                }
+                for r in 0..size {
+                    let (mpr, mqr) = (m[[p, r]], m[[q, r]]);
+                    m[[p, r]] = c * mpr - s * mqr;
+                    m[[q, r]] = s * mpr + c * mqr;
+                }
+            }
+        }
+    }
+
+    // Every eigenvalue appears twice on the diagonal.
+    let mut diagonal: Vec<f64> = (0..size).map(|i| m[[i, i]]).collect();
+    diagonal.sort_by(|a, b| a.total_cmp(b));
+    diagonal.into_iter().step_by(2).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::QuantumCircuit;
+    use crate::state::QuantumState;
+    use crate::test_utils::random_circuit_with_t_gate;
+
+    #[test]
+    fn test_hermitian_eigenvalues() {
+        let i = Complex64::new(0.0, 1.0);
+        let one = Complex64::new(1.0, 0.0);
+        // Pauli Y has the eigenvalues -1 and 1.
+        let y = Array2::from_shape_vec((2, 2), vec![0.0 * one, -i, i, 0.0 * one]).unwrap();
+        let eigenvalues = hermitian_eigenvalues(&y);
+        assert!((eigenvalues[0] + 1.0).abs() < 1e-12);
+        assert!((eigenvalues[1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reduced_density_matrix() {
+        let half = Complex64::new(0.5, 0.0);
+        let zero = Complex64::new(0.0, 0.0);
+
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let expected = Array2::from_shape_vec((2, 2), vec![half, zero, zero, half]).unwrap();
+        for qubit in 0..2 {
+            let rho = state.reduced_density_matrix(&[qubit]).unwrap();
+            assert_eq_complex_array2(&rho, &expected);
+        }
+
+        // |+i> on qubit 1: ρ = (I + Y) / 2
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(1);
+        circuit.apply_s(1);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let rho = state.reduced_density_matrix(&[1]).unwrap();
+        let i = Complex64::new(0.0, 0.5);
+        let expected = Array2::from_shape_vec((2, 2), vec![half, -i, i, half]).unwrap();
+        assert_eq_complex_array2(&rho, &expected);
+
+        assert!(matches!(
+            state.reduced_density_matrix(&[0, 1, 2]),
+            Err(Error::QubitIndexOutOfBounds(2, 2))
+        ));
+        let state = QuantumState::from_circuit(&QuantumCircuit::new(10)).unwrap();
+        let qubits: Vec<usize> = (0..9).collect();
+        assert!(matches!(
+            state.reduced_density_matrix(&qubits),
+            Err(Error::DensityMatrixTooLarge(9))
+        ));
+    }
+
+    #[test]
+    fn test_reduced_density_matrix_random() {
+        for seed in 0..3 {
+            let circuit = random_circuit_with_t_gate(4, 30, 4, Some(seed));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let statevector = state.to_statevector().unwrap();
+
+            // ρ over the qubits [2, 0], traced over the qubits 1 and 3
+            let qargs = [2, 0];
+            let index = |a: usize, z: usize| {
+                ((a & 1) << 2) | ((a >> 1) & 1) | ((z & 1) << 1) | (((z >> 1) & 1) << 3)
+            };
+            let mut expected = Array2::<Complex64>::zeros((4, 4));
+            for a in 0..4 {
+                for b in 0..4 {
+                    for z in 0..4 {
+                        expected[[a, b]] +=
+                            statevector[index(a, z)] * statevector[index(b, z)].conj();
+                    }
+                }
+            }
+            let rho = state.reduced_density_matrix(&qargs).unwrap();
+            assert_eq_complex_array2(&rho, &expected);
+        }
+    }
+
+    fn assert_eq_complex_array2(a: &Array2<Complex64>, b: &Array2<Complex64>) {
+        assert_eq!(a.dim(), b.dim());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x - y).norm() < 1e-10, "{} != {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_entanglement_entropy() {
+        let mut circuit = QuantumCircuit::new(2);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        let ln2 = std::f64::consts::LN_2;
+        assert!((state.entanglement_entropy(&[0]).unwrap() - ln2).abs() < 1e-10);
+        assert!((state.entanglement_entropy(&[1]).unwrap() - ln2).abs() < 1e-10);
+        assert!(state.entanglement_entropy(&[0, 1]).unwrap().abs() < 1e-10);
+
+        // A product state with a magic state on qubit 2
+        let mut circuit = QuantumCircuit::new(3);
+        circuit.apply_h(0);
+        circuit.apply_cx(0, 1);
+        circuit.apply_h(2);
+        circuit.apply_t(2);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert!(state.entanglement_entropy(&[2]).unwrap().abs() < 1e-10);
+        assert!((state.entanglement_entropy(&[1, 2]).unwrap() - ln2).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_entanglement_entropy_random() {
+        // Compare with the entropy from the Schmidt coefficients of the statevector.
+        for seed in 0..3 {
+            let circuit = random_circuit_with_t_gate(4, 30, 4, Some(seed));
+            let state = QuantumState::from_circuit(&circuit).unwrap();
+            let statevector = state.to_statevector().unwrap();
+
+            // ρ_A for A = {0, 1} from the statevector, with x the index of A and z of B.
+            let mut rho = Array2::<Complex64>::zeros((4, 4));
+            for x in 0..4 {
+                for y in 0..4 {
+                    for z in 0..4 {
+                        rho[[x, y]] += statevector[x | (z << 2)] * statevector[y | (z << 2)].conj();
+                    }
+                }
+            }
+            let expected: f64 = hermitian_eigenvalues(&rho)
+                .into_iter()
+                .filter(|&lambda| lambda > EIGENVALUE_TOL)
+                .map(|lambda| -lambda * lambda.ln())
+                .sum();
+
+            let entropy = state.entanglement_entropy(&[0, 1]).unwrap();
+            assert!((entropy - expected).abs() < 1e-8);
+            let entropy = state.entanglement_entropy(&[3, 2]).unwrap();
+            assert!((entropy - expected).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_entanglement_entropy_errors() {
+        let state = QuantumState::from_circuit(&QuantumCircuit::new(20)).unwrap();
+        let qubits: Vec<usize> = (0..10).collect();
+        assert!(matches!(
+            state.entanglement_entropy(&qubits),
+            Err(Error::DensityMatrixTooLarge(10))
+        ));
+        // The complement is used for large subsystems.
+        let qubits: Vec<usize> = (0..15).collect();
+        assert!(state.entanglement_entropy(&qubits).unwrap().abs() < 1e-10);
+
+        assert!(state.entanglement_entropy(&[20]).is_err());
+        assert!(state.entanglement_entropy(&[0, 0]).is_err());
+    }
+}
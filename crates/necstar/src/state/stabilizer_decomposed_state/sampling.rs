@@ -3,8 +3,8 @@ use crate::{
     state::{Coefficient, StabilizerDecomposedState},
     types::shot_count::{OutcomeInteger, SamplingBuffer, ShotCount},
 };
-use rand::SeedableRng;
 use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
 use rand_distr::{Binomial, Distribution};
 
 impl<T: Coefficient> StabilizerDecomposedState<T> {
@@ -16,13 +16,22 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         shots: usize,
         seed: Option<[u8; 32]>,
     ) -> Result<ShotCount> {
-        self.validate_qargs(qargs)?;
-        let num_qubits = qargs.len();
-
         let mut rng = match seed {
             Some(s) => StdRng::from_seed(s),
             None => StdRng::from_entropy(),
         };
+        self.sample_with_rng(qargs, shots, &mut rng)
+    }
+
+    /// Same as [`sample`](Self::sample), but draws the random numbers from `rng`.
+    pub(crate) fn sample_with_rng<R: RngCore + ?Sized>(
+        &self,
+        qargs: &[usize],
+        shots: usize,
+        rng: &mut R,
+    ) -> Result<ShotCount> {
+        self.validate_qargs(qargs)?;
+        let num_qubits = qargs.len();
 
         // Pair each qarg with its target bit index in the result.
         // Then, sort by physical qubit index (qarg) in descending order.
@@ -34,57 +43,45 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
             .map(|(bit_index, &qarg)| (qarg, bit_index))
             .collect();
 
-        sorted_qargs.sort_by(|a, b| b.0.cmp(&a.0));
+        sorted_qargs.sort_by_key(|&(qarg, _)| std::cmp::Reverse(qarg));
 
         let buffer = if num_qubits <= 32 {
             let mut outcomes = Vec::new();
-            self.recursive_sample(
-                &sorted_qargs,
-                0,
-                shots,
-                u32::zero(),
-                &mut outcomes,
-                &mut rng,
-            )?;
+            self.recursive_sample(&sorted_qargs, 0, shots, u32::zero(), &mut outcomes, rng)?;
             SamplingBuffer::U32(outcomes)
         } else if num_qubits <= 64 {
             let mut outcomes = Vec::new();
-            self.recursive_sample(
-                &sorted_qargs,
-                0,
-                shots,
-                u64::zero(),
-                &mut outcomes,
-                &mut rng,
-            )?;
+            self.recursive_sample(&sorted_qargs, 0, shots, u64::zero(), &mut outcomes, rng)?;
             SamplingBuffer::U64(outcomes)
         } else if num_qubits <= 128 {
+            let mut outcomes = Vec::new();
+            self.recursive_sample(&sorted_qargs, 0, shots, u128::zero(), &mut outcomes, rng)?;
+            SamplingBuffer::U128(outcomes)
+        } else {
             let mut outcomes = Vec::new();
             self.recursive_sample(
                 &sorted_qargs,
                 0,
                 shots,
-                u128::zero(),
+                Vec::<bool>::zero(),
                 &mut outcomes,
-                &mut rng,
+                rng,
             )?;
-            SamplingBuffer::U128(outcomes)
-        } else {
-            return Err(Error::SamplingTooManyQubits);
+            SamplingBuffer::Wide(outcomes)
         };
 
         Ok(buffer.finalize(num_qubits))
     }
 
     /// Recursively sample from the state, discarding qubits as they are measured.
-    fn recursive_sample<I: OutcomeInteger>(
+    fn recursive_sample<I: OutcomeInteger, R: RngCore + ?Sized>(
         &self,
         qubit_indices: &[(usize, usize)], // (physical_qarg, bit_position)
         current_idx: usize,
         current_shots: usize,
         current_outcome: I,
         outcome_counts: &mut Vec<(I, usize)>,
-        rng: &mut StdRng,
+        rng: &mut R,
     ) -> Result<()> {
         // Stop recursion if there are no shots to process.
         if current_shots == 0 {
@@ -164,7 +161,7 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
                 qubit_indices,
                 current_idx + 1,
                 num_zeros,
-                current_outcome,
+                current_outcome.clone(),
                 outcome_counts,
                 rng,
             )?;
@@ -296,6 +293,54 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_sampling_sorted() {
+        // sample_state = |000> + |001> + |010> + |111> (Little Endian)
+        let sample_state = crate::test_utils::create_sample_stab_decomp_state();
+        for (i, qargs) in [[0, 1, 2], [2, 0, 1], [1, 2, 0]].iter().enumerate() {
+            let result = sample_state
+                .sample(qargs, 1000, Some([i as u8; 32]))
+                .unwrap();
+            let as_integers: Vec<usize> = result
+                .iter()
+                .map(|(outcome, _)| {
+                    outcome
+                        .iter()
+                        .enumerate()
+                        .map(|(bit, &b)| (b as usize) << bit)
+                        .sum()
+                })
+                .collect();
+            assert_eq!(as_integers.len(), 4);
+            assert!(as_integers.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    #[test]
+    fn test_sampling_wide() {
+        use crate::circuit::QuantumCircuit;
+        use crate::state::QuantumState;
+
+        // GHZ-like state (|0...0> + e^{iπ/4}|1...1>)/√2 on 130 qubits
+        let num_qubits = 130;
+        let mut circuit = QuantumCircuit::new(num_qubits);
+        circuit.apply_h(0);
+        for q in 1..num_qubits {
+            circuit.apply_cx(0, q);
+        }
+        circuit.apply_t(0);
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+
+        let qargs: Vec<usize> = (0..num_qubits).collect();
+        let shots = 1000;
+        let result = state.sample(&qargs, shots, Some([5; 32])).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].0, vec![false; num_qubits]);
+        assert_eq!(result[1].0, vec![true; num_qubits]);
+        assert_eq!(result[0].1 + result[1].1, shots);
+        assert!(result[0].1 > 400 && result[1].1 > 400);
+    }
+
     // This test is ignored by default due to its potentially long runtime.
     #[test]
     #[ignore]
@@ -359,4 +404,32 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_sample_with_rng() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let circuit = crate::test_utils::random_circuit_with_t_gate(4, 40, 4, Some(11));
+        let state = crate::state::QuantumState::from_circuit(&circuit).unwrap();
+        let qargs = [3, 0, 1];
+
+        let mut rng1 = StdRng::seed_from_u64(2024);
+        let mut rng2 = StdRng::seed_from_u64(2024);
+        for _ in 0..3 {
+            let samples1 = state.sample_with_rng(&qargs, 500, &mut rng1).unwrap();
+            let samples2 = state.sample_with_rng(&qargs, 500, &mut rng2).unwrap();
+            assert_eq!(samples1, samples2);
+            assert_eq!(samples1.iter().map(|(_, count)| count).sum::<usize>(), 500);
+        }
+
+        // The seeded variant is equivalent to a freshly seeded `StdRng`.
+        let seed = [5u8; 32];
+        assert_eq!(
+            state.sample(&qargs, 500, Some(seed)).unwrap(),
+            state
+                .sample_with_rng(&qargs, 500, &mut StdRng::from_seed(seed))
+                .unwrap()
+        );
+    }
 }
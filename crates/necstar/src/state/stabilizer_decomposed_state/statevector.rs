@@ -1,17 +1,33 @@
+use std::collections::HashMap;
+
 use ndarray::Array1;
 use num_complex::Complex64;
 
 use crate::error::{Error, Result};
 use crate::state::{Coefficient, StabilizerDecomposedState};
 
+/// The default limit on the number of qubits for [`StabilizerDecomposedState::to_statevector`],
+/// corresponding to 4 GiB of `Complex64` amplitudes.
+pub(crate) const MAX_QUBITS_FOR_STATEVECTOR: usize = 28;
+
+/// Amplitudes of [`StabilizerDecomposedState::to_sparse_statevector`] that are smaller than this
+/// fraction of the sum of the magnitudes of their contributions are treated as cancelled.
+const SPARSE_CANCELLATION_TOL: f64 = 1e-10;
+
 impl<T: Coefficient> StabilizerDecomposedState<T> {
     /// Converts the stabilizer decomposed state to a statevector representation.
     /// Note that the state is represented as a dense vector, which may be inefficient for large
     /// number of qubits.
     /// The indexing of the statevector is in little-endian order like in Qiskit.
     pub(crate) fn to_statevector(&self) -> Result<Array1<Complex64>> {
-        const MAX_QUBITS_FOR_STATEVECTOR: usize = 28;
-        if self.num_qubits > MAX_QUBITS_FOR_STATEVECTOR {
+        self.to_statevector_with_limit(MAX_QUBITS_FOR_STATEVECTOR)
+    }
+
+    /// Same as [`to_statevector`](Self::to_statevector), but fails with
+    /// [`Error::StatevectorTooLarge`] only for more than `max_qubits` qubits.
+    pub(crate) fn to_statevector_with_limit(&self, max_qubits: usize) -> Result<Array1<Complex64>> {
+        // The dimension 2^n must fit into a `usize` regardless of the requested limit.
+        if self.num_qubits > max_qubits.min(usize::BITS as usize - 1) {
             return Err(Error::StatevectorTooLarge(self.num_qubits));
         }
         let mut statevector = Array1::<Complex64>::zeros(1 << self.num_qubits);
@@ -22,6 +38,40 @@ impl<T: Coefficient> StabilizerDecomposedState<T> {
         }
         Ok(statevector * self.global_factor)
     }
+
+    /// Computes the nonzero amplitudes of the state as `(index, amplitude)` pairs, sorted by the
+    /// little-endian index.
+    ///
+    /// Only the supports of the stabilizer components are enumerated, so the cost scales with
+    /// the sum of the support sizes instead of `2^n`. Amplitudes whose contributions cancel up
+    /// to [`SPARSE_CANCELLATION_TOL`] are omitted.
+    pub(crate) fn to_sparse_statevector(&self) -> Result<Vec<(u128, Complex64)>> {
+        if self.num_qubits > u128::BITS as usize {
+            return Err(Error::StatevectorTooLarge(self.num_qubits));
+        }
+        // The accumulated amplitude and the sum of the magnitudes of its contributions
+        let mut amplitudes: HashMap<u128, (Complex64, f64)> = HashMap::new();
+        for (stab, coeff) in self.stabilizers.iter().zip(self.coefficients.iter()) {
+            let coeff_complex: Complex64 = (*coeff).into();
+            for bits in stab.support() {
+                let index = bits
+                    .iter()
+                    .enumerate()
+                    .fold(0u128, |acc, (q, &bit)| acc | ((bit as u128) << q));
+                let contribution = coeff_complex * stab.amplitude(&bits)?;
+                let entry = amplitudes.entry(index).or_default();
+                entry.0 += contribution;
+                entry.1 += contribution.norm();
+            }
+        }
+        let mut sparse: Vec<(u128, Complex64)> = amplitudes
+            .into_iter()
+            .filter(|(_, (amplitude, scale))| amplitude.norm() > SPARSE_CANCELLATION_TOL * scale)
+            .map(|(index, (amplitude, _))| (index, amplitude * self.global_factor))
+            .collect();
+        sparse.sort_unstable_by_key(|&(index, _)| index);
+        Ok(sparse)
+    }
 }
 
 #[cfg(test)]
@@ -47,4 +97,74 @@ mod tests {
         ];
         assert_eq_complex_array1(&statevector, &expected_statevector);
     }
+
+    #[test]
+    fn test_to_sparse_statevector() {
+        use crate::error::Error;
+        use crate::test_utils::{create_all_zero_state, random_circuit_with_t_gate};
+
+        let sample_state = create_sample_stab_decomp_state();
+        let sparse = sample_state.to_sparse_statevector().unwrap();
+        let indices: Vec<u128> = sparse.iter().map(|&(index, _)| index).collect();
+        assert_eq!(indices, vec![0b000, 0b001, 0b010, 0b111]);
+        for (_, amplitude) in sparse {
+            assert!((amplitude - Complex64::new(0.5, 0.0)).norm() < 1e-10);
+        }
+
+        for i in 0..5 {
+            let circuit = random_circuit_with_t_gate(5, 60, 6, Some(300 + i));
+            let state = crate::state::QuantumState::from_circuit(&circuit).unwrap();
+            let dense = state.to_statevector().unwrap();
+            let sparse = state.to_sparse_statevector().unwrap();
+            let mut expected = Vec::new();
+            for (index, amplitude) in dense.iter().enumerate() {
+                if amplitude.norm() > 1e-8 {
+                    expected.push(index as u128);
+                }
+            }
+            let indices: Vec<u128> = sparse.iter().map(|&(index, _)| index).collect();
+            assert_eq!(indices, expected);
+            for (index, amplitude) in sparse {
+                assert!((amplitude - dense[index as usize]).norm() < 1e-10);
+            }
+        }
+
+        assert!(matches!(
+            create_all_zero_state(129).to_sparse_statevector(),
+            Err(Error::StatevectorTooLarge(129))
+        ));
+    }
+
+    #[test]
+    fn test_to_statevector_limit() {
+        use crate::error::Error;
+        use crate::test_utils::create_all_zero_state;
+
+        let state = create_all_zero_state(30);
+        assert!(matches!(
+            state.to_statevector(),
+            Err(Error::StatevectorTooLarge(30))
+        ));
+
+        let sample_state = create_sample_stab_decomp_state();
+        assert!(matches!(
+            sample_state.to_statevector_with_limit(2),
+            Err(Error::StatevectorTooLarge(3))
+        ));
+        assert_eq_complex_array1(
+            &sample_state.to_statevector_with_limit(3).unwrap(),
+            &sample_state.to_statevector().unwrap(),
+        );
+        assert_eq_complex_array1(
+            &sample_state.to_statevector_with_limit(usize::MAX).unwrap(),
+            &sample_state.to_statevector().unwrap(),
+        );
+
+        // Even without a limit, the dimension must fit into a `usize`.
+        let state = create_all_zero_state(usize::BITS as usize);
+        assert!(matches!(
+            state.to_statevector_with_limit(usize::MAX),
+            Err(Error::StatevectorTooLarge(_))
+        ));
+    }
 }
@@ -2,6 +2,18 @@ use num_complex::Complex64;
 use num_traits::One;
 use std::fmt::Debug;
 use std::ops::Mul;
+
+/// The numeric type used for the coefficients of the stabilizer decomposition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoefficientType {
+    /// Exact coefficients of the form `e^{ikπ/4} 2^{-r/2}`, which cover every decomposition the
+    /// compiler produces and keep merged coefficients free of rounding errors.
+    #[default]
+    Scalar,
+    /// Floating-point complex coefficients, which can represent arbitrary amplitudes.
+    Complex64,
+}
+
 /// Trait representing the complex conjugate operation.
 pub(crate) trait Conj {
     fn conj(&self) -> Self;
@@ -35,8 +47,11 @@ impl Amplify for Complex64 {
 
 /// Trait representing a coefficient in the stabilizer decomposed state.
 pub(crate) trait Coefficient:
-    InnerProduct + Into<Complex64> + One + Amplify + Debug
+    InnerProduct + Into<Complex64> + One + Amplify + Debug + Send + Sync
 {
 }
 
-impl<T> Coefficient for T where T: InnerProduct + Into<Complex64> + One + Amplify + Debug {}
+impl<T> Coefficient for T where
+    T: InnerProduct + Into<Complex64> + One + Amplify + Debug + Send + Sync
+{
+}
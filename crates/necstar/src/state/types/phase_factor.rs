@@ -5,6 +5,7 @@ use std::ops::{Mul, MulAssign};
 ///
 /// Internally, this stores the value of `k`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct PhaseFactor(u8);
 
 impl PhaseFactor {
@@ -23,6 +24,24 @@ impl PhaseFactor {
         Complex64::new(angle.cos(), angle.sin())
     }
 
+    /// Creates the phase factor `e^(i * k * pi / 4)`, taking `k` modulo 8.
+    pub(crate) fn from_exponent(k: u8) -> Self {
+        Self(k % 8)
+    }
+
+    /// Returns the exponent `k` of the phase factor `e^(i * k * pi / 4)`.
+    pub(crate) fn exponent(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the phase factor `e^(i * k * pi / 4)` closest to the given complex number, or
+    /// `None` if the number differs from every such phase by more than `tol`.
+    pub(crate) fn from_complex(value: Complex64, tol: f64) -> Option<Self> {
+        let k = (value.arg() / std::f64::consts::FRAC_PI_4).round() as i64;
+        let phase = Self::from_exponent(k.rem_euclid(8) as u8);
+        ((value - phase.to_complex()).norm() < tol).then_some(phase)
+    }
+
     /// Returns the inverse of the phase factor (complex conjugate).
     pub(crate) fn conjugated(&self) -> Self {
         Self((8 - self.0) % 8)
@@ -1,6 +1,6 @@
 use num_complex::Complex64;
 use num_traits::One;
-use std::ops::{Mul, MulAssign};
+use std::ops::{Add, Mul, MulAssign};
 
 use crate::state::types::{
     coefficient::{Amplify, Conj},
@@ -9,6 +9,7 @@ use crate::state::types::{
 
 /// Represents a scalar value in the form `phase * 2^(-r/2)` or zero.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Scalar {
     Zero,
     NonZero { phase: PhaseFactor, r: isize },
@@ -36,6 +37,30 @@ impl Scalar {
             }
         }
     }
+
+    /// Converts the scalar to its exact representation, or `None` for zero.
+    fn to_exact(self) -> Option<ExactScalar> {
+        let Scalar::NonZero { phase, r } = self else {
+            return None;
+        };
+        let k = phase.exponent() as usize;
+        let mut v = [0; 4];
+        v[k % 4] = if k < 4 { 1 } else { -1 };
+
+        // 2^(-r/2) = 2^(-q) for r = 2q, and sqrt(2) * 2^(-q-1) for r = 2q + 1
+        let q = r.div_euclid(2);
+        if r.rem_euclid(2) == 0 {
+            return Some(ExactScalar { v, e: q });
+        }
+        // sqrt(2) = ω - ω^3
+        let times_omega = |v: [i128; 4]| [-v[3], v[0], v[1], v[2]];
+        let v_omega = times_omega(v);
+        let v_omega3 = times_omega(times_omega(v_omega));
+        Some(ExactScalar {
+            v: [0, 1, 2, 3].map(|i| v_omega[i] - v_omega3[i]),
+            e: q + 1,
+        })
+    }
 }
 
 impl Mul for Scalar {
@@ -54,6 +79,82 @@ impl Mul for Scalar {
     }
 }
 
+impl Add for Scalar {
+    type Output = Option<Self>;
+
+    /// Adds two scalars exactly. Returns `None` if the sum is not of the form
+    /// `phase * 2^(-r/2)`, e.g. `1 + e^(i * pi / 4)`.
+    ///
+    /// Sums of two non-zero scalars `a` and `b` with `|a| >= |b|` are closed, among others, when
+    /// - `a = b`, giving `2a`,
+    /// - `a = -b`, giving zero,
+    /// - `a = ±i b`, giving `(1 ± i) b`, i.e. `√2 b` rotated by `±π/4`,
+    /// - `a = -2b`, giving `-b`,
+    /// - `a = √2 e^(±3iπ/4) b`, giving `±i b`.
+    fn add(self, rhs: Self) -> Self::Output {
+        let (lhs_exact, rhs_exact) = match (self.to_exact(), rhs.to_exact()) {
+            (None, _) => return Some(rhs),
+            (_, None) => return Some(self),
+            (Some(lhs_exact), Some(rhs_exact)) => (lhs_exact, rhs_exact),
+        };
+        let sum = lhs_exact.checked_add(&rhs_exact)?;
+        if sum.is_zero() {
+            return Some(Scalar::Zero);
+        }
+
+        // The candidate is determined numerically and then verified exactly.
+        let value = self.to_complex() + rhs.to_complex();
+        let r = (-2.0 * value.norm().log2()).round() as isize;
+        let phase = PhaseFactor::from_complex(value / value.norm(), 1e-6)?;
+        let candidate = Scalar::NonZero { phase, r };
+        let candidate_exact = candidate.to_exact()?;
+        (candidate_exact.checked_sub(&sum)?.is_zero()).then_some(candidate)
+    }
+}
+
+/// An element `v * 2^(-e)` of `Z[ω] / 2^e` with `ω = e^(i * pi / 4)`, where `v` holds the integer
+/// coefficients of `1, ω, ω^2, ω^3` (note that `ω^4 = -1`).
+/// Used to add scalars without rounding errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ExactScalar {
+    v: [i128; 4],
+    e: isize,
+}
+
+/// The maximum shift used to bring two [`ExactScalar`]s to a common denominator.
+const MAX_EXACT_SHIFT: isize = 64;
+
+impl ExactScalar {
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|&a| a == 0)
+    }
+
+    /// Rescales the coefficients to the denominator `2^e` with `e >= self.e`.
+    fn rescaled(&self, e: isize) -> Option<[i128; 4]> {
+        let shift = e - self.e;
+        if shift > MAX_EXACT_SHIFT {
+            return None;
+        }
+        Some(self.v.map(|a| a << shift))
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let e = self.e.max(rhs.e);
+        let (lhs, rhs) = (self.rescaled(e)?, rhs.rescaled(e)?);
+        Some(ExactScalar {
+            v: [0, 1, 2, 3].map(|i| lhs[i] + rhs[i]),
+            e,
+        })
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&ExactScalar {
+            v: rhs.v.map(|a| -a),
+            e: rhs.e,
+        })
+    }
+}
+
 impl From<Scalar> for Complex64 {
     fn from(scalar: Scalar) -> Self {
         scalar.to_complex()
@@ -118,3 +219,55 @@ impl Amplify for Scalar {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(k: u8, r: isize) -> Scalar {
+        Scalar::NonZero {
+            phase: PhaseFactor::from_exponent(k),
+            r,
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        // 1 + 1 = 2
+        assert_eq!(Scalar::ONE + Scalar::ONE, Some(scalar(0, -2)));
+        // 1 + (-1) = 0
+        assert_eq!(Scalar::ONE + scalar(4, 0), Some(Scalar::Zero));
+        // 0 is the identity
+        assert_eq!(Scalar::Zero + scalar(3, 5), Some(scalar(3, 5)));
+        assert_eq!(scalar(3, 5) + Scalar::Zero, Some(scalar(3, 5)));
+        assert_eq!(Scalar::Zero + Scalar::Zero, Some(Scalar::Zero));
+        // 1 + i = √2 e^(iπ/4)
+        assert_eq!(Scalar::ONE + scalar(2, 0), Some(scalar(1, -1)));
+        // 1 - 1/2 = 1/2
+        assert_eq!(Scalar::ONE + scalar(4, 2), Some(scalar(0, 2)));
+        // e^(-iπ/4) + i/√2 = 1/√2
+        assert_eq!(scalar(7, 0) + scalar(2, 1), Some(scalar(0, 1)));
+        // 1 + √2 e^(3iπ/4) = i
+        assert_eq!(Scalar::ONE + scalar(3, -1), Some(scalar(2, 0)));
+
+        // Inexpressible sums
+        assert_eq!(Scalar::ONE + scalar(1, 0), None); // 1 + e^(iπ/4)
+        assert_eq!(Scalar::ONE + scalar(0, 2), None); // 3/2
+        assert_eq!(Scalar::ONE + scalar(0, 1), None); // 1 + 1/√2
+        assert_eq!(Scalar::ONE + scalar(0, -200), None); // beyond the exact range
+
+        // The sums agree with the complex values
+        for k1 in 0..8 {
+            for k2 in 0..8 {
+                for r in -3..=3 {
+                    let (a, b) = (scalar(k1, 0), scalar(k2, r));
+                    if let Some(sum) = a + b {
+                        let expected = a.to_complex() + b.to_complex();
+                        assert!((sum.to_complex() - expected).norm() < 1e-10);
+                        assert_eq!(b + a, Some(sum));
+                    }
+                }
+            }
+        }
+    }
+}
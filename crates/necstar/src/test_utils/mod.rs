@@ -1,10 +1,10 @@
-use crate::circuit::{QuantumCircuit, QuantumGate};
+use crate::circuit::QuantumCircuit;
+use crate::circuit::random_clifford_t::random_clifford_t_with_rng;
 use crate::state::StabilizerDecomposedState;
 use ndarray::{Array1, Array2};
 use num_complex::Complex64;
+use rand::SeedableRng;
 use rand::rngs::StdRng;
-use rand::seq::SliceRandom;
-use rand::{Rng, SeedableRng};
 use stabilizer_ch_form_rust::StabilizerCHForm;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -124,87 +124,11 @@ pub(crate) fn random_circuit_with_t_gate(
     t_type_gate_count: usize,
     seed: Option<u64>,
 ) -> QuantumCircuit {
-    let mut circuit = QuantumCircuit::new(num_qubits);
     let mut rng = match seed {
         Some(s) => StdRng::seed_from_u64(s),
         None => StdRng::from_entropy(),
     };
-
-    // An enum to represent the category of gate to be generated.
-    #[derive(Clone, Copy)]
-    enum GateCategory {
-        Clifford,
-        TType,
-    }
-
-    // Create a pool of gate categories to be generated.
-    let total_gates = clifford_gate_count + t_type_gate_count;
-    let mut gate_categories: Vec<GateCategory> = Vec::with_capacity(total_gates);
-    gate_categories.extend(std::iter::repeat(GateCategory::Clifford).take(clifford_gate_count));
-    gate_categories.extend(std::iter::repeat(GateCategory::TType).take(t_type_gate_count));
-
-    // Shuffle the pool to ensure random ordering of Clifford and T-type gates.
-    gate_categories.shuffle(&mut rng);
-
-    for category in gate_categories {
-        let gate = match category {
-            GateCategory::Clifford => {
-                // Define the number of available Clifford gates of each arity.
-                const NUM_1Q_CLIFFORDS: u32 = 8; // H, X, Y, Z, S, Sdg, SqrtX, SqrtXdg
-                const NUM_2Q_CLIFFORDS: u32 = 3; // CX, CZ, Swap
-
-                // Determine the range of possible gates based on the number of qubits.
-                let max_gate_idx = if num_qubits < 2 {
-                    NUM_1Q_CLIFFORDS
-                } else {
-                    NUM_1Q_CLIFFORDS + NUM_2Q_CLIFFORDS
-                };
-
-                let gate_idx = rng.gen_range(0..max_gate_idx);
-
-                if gate_idx < NUM_1Q_CLIFFORDS {
-                    // Generate a 1-qubit Clifford gate.
-                    let q = rng.gen_range(0..num_qubits);
-                    match gate_idx {
-                        0 => QuantumGate::H(q),
-                        1 => QuantumGate::X(q),
-                        2 => QuantumGate::Y(q),
-                        3 => QuantumGate::Z(q),
-                        4 => QuantumGate::S(q),
-                        5 => QuantumGate::Sdg(q),
-                        6 => QuantumGate::SqrtX(q),
-                        7 => QuantumGate::SqrtXdg(q),
-                        _ => unreachable!(),
-                    }
-                } else {
-                    // Generate a 2-qubit Clifford gate.
-                    let q1 = rng.gen_range(0..num_qubits);
-                    let mut q2 = rng.gen_range(0..num_qubits);
-                    while q1 == q2 {
-                        q2 = rng.gen_range(0..num_qubits);
-                    }
-                    match gate_idx - NUM_1Q_CLIFFORDS {
-                        0 => QuantumGate::CX(q1, q2),
-                        1 => QuantumGate::CZ(q1, q2),
-                        2 => QuantumGate::Swap(q1, q2),
-                        _ => unreachable!(),
-                    }
-                }
-            }
-            GateCategory::TType => {
-                // Generate a T or Tdg gate.
-                let q = rng.gen_range(0..num_qubits);
-                if rng.gen_bool(0.5) {
-                    QuantumGate::T(q)
-                } else {
-                    QuantumGate::Tdg(q)
-                }
-            }
-        };
-        circuit.apply_gate(gate);
-    }
-
-    circuit
+    random_clifford_t_with_rng(num_qubits, clifford_gate_count, t_type_gate_count, &mut rng)
 }
 
 #[allow(dead_code)]
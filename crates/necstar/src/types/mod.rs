@@ -1,4 +1,9 @@
+pub mod seed;
 pub mod shot_count;
 
-// Re-export PauliString publicly from `stabilizer-ch-form-rust`
-pub use stabilizer_ch_form_rust::types::pauli::PauliString;
+pub use seed::seed_from_u64;
+
+// Re-export PauliString and Pauli publicly from `stabilizer-ch-form-rust`
+pub use stabilizer_ch_form_rust::types::pauli::{Pauli, PauliString};
+// Re-export the stabilizer state representation for `QuantumState::from_stabilizers`
+pub use stabilizer_ch_form_rust::StabilizerCHForm;
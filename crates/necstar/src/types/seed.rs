@@ -0,0 +1,65 @@
+/// Expands a `u64` into the 32-byte seed taken by the randomized methods, such as
+/// [`QuantumCircuit::random_clifford`](crate::circuit::QuantumCircuit::random_clifford),
+/// [`QuantumState::measure`](crate::state::QuantumState::measure) and
+/// [`QuantumState::sample`](crate::state::QuantumState::sample).
+///
+/// The value is stored in little-endian order in the first 8 bytes and the rest is zero, which
+/// matches how the Python bindings interpret integer seeds.
+///
+/// ## Examples
+/// ```rust
+/// use necstar::prelude::{QuantumCircuit, QuantumState};
+/// use necstar::types::seed_from_u64;
+///
+/// let seed = Some(seed_from_u64(42));
+/// let circuit = QuantumCircuit::random_clifford_t(3, 30, 3, seed);
+/// let state = QuantumState::from_circuit(&circuit).unwrap();
+/// assert_eq!(
+///     state.sample(&[0, 1, 2], 100, seed).unwrap(),
+///     state.sample(&[0, 1, 2], 100, seed).unwrap()
+/// );
+/// ```
+pub fn seed_from_u64(value: u64) -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    seed[..8].copy_from_slice(&value.to_le_bytes());
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::QuantumCircuit;
+    use crate::state::QuantumState;
+
+    #[test]
+    fn test_seed_from_u64() {
+        assert_eq!(seed_from_u64(0), [0; 32]);
+        let seed = seed_from_u64(0x0102);
+        assert_eq!(seed[..2], [0x02, 0x01]);
+        assert!(seed[2..].iter().all(|&b| b == 0));
+        assert_ne!(seed_from_u64(1), seed_from_u64(2));
+    }
+
+    #[test]
+    fn test_seed_reproducibility() {
+        let seed = Some(seed_from_u64(1234));
+        let circuit = QuantumCircuit::random_clifford(4, seed);
+        assert_eq!(circuit, QuantumCircuit::random_clifford(4, seed));
+
+        let circuit = QuantumCircuit::random_clifford_t(4, 40, 4, seed);
+        assert_eq!(circuit, QuantumCircuit::random_clifford_t(4, 40, 4, seed));
+
+        let state = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq!(
+            state.sample(&[0, 1, 2, 3], 200, seed).unwrap(),
+            state.sample(&[0, 1, 2, 3], 200, seed).unwrap()
+        );
+
+        let mut state1 = QuantumState::from_circuit(&circuit).unwrap();
+        let mut state2 = QuantumState::from_circuit(&circuit).unwrap();
+        assert_eq!(
+            state1.measure_all(seed).unwrap(),
+            state2.measure_all(seed).unwrap()
+        );
+    }
+}
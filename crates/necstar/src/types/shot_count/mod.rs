@@ -4,11 +4,18 @@ use std::fmt::Debug;
 /// Each entry in the vector contains a tuple of:
 /// 1. `Vec<bool>`: The measurement outcome for each qubit (false for `|0>`, true for `|1>`).
 /// 2. `usize`: The frequency (count) of this specific outcome.
+///
+/// The outcomes are sorted in ascending order of their little-endian integer representation.
 pub type ShotCount = Vec<(Vec<bool>, usize)>;
 
+/// Represents an exact distribution of measurement outcomes, as [`ShotCount`] with the
+/// probability of each outcome in place of its frequency.
+pub type Distribution = Vec<(Vec<bool>, f64)>;
+
 /// Trait for representing measurement outcomes (e.g. [false, false, true])
 /// as integer types: u32, u64, u128 (e.g. 0b001 for the previous example).
-pub(crate) trait OutcomeInteger: Copy + Sized + Debug {
+/// Outcomes wider than 128 qubits fall back to `Vec<bool>`.
+pub(crate) trait OutcomeInteger: Clone + Sized + Debug {
     fn zero() -> Self;
     fn set_bit(self, index: usize) -> Self;
     fn to_vec_bool(self, len: usize) -> Vec<bool>;
@@ -42,16 +49,35 @@ impl_outcome_integer!(u32);
 impl_outcome_integer!(u64);
 impl_outcome_integer!(u128);
 
+impl OutcomeInteger for Vec<bool> {
+    fn zero() -> Self {
+        Vec::new()
+    }
+    fn set_bit(mut self, index: usize) -> Self {
+        if self.len() <= index {
+            self.resize(index + 1, false);
+        }
+        self[index] = true;
+        self
+    }
+    fn to_vec_bool(mut self, len: usize) -> Vec<bool> {
+        self.resize(len, false);
+        self
+    }
+}
+
 pub(crate) enum SamplingBuffer {
     U32(Vec<(u32, usize)>),
     U64(Vec<(u64, usize)>),
     U128(Vec<(u128, usize)>),
+    Wide(Vec<(Vec<bool>, usize)>),
 }
 
 impl SamplingBuffer {
-    /// Converts the internal sampling result into the final `ShotCount` format.
+    /// Converts the internal sampling result into the final `ShotCount` format, sorted in
+    /// ascending order of the little-endian integer representation of the outcomes.
     pub(crate) fn finalize(self, num_qubits: usize) -> ShotCount {
-        match self {
+        let mut shot_count: ShotCount = match self {
             SamplingBuffer::U32(data) => data
                 .into_iter()
                 .map(|(bits, count)| (bits.to_vec_bool(num_qubits), count))
@@ -64,6 +90,13 @@ impl SamplingBuffer {
                 .into_iter()
                 .map(|(bits, count)| (bits.to_vec_bool(num_qubits), count))
                 .collect(),
-        }
+            SamplingBuffer::Wide(data) => data
+                .into_iter()
+                .map(|(bits, count)| (bits.to_vec_bool(num_qubits), count))
+                .collect(),
+        };
+        // The last element is the most significant bit.
+        shot_count.sort_unstable_by(|(a, _), (b, _)| a.iter().rev().cmp(b.iter().rev()));
+        shot_count
     }
 }
@@ -75,6 +75,29 @@ impl CliffordCircuit {
         }
     }
 
+    /// Creates the inverse of this circuit, which applies the inverses of the gates in the
+    /// reverse order.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::circuit::CliffordCircuit;
+    /// use stabilizer_ch_form_rust::circuit::CliffordGate::{ CX, Sdg };
+    ///
+    /// let mut circuit = CliffordCircuit::new(2);
+    /// circuit.apply_cx(0, 1);
+    /// circuit.apply_s(1);
+    /// assert_eq!(circuit.inverse().gates, vec![Sdg(1), CX(0, 1)]);
+    /// ```
+    ///
+    /// ## Returns
+    /// A new [`CliffordCircuit`] with the same number of qubits.
+    pub fn inverse(&self) -> Self {
+        CliffordCircuit {
+            num_qubits: self.num_qubits,
+            gates: self.gates.iter().rev().map(CliffordGate::inverse).collect(),
+        }
+    }
+
     /// Adds a Clifford gate to the circuit.
     /// ## Arguments
     /// * `gate` - The Clifford gate to add.
@@ -36,6 +36,33 @@ impl CliffordGate {
         }
     }
 
+    /// Returns the inverse of the gate.
+    ///
+    /// `S` and `SqrtX` are mapped to `Sdg` and `SqrtXdg` and vice versa, and the other gates are
+    /// self-inverse.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::circuit::CliffordGate;
+    /// assert_eq!(CliffordGate::S(0).inverse(), CliffordGate::Sdg(0));
+    /// assert_eq!(CliffordGate::CX(0, 1).inverse(), CliffordGate::CX(0, 1));
+    /// ```
+    pub fn inverse(&self) -> Self {
+        match *self {
+            CliffordGate::S(q) => CliffordGate::Sdg(q),
+            CliffordGate::Sdg(q) => CliffordGate::S(q),
+            CliffordGate::SqrtX(q) => CliffordGate::SqrtXdg(q),
+            CliffordGate::SqrtXdg(q) => CliffordGate::SqrtX(q),
+            CliffordGate::H(_)
+            | CliffordGate::X(_)
+            | CliffordGate::Y(_)
+            | CliffordGate::Z(_)
+            | CliffordGate::CX(_, _)
+            | CliffordGate::CZ(_, _)
+            | CliffordGate::Swap(_, _) => self.clone(),
+        }
+    }
+
     /// Returns a new `CliffordGate` with qubit indices shifted by the specified offset.
     pub(crate) fn shifted(&self, offset: usize) -> Self {
         let mut new_gate = self.clone();
@@ -195,7 +195,7 @@ cx q[0], q[1];"#;
         // Generate a random Clifford circuit
         let circuit1 = CliffordCircuit::random_clifford(num_qubits, Some([42; 32]));
         assert!(
-            circuit1.gates.len() > 0,
+            !circuit1.gates.is_empty(),
             "Random circuit should not be empty"
         );
 
@@ -226,7 +226,7 @@ mod tests {
         let num_qubits = 3;
         let circuit = random_clifford(num_qubits, Some([42; 32]));
         assert_eq!(circuit.num_qubits, num_qubits);
-        assert!(circuit.gates.len() > 0);
+        assert!(!circuit.gates.is_empty());
     }
 
     #[test]
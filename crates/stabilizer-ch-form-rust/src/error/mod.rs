@@ -54,6 +54,10 @@ pub enum Error {
     #[error("Cannot discard qubit {0} because it is not in a proper state")]
     CannotDiscardQubit(usize),
 
+    /// Error for stabilizer generators that do not define a stabilizer state.
+    #[error("Invalid stabilizer tableau: {0}")]
+    InvalidTableau(String),
+
     /// Error for QASM parsing issues.
     #[error("QASM parsing error: {0}")]
     QasmParsingError(String),
@@ -1,8 +1,56 @@
+use num_complex::Complex64;
+
 use crate::StabilizerCHForm;
 use crate::error::{Error, Result};
 use crate::form::types::Scalar;
 
 impl StabilizerCHForm {
+    /// Computes the amplitude <s|φ> for the computational basis state |s>, including the global
+    /// phase.
+    ///
+    /// Time complexity: O(n^2)
+    ///
+    /// ## Arguments
+    /// * `bitstring` - The computational basis state. The `i`-th element corresponds to qubit `i`,
+    ///   where `false` for `|0>` and `true` for `|1>`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the amplitude as `Complex64`.
+    pub fn amplitude(&self, bitstring: &[bool]) -> Result<Complex64> {
+        let s = ndarray::Array1::from_vec(bitstring.to_vec());
+        Ok(self.amplitude_at_computational_basis(&s)?.to_complex() * self.global_phase())
+    }
+
+    /// Returns the computational basis states with nonzero amplitude.
+    ///
+    /// The state is |φ> = ω U_C U_H |s>, where U_H |s> is a uniform superposition over the
+    /// bitstrings `y` with `y_j = s_j` for `v_j = 0`, and U_C maps |y> to a multiple of |G y>
+    /// since U_C^{-1} Z_p U_C = Π_j Z_j^{G_pj}. The support therefore consists of the
+    /// `2^{|v|}` distinct bitstrings `G y`.
+    ///
+    /// ## Returns
+    /// The bitstrings in the support, where the `i`-th element corresponds to qubit `i`. The
+    /// amplitudes can be computed with [`amplitude`](Self::amplitude).
+    pub fn support(&self) -> Vec<Vec<bool>> {
+        let n = self.n;
+        let gy = |y: &[bool]| -> Vec<bool> {
+            (0..n)
+                .map(|p| (0..n).fold(false, |acc, j| acc ^ (self.mat_g[[p, j]] & y[j])))
+                .collect()
+        };
+        let base: Vec<bool> = (0..n).map(|j| !self.vec_v[j] && self.vec_s[j]).collect();
+        let mut support = vec![gy(&base)];
+        for j in (0..n).filter(|&j| self.vec_v[j]) {
+            let column = self.mat_g.column(j);
+            let flipped: Vec<Vec<bool>> = support
+                .iter()
+                .map(|x| x.iter().zip(column.iter()).map(|(&a, &b)| a ^ b).collect())
+                .collect();
+            support.extend(flipped);
+        }
+        support
+    }
+
     /// Computes the amplitude <0...0|φ> for the stabilizer state φ.
     ///
     /// NOTE: The amplitude includes the phase factor, but not ω.
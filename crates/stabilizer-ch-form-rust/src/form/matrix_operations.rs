@@ -1,5 +1,5 @@
 use crate::StabilizerCHForm;
-use ndarray::{Zip, s};
+use ndarray::{Array2, Zip, s};
 
 impl StabilizerCHForm {
     /// Performs a bitwise XOR operation on two rows of a boolean matrix.
@@ -27,4 +27,29 @@ impl StabilizerCHForm {
                 *t ^= s;
             });
     }
+
+    /// Computes the inverse of a square matrix over GF(2) by Gauss-Jordan elimination.
+    /// Returns `None` if the matrix is singular.
+    pub(crate) fn gf2_inverse(matrix: &Array2<bool>) -> Option<Array2<bool>> {
+        let n = matrix.nrows();
+        let mut mat = matrix.clone();
+        let mut inv = Array2::from_shape_fn((n, n), |(i, j)| i == j);
+
+        for col in 0..n {
+            let pivot = (col..n).find(|&row| mat[[row, col]])?;
+            if pivot != col {
+                for j in 0..n {
+                    mat.swap([pivot, j], [col, j]);
+                    inv.swap([pivot, j], [col, j]);
+                }
+            }
+            for row in 0..n {
+                if row != col && mat[[row, col]] {
+                    Self::xor_rows(&mut mat, row, col);
+                    Self::xor_rows(&mut inv, row, col);
+                }
+            }
+        }
+        Some(inv)
+    }
 }
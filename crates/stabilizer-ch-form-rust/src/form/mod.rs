@@ -9,6 +9,7 @@ use crate::{
 use types::PhaseFactor;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StabilizerCHForm {
     pub(crate) n: usize,
     pub(crate) mat_g: Array2<bool>,
@@ -34,7 +35,9 @@ mod permute;
 mod project;
 mod resolve_superposition;
 mod right_multiplication;
+mod stabilizers;
 mod statevector;
+mod tableau;
 mod types;
 
 impl StabilizerCHForm {
@@ -115,4 +118,33 @@ impl StabilizerCHForm {
         }
         Ok(ch_form)
     }
+
+    /// Constructs a [`StabilizerCHForm`] from a [`CliffordCircuit`], invoking `callback` after
+    /// each gate application.
+    ///
+    /// This is intended for debugging and inspecting the intermediate CH-forms, e.g. to compare
+    /// them against a reference simulator gate by gate.
+    ///
+    /// ## Arguments
+    /// * `circuit` - The [`CliffordCircuit`] to convert.
+    /// * `callback` - A closure called with the applied gate and the CH-form right after the gate
+    ///   has been applied.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the resulting [`StabilizerCHForm`].
+    pub fn from_clifford_circuit_traced<F>(
+        circuit: &CliffordCircuit,
+        callback: &mut F,
+    ) -> Result<Self>
+    where
+        F: FnMut(&CliffordGate, &StabilizerCHForm),
+    {
+        let mut ch_form = StabilizerCHForm::new(circuit.num_qubits)?;
+
+        for gate in &circuit.gates {
+            ch_form.apply_gate(gate)?;
+            callback(gate, &ch_form);
+        }
+        Ok(ch_form)
+    }
 }
@@ -0,0 +1,399 @@
+use ndarray::Array1;
+use num_complex::Complex64;
+
+use crate::StabilizerCHForm;
+use crate::form::types::PhaseFactor;
+use crate::types::pauli::{Pauli, PauliString};
+
+/// A Pauli operator `i^k X^x Z^z` with the phase exponent `k` taken modulo 4.
+pub(super) struct PhasedPauli {
+    pub(super) k: u8,
+    pub(super) x: Array1<bool>,
+    pub(super) z: Array1<bool>,
+}
+
+impl PhasedPauli {
+    fn identity(n: usize) -> Self {
+        Self {
+            k: 0,
+            x: Array1::from_elem(n, false),
+            z: Array1::from_elem(n, false),
+        }
+    }
+
+    /// Right-multiplies by `i^k X^x Z^z`, using `Z^z1 X^x2 = (-1)^{z1·x2} X^x2 Z^z1`.
+    pub(super) fn mul_assign(&mut self, k: u8, x: &Array1<bool>, z: &Array1<bool>) {
+        let anticommuting = self
+            .z
+            .iter()
+            .zip(x.iter())
+            .fold(false, |acc, (&a, &b)| acc ^ (a & b));
+        self.k = (self.k + k + if anticommuting { 2 } else { 0 }) % 4;
+        self.x = &self.x ^ x;
+        self.z = &self.z ^ z;
+    }
+}
+
+impl StabilizerCHForm {
+    /// Returns the `n` stabilizer generators of the state in symplectic form.
+    ///
+    /// Each generator is `(negative, x, z)` and represents `(-1)^negative · P(x, z)`, where
+    /// `P(x, z)` is the Hermitian Pauli operator acting as `X` on qubits with `x_j & !z_j`,
+    /// `Z` on qubits with `!x_j & z_j`, and `Y` on qubits with `x_j & z_j`.
+    ///
+    /// The state is |φ> = ω U_C U_H |s>, so the stabilizers of U_H |s> (i.e. `(-1)^{s_j} Z_j` if
+    /// `v_j = 0`, and `(-1)^{s_j} X_j` if `v_j = 1`) are conjugated by U_C. The conjugation
+    /// U_C P U_C^{-1} is obtained by inverting the C-type tableau
+    /// U_C^{-1} Z_p U_C = Π_j Z_j^{G_pj}, U_C^{-1} X_p U_C = γ_p Π_j X_j^{F_pj} Z_j^{M_pj}.
+    /// See around eq. (45) of arXiv:1808.00128 for details.
+    // avoid `is_multiple_of` for compatibility with older Rust toolchains
+    #[allow(clippy::manual_is_multiple_of)]
+    pub(crate) fn stabilizer_generators(&self) -> Vec<(bool, Array1<bool>, Array1<bool>)> {
+        let n = self.n;
+        // G and F are always invertible since U_C is a Clifford operator.
+        let g_inv = Self::gf2_inverse(&self.mat_g).expect("G matrix must be invertible");
+        let f_inv = Self::gf2_inverse(&self.mat_f).expect("F matrix must be invertible");
+
+        // Row vector times matrix over GF(2).
+        let vec_mat = |vec: &Array1<bool>, mat: &ndarray::Array2<bool>| -> Array1<bool> {
+            let mut out = Array1::from_elem(mat.ncols(), false);
+            for (p, _) in vec.iter().enumerate().filter(|(_, b)| **b) {
+                out = &out ^ &mat.row(p);
+            }
+            out
+        };
+
+        let mut generators = Vec::with_capacity(n);
+        for j in 0..n {
+            // Target: the j-th stabilizer of U_H |s> (without sign).
+            let mut target_x = Array1::from_elem(n, false);
+            let mut target_z = Array1::from_elem(n, false);
+            if self.vec_v[j] {
+                target_x[j] = true;
+            } else {
+                target_z[j] = true;
+            }
+
+            // Solve [a b] such that U_C^{-1} P(a, b) U_C ∝ X^target_x Z^target_z,
+            // i.e. target_x = a F and target_z = a M + b G.
+            let a = vec_mat(&target_x, &f_inv);
+            let b = vec_mat(&(&target_z ^ &vec_mat(&a, &self.mat_m)), &g_inv);
+
+            // Track the phase of U_C^{-1} P(a, b) U_C, where P(a, b) = i^{a·b} X^a Z^b.
+            let weight_y = a.iter().zip(b.iter()).filter(|&(&x, &z)| x & z).count();
+            let mut image = PhasedPauli::identity(n);
+            image.k = (weight_y % 4) as u8;
+            for p in 0..n {
+                if a[p] {
+                    let gamma_k = phase_exponent(self.gamma[p]);
+                    image.mul_assign(
+                        gamma_k,
+                        &self.mat_f.row(p).to_owned(),
+                        &self.mat_m.row(p).to_owned(),
+                    );
+                }
+            }
+            let zeros = Array1::from_elem(n, false);
+            for p in 0..n {
+                if b[p] {
+                    image.mul_assign(0, &zeros, &self.mat_g.row(p).to_owned());
+                }
+            }
+            debug_assert!(image.x == target_x && image.z == target_z);
+
+            // c · U_C^{-1} P U_C = (-1)^{s_j} X^target_x Z^target_z  =>  c = (-1)^{s_j} i^{-k}
+            let sign_k = (4 + 2 * (self.vec_s[j] as u8) - image.k) % 4;
+            debug_assert!(sign_k % 2 == 0, "stabilizer generators must be Hermitian");
+            generators.push((sign_k == 2, a, b));
+        }
+        generators
+    }
+
+    /// Returns the generators of the stabilizer group in a canonical form, so that two states
+    /// have the same canonical generators if and only if they are equal up to global phase.
+    ///
+    /// The generators are brought to the reduced row echelon form over the columns
+    /// `x_0, ..., x_{n-1}, z_0, ..., z_{n-1}`, multiplying the rows as Pauli operators so that
+    /// the signs are tracked.
+    // avoid `is_multiple_of` for compatibility with older Rust toolchains
+    #[allow(clippy::manual_is_multiple_of)]
+    pub(crate) fn canonical_stabilizer_generators(
+        &self,
+    ) -> Vec<(bool, Array1<bool>, Array1<bool>)> {
+        let n = self.n;
+        // Hermitian (-1)^negative P(x, z) = (-1)^negative i^{x·z} X^x Z^z
+        let mut rows: Vec<PhasedPauli> = self
+            .stabilizer_generators()
+            .into_iter()
+            .map(|(negative, x, z)| {
+                let weight_y = x.iter().zip(z.iter()).filter(|&(&x, &z)| x & z).count();
+                PhasedPauli {
+                    k: ((2 * negative as usize + weight_y) % 4) as u8,
+                    x,
+                    z,
+                }
+            })
+            .collect();
+
+        let entry = |row: &PhasedPauli, col: usize| {
+            if col < n { row.x[col] } else { row.z[col - n] }
+        };
+        let mut pivot_row = 0;
+        for col in 0..2 * n {
+            let Some(found) = (pivot_row..n).find(|&r| entry(&rows[r], col)) else {
+                continue;
+            };
+            rows.swap(pivot_row, found);
+            let (k, x, z) = (
+                rows[pivot_row].k,
+                rows[pivot_row].x.clone(),
+                rows[pivot_row].z.clone(),
+            );
+            for (r, row) in rows.iter_mut().enumerate() {
+                // The generators commute, so the products stay Hermitian.
+                if r != pivot_row && entry(row, col) {
+                    row.mul_assign(k, &x, &z);
+                }
+            }
+            pivot_row += 1;
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                let weight_y = row
+                    .x
+                    .iter()
+                    .zip(row.z.iter())
+                    .filter(|&(&x, &z)| x & z)
+                    .count();
+                let sign_k = (4 + row.k as usize - weight_y % 4) % 4;
+                debug_assert!(sign_k % 2 == 0, "stabilizer generators must be Hermitian");
+                (sign_k == 2, row.x, row.z)
+            })
+            .collect()
+    }
+
+    /// Checks whether the two states are equal up to global phase, by comparing their
+    /// stabilizer groups.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::StabilizerCHForm;
+    ///
+    /// // S H |0> = |+i> = Z S† H |0>
+    /// let mut plus_i = StabilizerCHForm::new(1).unwrap();
+    /// plus_i.apply_h(0).unwrap();
+    /// plus_i.apply_s(0).unwrap();
+    /// let mut other = StabilizerCHForm::new(1).unwrap();
+    /// other.apply_h(0).unwrap();
+    /// other.apply_sdg(0).unwrap();
+    /// other.apply_z(0).unwrap();
+    /// assert!(plus_i.equals_up_to_phase(&other));
+    ///
+    /// let zero = StabilizerCHForm::new(1).unwrap();
+    /// assert!(!plus_i.equals_up_to_phase(&zero));
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `other` - The other [`StabilizerCHForm`] to compare with.
+    ///
+    /// ## Returns
+    /// `true` if `other` equals `self` up to global phase. States with different numbers of
+    /// qubits are never equal.
+    pub fn equals_up_to_phase(&self, other: &StabilizerCHForm) -> bool {
+        self.n == other.n
+            && self.canonical_stabilizer_generators() == other.canonical_stabilizer_generators()
+    }
+
+    /// Checks whether the two states are equal including the global phase.
+    ///
+    /// The stabilizer groups are compared as in [`equals_up_to_phase`](Self::equals_up_to_phase),
+    /// and the global phases are compared through the overlap `<self|other>`, which is `1` for
+    /// equal states. The raw CH-form fields are not compared, since different CH-forms (e.g.
+    /// different splits of the phase between `omega` and the phase factor) can denote the same
+    /// state.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::StabilizerCHForm;
+    ///
+    /// // S √X S = e^{iπ/4} H
+    /// let mut h = StabilizerCHForm::new(1).unwrap();
+    /// h.apply_h(0).unwrap();
+    /// let mut rotated = StabilizerCHForm::new(1).unwrap();
+    /// rotated.apply_s(0).unwrap();
+    /// rotated.apply_sqrt_x(0).unwrap();
+    /// rotated.apply_s(0).unwrap();
+    /// assert!(h.equals_up_to_phase(&rotated));
+    /// assert!(!h.equals_exact(&rotated));
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `other` - The other [`StabilizerCHForm`] to compare with.
+    ///
+    /// ## Returns
+    /// `true` if `other` equals `self` including the global phase, up to a tolerance of `1e-8`
+    /// on the overlap.
+    pub fn equals_exact(&self, other: &StabilizerCHForm) -> bool {
+        if !self.equals_up_to_phase(other) {
+            return false;
+        }
+        self.inner_product(other)
+            .is_ok_and(|overlap| (overlap - Complex64::new(1.0, 0.0)).norm() < 1e-8)
+    }
+
+    /// Returns the generators of the stabilizer group in a canonical form as signed
+    /// [`PauliString`]s. Two states are equal up to global phase if and only if their canonical
+    /// generators coincide, so the result can be used as a hash key.
+    ///
+    /// ## Returns
+    /// A vector of `(negative, pauli)` pairs, each representing `(-1)^negative · pauli`, where
+    /// each `pauli` is a dense [`PauliString`] of length `n`.
+    pub fn canonical_stabilizers(&self) -> Vec<(bool, PauliString)> {
+        self.canonical_stabilizer_generators()
+            .into_iter()
+            .map(|(negative, x, z)| (negative, pauli_string_from_symplectic(&x, &z)))
+            .collect()
+    }
+
+    /// Returns the `n` stabilizer generators of the state as signed [`PauliString`]s.
+    ///
+    /// Each element `(negative, pauli)` satisfies `(-1)^negative · pauli |φ> = |φ>`. Unlike
+    /// [`canonical_stabilizers`](Self::canonical_stabilizers), the generators are returned as they
+    /// are stored in the CH-form.
+    ///
+    /// ## Returns
+    /// A vector of `(negative, pauli)` pairs, where each `pauli` is a dense [`PauliString`] of
+    /// length `n`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::StabilizerCHForm;
+    ///
+    /// // |-> = Z H |0>
+    /// let mut ch_form = StabilizerCHForm::new(1).unwrap();
+    /// ch_form.apply_h(0).unwrap();
+    /// ch_form.apply_z(0).unwrap();
+    ///
+    /// let generators = ch_form.stabilizers_as_pauli_strings();
+    /// assert!(generators[0].0);
+    /// assert_eq!(generators[0].1.to_string(), "X");
+    /// ```
+    pub fn stabilizers_as_pauli_strings(&self) -> Vec<(bool, PauliString)> {
+        self.stabilizer_generators()
+            .into_iter()
+            .map(|(negative, x, z)| (negative, pauli_string_from_symplectic(&x, &z)))
+            .collect()
+    }
+}
+
+/// Converts the symplectic representation `(x, z)` to a dense [`PauliString`].
+fn pauli_string_from_symplectic(x: &Array1<bool>, z: &Array1<bool>) -> PauliString {
+    let ops = x
+        .iter()
+        .zip(z.iter())
+        .map(|(&x, &z)| Pauli::from_symplectic(x, z))
+        .collect();
+    PauliString::Dense(ops)
+}
+
+/// Returns `k` such that the phase equals `i^k`.
+fn phase_exponent(phase: PhaseFactor) -> u8 {
+    match phase {
+        PhaseFactor::PLUS_ONE => 0,
+        PhaseFactor::PLUS_I => 1,
+        PhaseFactor::MINUS_ONE => 2,
+        PhaseFactor::MINUS_I => 3,
+        _ => unreachable!("gamma must be a power of i"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circuit::CliffordCircuit;
+
+    use super::*;
+
+    #[test]
+    fn test_equals_up_to_phase() {
+        let num_qubits = 4;
+        for i in 0..10 {
+            let circuit = CliffordCircuit::random_clifford(num_qubits, Some([i + 56; 32]));
+            let state = StabilizerCHForm::from_clifford_circuit(&circuit).unwrap();
+
+            // The same state prepared with extra gates that only change the global phase
+            let mut rephased = state.clone();
+            for q in 0..num_qubits {
+                rephased.apply_x(q).unwrap();
+                rephased.apply_z(q).unwrap();
+                rephased.apply_x(q).unwrap();
+                rephased.apply_z(q).unwrap();
+            }
+            rephased.apply_s(0).unwrap();
+            rephased.apply_s(0).unwrap();
+            rephased.apply_z(0).unwrap();
+            assert!(state.equals_up_to_phase(&rephased));
+            assert_eq!(
+                state.canonical_stabilizers(),
+                rephased.canonical_stabilizers()
+            );
+            assert!((state.inner_product(&rephased).unwrap().norm() - 1.0).abs() < 1e-8);
+
+            // Compare with the overlap of different states
+            let other = StabilizerCHForm::from_clifford_circuit(&CliffordCircuit::random_clifford(
+                num_qubits,
+                Some([i + 78; 32]),
+            ))
+            .unwrap();
+            let overlap = state.inner_product(&other).unwrap().norm();
+            assert_eq!(
+                state.equals_up_to_phase(&other),
+                (overlap - 1.0).abs() < 1e-8
+            );
+
+            // A sign flip of a stabilizer gives an orthogonal state
+            let mut flipped = state.clone();
+            flipped.apply_x(1).unwrap();
+            assert_eq!(
+                state.equals_up_to_phase(&flipped),
+                (state.inner_product(&flipped).unwrap().norm() - 1.0).abs() < 1e-8
+            );
+        }
+
+        assert!(
+            !StabilizerCHForm::new(1)
+                .unwrap()
+                .equals_up_to_phase(&StabilizerCHForm::new(2).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_equals_exact() {
+        let mut h = StabilizerCHForm::new(2).unwrap();
+        h.apply_h(0).unwrap();
+        h.apply_cx(0, 1).unwrap();
+
+        // H = X H Z exactly
+        let mut xhz = StabilizerCHForm::new(2).unwrap();
+        xhz.apply_z(0).unwrap();
+        xhz.apply_h(0).unwrap();
+        xhz.apply_x(0).unwrap();
+        xhz.apply_cx(0, 1).unwrap();
+        assert!(h.equals_up_to_phase(&xhz));
+        assert!(h.equals_exact(&xhz));
+
+        // S √X S = e^{iπ/4} H
+        let mut rotated = StabilizerCHForm::new(2).unwrap();
+        rotated.apply_s(0).unwrap();
+        rotated.apply_sqrt_x(0).unwrap();
+        rotated.apply_s(0).unwrap();
+        rotated.apply_cx(0, 1).unwrap();
+        assert!(h.equals_up_to_phase(&rotated));
+        assert!(!h.equals_exact(&rotated));
+
+        let mut flipped = h.clone();
+        flipped.apply_z(0).unwrap();
+        assert!(!h.equals_up_to_phase(&flipped));
+        assert!(!h.equals_exact(&flipped));
+    }
+}
@@ -0,0 +1,368 @@
+use ndarray::Array2;
+
+use super::stabilizers::PhasedPauli;
+use crate::StabilizerCHForm;
+use crate::circuit::{CliffordCircuit, CliffordGate};
+use crate::error::{Error, Result};
+
+impl PhasedPauli {
+    /// Replaces the operator `P` by `g P g†` for the gate `g`, which must be `H`, `S`, `CX` or
+    /// `CZ`.
+    fn conjugate(&mut self, gate: &CliffordGate) {
+        match *gate {
+            // H X H = Z, H Z H = X, and X Z -> Z X = -X Z
+            CliffordGate::H(q) => {
+                if self.x[q] && self.z[q] {
+                    self.k = (self.k + 2) % 4;
+                }
+                std::mem::swap(&mut self.x[q], &mut self.z[q]);
+            }
+            // S X S† = i X Z, S Z S† = Z
+            CliffordGate::S(q) => {
+                if self.x[q] {
+                    self.k = (self.k + 1) % 4;
+                    self.z[q] ^= true;
+                }
+            }
+            // X_c -> X_c X_t, Z_t -> Z_c Z_t
+            CliffordGate::CX(c, t) => {
+                let x_c = self.x[c];
+                let z_t = self.z[t];
+                self.x[t] ^= x_c;
+                self.z[c] ^= z_t;
+            }
+            // X_a -> X_a Z_b, X_b -> Z_a X_b, and X_a Z_b Z_a X_b = -X_a X_b Z_a Z_b
+            CliffordGate::CZ(a, b) => {
+                let (x_a, x_b) = (self.x[a], self.x[b]);
+                if x_a && x_b {
+                    self.k = (self.k + 2) % 4;
+                }
+                self.z[b] ^= x_a;
+                self.z[a] ^= x_b;
+            }
+            _ => unreachable!("only H, S, CX and CZ are used to reduce the tableau"),
+        }
+    }
+}
+
+/// Conjugates all generators by `gate` and records it.
+fn apply(rows: &mut [PhasedPauli], gates: &mut Vec<CliffordGate>, gate: CliffordGate) {
+    for row in rows.iter_mut() {
+        row.conjugate(&gate);
+    }
+    gates.push(gate);
+}
+
+impl StabilizerCHForm {
+    /// Constructs the stabilizer state defined by `n` stabilizer generators in symplectic form.
+    ///
+    /// Row `i` of `x` and `z` together with `phases[i]` defines the generator
+    /// `(-1)^phases[i] · P(x_i, z_i)`, where `P(x_i, z_i)` acts as `X` on qubits `j` with
+    /// `x[[i, j]] & !z[[i, j]]`, `Z` on qubits with `!x[[i, j]] & z[[i, j]]`, and `Y` on qubits
+    /// with both set. This is the same convention as the generators returned by
+    /// [`stabilizers_as_pauli_strings`](Self::stabilizers_as_pauli_strings).
+    ///
+    /// The generators are reduced to `±Z_j` by conjugating with a Clifford circuit `U` built by
+    /// Gaussian elimination over GF(2), and the state is prepared as `U† |s>`, where `s_j` is
+    /// the sign of the `j`-th reduced generator. The global phase of the result is arbitrary.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use ndarray::array;
+    /// use stabilizer_ch_form_rust::StabilizerCHForm;
+    ///
+    /// // The Bell state is stabilized by XX and ZZ.
+    /// let x = array![[true, true], [false, false]];
+    /// let z = array![[false, false], [true, true]];
+    /// let bell = StabilizerCHForm::from_tableau(&x, &z, &[false, false]).unwrap();
+    ///
+    /// let mut expected = StabilizerCHForm::new(2).unwrap();
+    /// expected.apply_h(0).unwrap();
+    /// expected.apply_cx(0, 1).unwrap();
+    /// assert!(bell.equals_up_to_phase(&expected));
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `x` - The `n × n` X part of the generators, one generator per row.
+    /// * `z` - The `n × n` Z part of the generators, one generator per row.
+    /// * `phases` - The signs of the generators, `true` for `-1`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the [`StabilizerCHForm`], or
+    /// [`Error::InvalidTableau`] if the shapes are inconsistent or the generators do not commute
+    /// or are not independent.
+    // avoid `is_multiple_of` for compatibility with older Rust toolchains
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn from_tableau(x: &Array2<bool>, z: &Array2<bool>, phases: &[bool]) -> Result<Self> {
+        let n = phases.len();
+        if n == 0 {
+            return Err(Error::InvalidNumQubits(n));
+        }
+        if x.dim() != (n, n) || z.dim() != (n, n) {
+            return Err(Error::InvalidTableau(format!(
+                "expected {n} x {n} matrices for {n} phases, got {:?} and {:?}",
+                x.dim(),
+                z.dim()
+            )));
+        }
+
+        // Hermitian (-1)^negative P(x, z) = (-1)^negative i^{x·z} X^x Z^z
+        let mut rows: Vec<PhasedPauli> = (0..n)
+            .map(|i| {
+                let (x, z) = (x.row(i).to_owned(), z.row(i).to_owned());
+                let weight_y = x.iter().zip(z.iter()).filter(|&(&x, &z)| x & z).count();
+                PhasedPauli {
+                    k: ((2 * phases[i] as usize + weight_y) % 4) as u8,
+                    x,
+                    z,
+                }
+            })
+            .collect();
+
+        let symplectic_product = |a: &PhasedPauli, b: &PhasedPauli| {
+            let xz = a.x.iter().zip(b.z.iter()).filter(|&(&x, &z)| x & z).count();
+            let zx = a.z.iter().zip(b.x.iter()).filter(|&(&z, &x)| z & x).count();
+            (xz + zx) % 2 == 1
+        };
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if symplectic_product(&rows[i], &rows[j]) {
+                    return Err(Error::InvalidTableau(format!(
+                        "generators {i} and {j} do not commute"
+                    )));
+                }
+            }
+        }
+
+        // Reduce generator j to ±X_j column by column. Since the generators commute, the
+        // generators after row j always act trivially on the qubits before j.
+        let mut gates = Vec::new();
+        for j in 0..n {
+            let pivot = match (j..n).find(|&r| rows[r].x[j]) {
+                Some(r) => r,
+                None => {
+                    let Some(r) = (j..n).find(|&r| rows[r].z[j]) else {
+                        return Err(Error::InvalidTableau(
+                            "generators are not independent".to_string(),
+                        ));
+                    };
+                    apply(&mut rows, &mut gates, CliffordGate::H(j));
+                    r
+                }
+            };
+            rows.swap(j, pivot);
+
+            let (k, x_j, z_j) = (rows[j].k, rows[j].x.clone(), rows[j].z.clone());
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i != j && row.x[j] {
+                    row.mul_assign(k, &x_j, &z_j);
+                }
+            }
+
+            for q in (j + 1)..n {
+                if rows[j].x[q] {
+                    apply(&mut rows, &mut gates, CliffordGate::CX(j, q));
+                }
+            }
+            for q in (j + 1)..n {
+                if rows[j].z[q] {
+                    apply(&mut rows, &mut gates, CliffordGate::CZ(j, q));
+                }
+            }
+            if rows[j].z[j] {
+                apply(&mut rows, &mut gates, CliffordGate::S(j));
+            }
+        }
+        for j in 0..n {
+            apply(&mut rows, &mut gates, CliffordGate::H(j));
+        }
+
+        // U |ψ> = |s> with U = g_m ... g_1, so |ψ> = g_1† ... g_m† |s>.
+        let mut circuit = CliffordCircuit::new(n);
+        for (j, row) in rows.iter().enumerate() {
+            debug_assert!(row.x.iter().all(|&b| !b));
+            debug_assert!(row.z.iter().enumerate().all(|(q, &b)| b == (q == j)));
+            debug_assert!(row.k % 2 == 0);
+            if row.k == 2 {
+                circuit.gates.push(CliffordGate::X(j));
+            }
+        }
+        circuit
+            .gates
+            .extend(gates.iter().rev().map(CliffordGate::inverse));
+        Self::from_clifford_circuit(&circuit)
+    }
+
+    /// Returns the stabilizer generators in the format accepted by
+    /// [`from_tableau`](Self::from_tableau).
+    ///
+    /// ## Returns
+    /// A tuple `(x, z, phases)` with the `n × n` X and Z parts of the generators, one generator
+    /// per row, and their signs (`true` for `-1`).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use ndarray::array;
+    /// use stabilizer_ch_form_rust::StabilizerCHForm;
+    ///
+    /// // |-> = Z H |0> is stabilized by -X.
+    /// let mut minus = StabilizerCHForm::new(1).unwrap();
+    /// minus.apply_h(0).unwrap();
+    /// minus.apply_z(0).unwrap();
+    /// let (x, z, phases) = minus.to_tableau();
+    /// assert_eq!(x, array![[true]]);
+    /// assert_eq!(z, array![[false]]);
+    /// assert_eq!(phases, vec![true]);
+    /// ```
+    pub fn to_tableau(&self) -> (Array2<bool>, Array2<bool>, Vec<bool>) {
+        let n = self.n;
+        let mut x = Array2::from_elem((n, n), false);
+        let mut z = Array2::from_elem((n, n), false);
+        let mut phases = Vec::with_capacity(n);
+        for (i, (negative, x_i, z_i)) in self.stabilizer_generators().into_iter().enumerate() {
+            x.row_mut(i).assign(&x_i);
+            z.row_mut(i).assign(&z_i);
+            phases.push(negative);
+        }
+        (x, z, phases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{Array1, array};
+    use num_complex::Complex64;
+
+    use super::*;
+
+    /// Asserts that the statevector of `state` equals `expected` up to global phase.
+    fn assert_statevector_up_to_phase(state: &StabilizerCHForm, expected: &Array1<Complex64>) {
+        let actual = state.to_statevector().unwrap();
+        let overlap: Complex64 = actual
+            .iter()
+            .zip(expected.iter())
+            .map(|(a, b)| a.conj() * b)
+            .sum();
+        assert!((overlap.norm() - 1.0).abs() < 1e-8, "overlap = {overlap}");
+    }
+
+    #[test]
+    fn test_from_tableau_bell_and_ghz() {
+        let r = 1.0 / 2f64.sqrt();
+
+        // XX, ZZ
+        let x = array![[true, true], [false, false]];
+        let z = array![[false, false], [true, true]];
+        let bell = StabilizerCHForm::from_tableau(&x, &z, &[false, false]).unwrap();
+        let mut expected = Array1::zeros(4);
+        expected[0b00] = Complex64::new(r, 0.0);
+        expected[0b11] = Complex64::new(r, 0.0);
+        assert_statevector_up_to_phase(&bell, &expected);
+
+        // -XX, ZZ
+        let bell_minus = StabilizerCHForm::from_tableau(&x, &z, &[true, false]).unwrap();
+        expected[0b11] = Complex64::new(-r, 0.0);
+        assert_statevector_up_to_phase(&bell_minus, &expected);
+
+        // -YY, ZZ
+        let x = array![[true, true], [false, false]];
+        let z = array![[true, true], [true, true]];
+        let bell_y = StabilizerCHForm::from_tableau(&x, &z, &[true, false]).unwrap();
+        expected[0b11] = Complex64::new(r, 0.0);
+        assert_statevector_up_to_phase(&bell_y, &expected);
+
+        // XXX, ZZI, IZZ
+        let x = array![
+            [true, true, true],
+            [false, false, false],
+            [false, false, false]
+        ];
+        let z = array![
+            [false, false, false],
+            [true, true, false],
+            [false, true, true]
+        ];
+        let ghz = StabilizerCHForm::from_tableau(&x, &z, &[false; 3]).unwrap();
+        let mut expected = Array1::zeros(8);
+        expected[0b000] = Complex64::new(r, 0.0);
+        expected[0b111] = Complex64::new(r, 0.0);
+        assert_statevector_up_to_phase(&ghz, &expected);
+    }
+
+    #[test]
+    fn test_from_tableau_round_trip() {
+        for i in 0..20 {
+            let num_qubits = 1 + i as usize % 6;
+            let circuit = CliffordCircuit::random_clifford(num_qubits, Some([i + 7; 32]));
+            let state = StabilizerCHForm::from_clifford_circuit(&circuit).unwrap();
+            let generators = state.stabilizer_generators();
+            let x = Array2::from_shape_fn((num_qubits, num_qubits), |(i, j)| generators[i].1[j]);
+            let z = Array2::from_shape_fn((num_qubits, num_qubits), |(i, j)| generators[i].2[j]);
+            let phases: Vec<bool> = generators
+                .iter()
+                .map(|(negative, _, _)| *negative)
+                .collect();
+            let restored = StabilizerCHForm::from_tableau(&x, &z, &phases).unwrap();
+            assert!(restored.equals_up_to_phase(&state));
+            assert_statevector_up_to_phase(&restored, &state.to_statevector().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_to_tableau_round_trip() {
+        for i in 0..20 {
+            let num_qubits = 1 + i as usize % 6;
+            let circuit = CliffordCircuit::random_clifford(num_qubits, Some([i + 31; 32]));
+            let mut state = StabilizerCHForm::from_clifford_circuit(&circuit).unwrap();
+            state.apply_x(0).unwrap();
+            let (x, z, phases) = state.to_tableau();
+            assert_eq!(x.dim(), (num_qubits, num_qubits));
+            assert_eq!(z.dim(), (num_qubits, num_qubits));
+            assert_eq!(phases.len(), num_qubits);
+
+            let restored = StabilizerCHForm::from_tableau(&x, &z, &phases).unwrap();
+            assert!(restored.equals_up_to_phase(&state));
+            assert_statevector_up_to_phase(&restored, &state.to_statevector().unwrap());
+
+            // Flipping a sign gives an orthogonal state.
+            let mut flipped = phases.clone();
+            flipped[0] ^= true;
+            let other = StabilizerCHForm::from_tableau(&x, &z, &flipped).unwrap();
+            assert!(state.inner_product(&other).unwrap().norm() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_from_tableau_invalid() {
+        // XI, ZI
+        let x = array![[true, false], [false, false]];
+        let z = array![[false, false], [true, false]];
+        assert!(matches!(
+            StabilizerCHForm::from_tableau(&x, &z, &[false, false]),
+            Err(Error::InvalidTableau(_))
+        ));
+
+        // ZZ, ZZ
+        let x = array![[false, false], [false, false]];
+        let z = array![[true, true], [true, true]];
+        assert!(matches!(
+            StabilizerCHForm::from_tableau(&x, &z, &[false, false]),
+            Err(Error::InvalidTableau(_))
+        ));
+
+        let x = array![[true, false]];
+        let z = array![[false, false]];
+        assert!(matches!(
+            StabilizerCHForm::from_tableau(&x, &z, &[false]),
+            Err(Error::InvalidTableau(_))
+        ));
+        assert!(matches!(
+            StabilizerCHForm::from_tableau(
+                &Array2::from_elem((0, 0), false),
+                &Array2::from_elem((0, 0), false),
+                &[]
+            ),
+            Err(Error::InvalidNumQubits(0))
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::{Mul, MulAssign};
 
 /// Represents a phase of the form e^(i * k * pi / 4) for k in {0, 1, ..., 7}.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct PhaseFactor(u8);
 
 impl PhaseFactor {
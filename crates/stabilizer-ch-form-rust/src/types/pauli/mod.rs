@@ -1,6 +1,7 @@
 use crate::error::Error;
 use crate::error::Result;
 use lazy_static::lazy_static;
+use num_complex::Complex64;
 use regex::Regex;
 use std::{fmt, str::FromStr};
 
@@ -39,7 +40,7 @@ pub use pauli_term::PauliTerm;
 /// assert!(identity_sparse.is_identity());
 /// assert!(identity_dense.is_identity());
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PauliString {
     Dense(Vec<Pauli>),
     Sparse(Vec<PauliTerm>),
@@ -119,6 +120,29 @@ fn parse_sparse(s: &str) -> Result<PauliString> {
     Ok(PauliString::Sparse(terms))
 }
 
+/// Returns the phase prefix (e.g. `"i"`, `"-"`, `"-i"`) of a Pauli string, if any.
+/// A lowercase `i` or `j` is only treated as a phase when followed by further characters,
+/// so that `"i"` alone still denotes the identity.
+fn leading_phase(s: &str) -> Option<&str> {
+    let sign_len = if s.starts_with('+') || s.starts_with('-') {
+        1
+    } else {
+        0
+    };
+    let rest = &s[sign_len..];
+    let imag_len = if (rest.starts_with('i') || rest.starts_with('j')) && rest.len() > 1 {
+        1
+    } else {
+        0
+    };
+    let phase_len = sign_len + imag_len;
+    if phase_len > 0 {
+        Some(&s[..phase_len])
+    } else {
+        None
+    }
+}
+
 /// Implements `FromStr` for PauliString to allow parsing from strings.
 impl FromStr for PauliString {
     type Err = Error;
@@ -131,6 +155,17 @@ impl FromStr for PauliString {
             return Ok(PauliString::identity());
         }
 
+        // PauliString carries no phase, so it always represents a Hermitian operator.
+        // Reject explicit phase prefixes such as "iXY" or "-XY" here rather than letting them
+        // produce an unexpected expectation value later.
+        if let Some(phase) = leading_phase(trimmed) {
+            return Err(Error::PauliStringParsingError(format!(
+                "phase prefix '{}' is not supported in '{}': PauliString represents a Hermitian \
+                 Pauli operator without phase",
+                phase, s
+            )));
+        }
+
         // Heuristic to decide format: check for digits.
         let has_digits = trimmed.chars().any(|c| c.is_ascii_digit());
 
@@ -148,6 +183,308 @@ impl PauliString {
         PauliString::Sparse(vec![])
     }
 
+    /// Constructs a dense Pauli string from its symplectic representation, where qubit `i`
+    /// carries `X^{x_i} Z^{z_i}` up to phase, i.e. `Y` if both `x_i` and `z_i` are set.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// // Little-endian: the rightmost character is qubit 0.
+    /// let pauli = PauliString::from_symplectic(&[true, false, true], &[false, true, true]).unwrap();
+    /// assert_eq!(pauli, "YZX".parse().unwrap());
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `x` - The X part, where the `i`-th element corresponds to qubit `i`.
+    /// * `z` - The Z part, of the same length as `x`.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the dense [`PauliString`] on `x.len()` qubits, or
+    /// [`Error::QubitCountMismatch`] if the lengths of `x` and `z` differ.
+    pub fn from_symplectic(x: &[bool], z: &[bool]) -> Result<Self> {
+        if x.len() != z.len() {
+            return Err(Error::QubitCountMismatch {
+                operation: "from_symplectic",
+                left: x.len(),
+                right: z.len(),
+            });
+        }
+        let ops = x
+            .iter()
+            .zip(z)
+            .map(|(&x, &z)| Pauli::from_symplectic(x, z))
+            .collect();
+        Ok(PauliString::Dense(ops))
+    }
+
+    /// Returns the symplectic representation `(x, z)` of the Pauli string on `num_qubits`
+    /// qubits, the inverse of [`from_symplectic`](Self::from_symplectic).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// let pauli: PauliString = "X0 Y2".parse().unwrap();
+    /// let (x, z) = pauli.to_symplectic(3).unwrap();
+    /// assert_eq!(x, vec![true, false, true]);
+    /// assert_eq!(z, vec![false, false, true]);
+    /// ```
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the X and Z parts of length `num_qubits`, or an error if a
+    /// non-identity operator acts on a qubit index `>= num_qubits`.
+    pub fn to_symplectic(&self, num_qubits: usize) -> Result<(Vec<bool>, Vec<bool>)> {
+        let PauliString::Dense(ops) = self.padded_to(num_qubits)? else {
+            unreachable!("padded_to always returns a dense Pauli string");
+        };
+        Ok(ops.into_iter().map(Pauli::to_symplectic).unzip())
+    }
+
+    /// Checks if the Pauli string represents a Hermitian operator.
+    ///
+    /// Since a [`PauliString`] is a tensor product of `I`, `X`, `Y`, `Z` without any phase, this
+    /// always holds. Non-Hermitian inputs such as `"iXY"` are rejected when parsing.
+    pub fn is_hermitian(&self) -> bool {
+        true
+    }
+
+    /// Converts the Pauli string to a dense representation of exactly `num_qubits` qubits,
+    /// padding the qubits without any operator with identities.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// let pauli: PauliString = "Z2".parse().unwrap();
+    /// let padded = pauli.padded_to(4).unwrap();
+    /// assert_eq!(padded, "IZII".parse().unwrap());
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `num_qubits` - The number of qubits of the resulting dense Pauli string.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the dense [`PauliString`], or an error if a non-identity operator
+    /// acts on a qubit index `>= num_qubits`.
+    pub fn padded_to(&self, num_qubits: usize) -> Result<PauliString> {
+        let mut ops = vec![Pauli::I; num_qubits];
+        match self {
+            PauliString::Dense(dense_ops) => {
+                for (qubit, &op) in dense_ops.iter().enumerate() {
+                    if op == Pauli::I {
+                        continue;
+                    }
+                    if qubit >= num_qubits {
+                        return Err(Error::QubitIndexOutOfBounds(qubit, num_qubits));
+                    }
+                    ops[qubit] = op;
+                }
+            }
+            PauliString::Sparse(terms) => {
+                for term in terms {
+                    if term.op == Pauli::I {
+                        continue;
+                    }
+                    if term.qubit >= num_qubits {
+                        return Err(Error::QubitIndexOutOfBounds(term.qubit, num_qubits));
+                    }
+                    if ops[term.qubit] != Pauli::I {
+                        return Err(Error::PauliStringParsingError(format!(
+                            "duplicate Pauli operator on qubit {}",
+                            term.qubit
+                        )));
+                    }
+                    ops[term.qubit] = term.op;
+                }
+            }
+        }
+        Ok(PauliString::Dense(ops))
+    }
+
+    /// Computes the product `self · other` of two Pauli operators.
+    ///
+    /// The product of two Pauli strings is again a Pauli string up to a phase in `{±1, ±i}`,
+    /// e.g. `X · Y = iZ`. The operands may be in either format and of different lengths; the
+    /// shorter one is padded with identities. The resulting Pauli string is always dense.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use num_complex::Complex64;
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// let x: PauliString = "X".parse().unwrap();
+    /// let y: PauliString = "Y".parse().unwrap();
+    /// let (product, phase) = x.multiply(&y).unwrap();
+    /// assert_eq!(product, "Z".parse().unwrap());
+    /// assert_eq!(phase, Complex64::new(0.0, 1.0));
+    /// ```
+    ///
+    /// ## Arguments
+    /// * `other` - The Pauli string multiplied from the right.
+    ///
+    /// ## Returns
+    /// A [`Result`] containing the product as a dense [`PauliString`] and its phase, or an error
+    /// if a sparse operand has several operators on the same qubit.
+    pub fn multiply(&self, other: &PauliString) -> Result<(PauliString, Complex64)> {
+        let num_qubits = self.min_num_qubits().max(other.min_num_qubits());
+        let (PauliString::Dense(lhs), PauliString::Dense(rhs)) =
+            (self.padded_to(num_qubits)?, other.padded_to(num_qubits)?)
+        else {
+            unreachable!("padded_to always returns a dense Pauli string");
+        };
+
+        // The phase is accumulated as a power of i.
+        let mut power = 0u8;
+        let ops = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(&a, &b)| {
+                let (op, p) = multiply_single(a, b);
+                power = (power + p) % 4;
+                op
+            })
+            .collect();
+
+        let phase = match power {
+            0 => Complex64::new(1.0, 0.0),
+            1 => Complex64::new(0.0, 1.0),
+            2 => Complex64::new(-1.0, 0.0),
+            _ => Complex64::new(0.0, -1.0),
+        };
+        Ok((PauliString::Dense(ops), phase))
+    }
+
+    /// Checks if the Pauli string commutes with `other`.
+    ///
+    /// Two Pauli strings commute if and only if the number of qubits on which both act with
+    /// different non-identity Paulis is even. The operands may be in either format and of
+    /// different lengths.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// let xy: PauliString = "X0 Y1".parse().unwrap();
+    /// let yx: PauliString = "XY".parse().unwrap();
+    /// assert!(xy.commutes(&yx));
+    /// ```
+    pub fn commutes(&self, other: &PauliString) -> bool {
+        let num_qubits = self.min_num_qubits().max(other.min_num_qubits());
+        let num_anticommuting = (0..num_qubits)
+            .filter(|&qubit| {
+                let (a, b) = (self.op_at(qubit), other.op_at(qubit));
+                a != Pauli::I && b != Pauli::I && a != b
+            })
+            .count();
+        num_anticommuting % 2 == 0
+    }
+
+    /// Checks if the Pauli string anticommutes with `other`, i.e. the negation of
+    /// [`commutes`](Self::commutes).
+    pub fn anticommutes(&self, other: &PauliString) -> bool {
+        !self.commutes(other)
+    }
+
+    /// Returns the number of qubits on which the Pauli string acts non-trivially.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// let pauli: PauliString = "IXIZ".parse().unwrap();
+    /// assert_eq!(pauli.weight(), 2);
+    /// ```
+    pub fn weight(&self) -> usize {
+        self.support().len()
+    }
+
+    /// Returns the sorted indices of the qubits on which the Pauli string acts non-trivially.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// // Little-endian: the rightmost character is qubit 0.
+    /// let pauli: PauliString = "IXIZ".parse().unwrap();
+    /// assert_eq!(pauli.support(), vec![0, 2]);
+    /// ```
+    pub fn support(&self) -> Vec<usize> {
+        match self {
+            PauliString::Dense(ops) => ops
+                .iter()
+                .enumerate()
+                .filter(|&(_, &op)| op != Pauli::I)
+                .map(|(qubit, _)| qubit)
+                .collect(),
+            PauliString::Sparse(terms) => {
+                let mut qubits: Vec<usize> = terms
+                    .iter()
+                    .filter(|t| t.op != Pauli::I)
+                    .map(|t| t.qubit)
+                    .collect();
+                qubits.sort_unstable();
+                qubits.dedup();
+                qubits
+            }
+        }
+    }
+
+    /// Returns the Pauli operator acting on `qubit`.
+    fn op_at(&self, qubit: usize) -> Pauli {
+        match self {
+            PauliString::Dense(ops) => ops.get(qubit).copied().unwrap_or(Pauli::I),
+            PauliString::Sparse(terms) => terms
+                .iter()
+                .find(|t| t.qubit == qubit)
+                .map_or(Pauli::I, |t| t.op),
+        }
+    }
+
+    /// Returns the smallest number of qubits the Pauli string can be padded to, i.e. the length
+    /// of a dense string and one more than the largest qubit index of a sparse one.
+    fn min_num_qubits(&self) -> usize {
+        match self {
+            PauliString::Dense(ops) => ops.len(),
+            PauliString::Sparse(terms) => terms.iter().map(|t| t.qubit + 1).max().unwrap_or(0),
+        }
+    }
+
+    /// Converts the Pauli string to the dense representation on `num_qubits` qubits.
+    ///
+    /// This is [`padded_to`](Self::padded_to) under the name matching
+    /// [`to_sparse`](Self::to_sparse).
+    pub fn to_dense(&self, num_qubits: usize) -> Result<PauliString> {
+        self.padded_to(num_qubits)
+    }
+
+    /// Converts the Pauli string to the sparse representation, dropping identities and sorting
+    /// the terms by qubit index.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use stabilizer_ch_form_rust::types::pauli::PauliString;
+    ///
+    /// // Little-endian: the rightmost character is qubit 0.
+    /// let pauli: PauliString = "IYIX".parse().unwrap();
+    /// assert_eq!(pauli.to_sparse(), "X0 Y2".parse().unwrap());
+    /// ```
+    pub fn to_sparse(&self) -> PauliString {
+        let mut terms: Vec<PauliTerm> = match self {
+            PauliString::Dense(ops) => ops
+                .iter()
+                .enumerate()
+                .filter(|&(_, &op)| op != Pauli::I)
+                .map(|(qubit, &op)| PauliTerm { op, qubit })
+                .collect(),
+            PauliString::Sparse(terms) => {
+                terms.iter().filter(|t| t.op != Pauli::I).cloned().collect()
+            }
+        };
+        terms.sort_by_key(|t| t.qubit);
+        PauliString::Sparse(terms)
+    }
+
     /// Checks if the Pauli string is the identity operator.
     pub fn is_identity(&self) -> bool {
         match self {
@@ -157,6 +494,22 @@ impl PauliString {
     }
 }
 
+/// Multiplies two single-qubit Pauli operators, returning the product and the exponent `k` of
+/// its phase `i^k`.
+fn multiply_single(a: Pauli, b: Pauli) -> (Pauli, u8) {
+    match (a, b) {
+        (Pauli::I, op) | (op, Pauli::I) => (op, 0),
+        (Pauli::X, Pauli::Y) => (Pauli::Z, 1),
+        (Pauli::Y, Pauli::Z) => (Pauli::X, 1),
+        (Pauli::Z, Pauli::X) => (Pauli::Y, 1),
+        (Pauli::Y, Pauli::X) => (Pauli::Z, 3),
+        (Pauli::Z, Pauli::Y) => (Pauli::X, 3),
+        (Pauli::X, Pauli::Z) => (Pauli::Y, 3),
+        // a == b
+        _ => (Pauli::I, 0),
+    }
+}
+
 impl fmt::Display for PauliString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -202,3 +555,266 @@ impl fmt::Display for PauliString {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_hermitian() {
+        let pauli: PauliString = "XY".parse().unwrap();
+        assert!(pauli.is_hermitian());
+        let pauli: PauliString = "X0 Y1".parse().unwrap();
+        assert!(pauli.is_hermitian());
+
+        // Phase prefixes are rejected at construction.
+        for s in ["iXY", "-iXY", "+iX0 Y1", "-XY", "jZ"] {
+            let result = s.parse::<PauliString>();
+            assert!(
+                matches!(result, Err(Error::PauliStringParsingError(ref msg)) if msg.contains("phase")),
+                "expected phase error for '{}', got {:?}",
+                s,
+                result
+            );
+        }
+
+        // A lone "i" still denotes the identity.
+        assert!("i".parse::<PauliString>().unwrap().is_identity());
+    }
+
+    #[test]
+    fn test_padded_to() {
+        let pauli: PauliString = "Z2".parse().unwrap();
+        let padded = pauli.padded_to(4).unwrap();
+        assert_eq!(
+            padded,
+            PauliString::Dense(vec![Pauli::I, Pauli::I, Pauli::Z, Pauli::I])
+        );
+        assert_eq!(padded.to_string(), "IZII");
+
+        // Dense strings are padded on the high qubits
+        let pauli: PauliString = "XY".parse().unwrap();
+        assert_eq!(pauli.padded_to(3).unwrap().to_string(), "IXY");
+
+        // Trailing identities beyond the range are dropped
+        let pauli: PauliString = "IIZ".parse().unwrap();
+        assert_eq!(pauli.padded_to(1).unwrap().to_string(), "Z");
+
+        // Identity
+        assert_eq!(
+            PauliString::identity().padded_to(2).unwrap().to_string(),
+            "II"
+        );
+
+        // Out-of-range operators
+        let pauli: PauliString = "X0 Z4".parse().unwrap();
+        assert!(matches!(
+            pauli.padded_to(4),
+            Err(Error::QubitIndexOutOfBounds(4, 4))
+        ));
+        let pauli: PauliString = "XII".parse().unwrap();
+        assert!(matches!(
+            pauli.padded_to(2),
+            Err(Error::QubitIndexOutOfBounds(2, 2))
+        ));
+    }
+
+    #[test]
+    fn test_multiply() {
+        let i = Complex64::new(0.0, 1.0);
+        let mul = |a: &str, b: &str| {
+            let a: PauliString = a.parse().unwrap();
+            let b: PauliString = b.parse().unwrap();
+            a.multiply(&b).unwrap()
+        };
+
+        assert_eq!(mul("X", "Y"), ("Z".parse().unwrap(), i));
+        assert_eq!(mul("Y", "X"), ("Z".parse().unwrap(), -i));
+        assert_eq!(mul("Z", "X"), ("Y".parse().unwrap(), i));
+        assert_eq!(mul("Y", "Z"), ("X".parse().unwrap(), i));
+        assert_eq!(
+            mul("Z", "Z"),
+            (PauliString::Dense(vec![Pauli::I]), Complex64::new(1.0, 0.0))
+        );
+
+        // XYZ · ZYX: qubit 0 Z·X = iY, qubit 1 Y·Y = I, qubit 2 X·Z = -iY
+        assert_eq!(
+            mul("XYZ", "ZYX"),
+            ("YIY".parse().unwrap(), Complex64::new(1.0, 0.0))
+        );
+        // Mixed formats and lengths: the dense operand is padded to three qubits
+        assert_eq!(mul("X0 Y2", "ZZ"), ("YZY".parse().unwrap(), -i));
+        assert_eq!(
+            mul("I", "X3"),
+            ("XIII".parse().unwrap(), Complex64::new(1.0, 0.0))
+        );
+        assert_eq!(
+            PauliString::identity()
+                .multiply(&PauliString::identity())
+                .unwrap(),
+            (PauliString::Dense(vec![]), Complex64::new(1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_commutes() {
+        let commutes = |a: &str, b: &str| {
+            let a: PauliString = a.parse().unwrap();
+            let b: PauliString = b.parse().unwrap();
+            assert_eq!(a.commutes(&b), b.commutes(&a));
+            assert_eq!(a.anticommutes(&b), !a.commutes(&b));
+            a.commutes(&b)
+        };
+
+        assert!(!commutes("X0", "Z0"));
+        assert!(commutes("X0", "Z1"));
+        assert!(commutes("X0 Y1", "Y0 X1"));
+        assert!(commutes("X", "X"));
+        assert!(commutes("XYZ", "I"));
+        // Mixed formats and lengths
+        assert!(!commutes("X0 Y1", "ZI"));
+        assert!(commutes("X0 Y1", "ZIIZZ"));
+        assert!(!commutes("ZIII", "X3"));
+        assert!(commutes("I", "X5"));
+    }
+
+    #[test]
+    fn test_weight_and_support() {
+        let pauli: PauliString = "IXIZ".parse().unwrap();
+        assert_eq!(pauli.weight(), 2);
+        assert_eq!(pauli.support(), vec![0, 2]);
+
+        let pauli: PauliString = "Y3 X1".parse().unwrap();
+        assert_eq!(pauli.weight(), 2);
+        assert_eq!(pauli.support(), vec![1, 3]);
+
+        // Identity entries are not part of the support
+        let pauli = PauliString::Sparse(vec![
+            PauliTerm {
+                op: Pauli::I,
+                qubit: 0,
+            },
+            PauliTerm {
+                op: Pauli::Z,
+                qubit: 2,
+            },
+        ]);
+        assert_eq!(pauli.support(), vec![2]);
+        assert_eq!("III".parse::<PauliString>().unwrap().weight(), 0);
+        assert_eq!(PauliString::identity().support(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_to_dense_and_to_sparse() {
+        let pauli: PauliString = "Y3 X1".parse().unwrap();
+        let dense = pauli.to_dense(5).unwrap();
+        assert_eq!(dense.to_string(), "IYIXI");
+        let sparse = dense.to_sparse();
+        assert_eq!(sparse.to_string(), "X1 Y3");
+        assert_eq!(sparse.to_dense(5).unwrap(), dense);
+
+        for s in ["IXIZ", "X0 Z5 Y2", "ZZZ", "I", "III"] {
+            let pauli: PauliString = s.parse().unwrap();
+            let sparse = pauli.to_dense(6).unwrap().to_sparse();
+            assert_eq!(sparse, pauli.to_sparse());
+            assert_eq!(sparse.to_dense(6).unwrap().to_sparse(), sparse);
+            assert_eq!(sparse.to_sparse(), sparse);
+        }
+
+        assert!(matches!(
+            "X4".parse::<PauliString>().unwrap().to_dense(4),
+            Err(Error::QubitIndexOutOfBounds(4, 4))
+        ));
+    }
+
+    #[test]
+    fn test_multiply_matches_matrix_product() {
+        let matrix = |p: Pauli| -> [[Complex64; 2]; 2] {
+            let (o, z, i) = (
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, 0.0),
+                Complex64::new(0.0, 1.0),
+            );
+            match p {
+                Pauli::I => [[o, z], [z, o]],
+                Pauli::X => [[z, o], [o, z]],
+                Pauli::Y => [[z, -i], [i, z]],
+                Pauli::Z => [[o, z], [z, -o]],
+            }
+        };
+        // Dense matrix of a Pauli string, with qubit 0 as the least significant bit.
+        let to_matrix = |ops: &[Pauli]| -> Vec<Vec<Complex64>> {
+            let dim = 1 << ops.len();
+            (0..dim)
+                .map(|r| {
+                    (0..dim)
+                        .map(|c| {
+                            ops.iter()
+                                .enumerate()
+                                .map(|(q, &op)| matrix(op)[(r >> q) & 1][(c >> q) & 1])
+                                .product()
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+
+        let paulis = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z];
+        for a in 0..16 {
+            for b in 0..16 {
+                let lhs = vec![paulis[a % 4], paulis[a / 4]];
+                let rhs = vec![paulis[b % 4], paulis[b / 4]];
+                let (product, phase) = PauliString::Dense(lhs.clone())
+                    .multiply(&PauliString::Dense(rhs.clone()))
+                    .unwrap();
+                let PauliString::Dense(ops) = product else {
+                    panic!("expected a dense product");
+                };
+
+                let (ml, mr, mp) = (to_matrix(&lhs), to_matrix(&rhs), to_matrix(&ops));
+                for r in 0..4 {
+                    for c in 0..4 {
+                        let expected: Complex64 = (0..4).map(|k| ml[r][k] * mr[k][c]).sum();
+                        assert!((expected - phase * mp[r][c]).norm() < 1e-12);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_symplectic() {
+        let y = PauliString::from_symplectic(&[true], &[true]).unwrap();
+        assert_eq!(y.to_sparse(), "Y0".parse().unwrap());
+        assert_eq!(y.to_string(), "Y");
+
+        let pauli =
+            PauliString::from_symplectic(&[true, false, true, false], &[false, false, true, true])
+                .unwrap();
+        assert_eq!(pauli, "ZYIX".parse().unwrap());
+        assert_eq!(
+            pauli.to_symplectic(4).unwrap(),
+            (
+                vec![true, false, true, false],
+                vec![false, false, true, true]
+            )
+        );
+
+        let sparse: PauliString = "Z1 X3".parse().unwrap();
+        let (x, z) = sparse.to_symplectic(5).unwrap();
+        assert_eq!(
+            PauliString::from_symplectic(&x, &z).unwrap(),
+            sparse.to_dense(5).unwrap()
+        );
+        assert!(sparse.to_symplectic(3).is_err());
+
+        assert!(matches!(
+            PauliString::from_symplectic(&[true, false], &[true]),
+            Err(Error::QubitCountMismatch {
+                left: 2,
+                right: 1,
+                ..
+            })
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 
 /// Represents a single-qubit Pauli operator.
 pub enum Pauli {
@@ -7,3 +7,26 @@ pub enum Pauli {
     Y,
     Z,
 }
+
+impl Pauli {
+    /// Returns the Pauli operator `X^x Z^z` up to phase, i.e. `Y` for `x = z = true`.
+    pub(crate) fn from_symplectic(x: bool, z: bool) -> Self {
+        match (x, z) {
+            (false, false) => Pauli::I,
+            (true, false) => Pauli::X,
+            (true, true) => Pauli::Y,
+            (false, true) => Pauli::Z,
+        }
+    }
+
+    /// Returns the symplectic representation `(x, z)`, the inverse of
+    /// [`from_symplectic`](Self::from_symplectic).
+    pub(crate) fn to_symplectic(self) -> (bool, bool) {
+        match self {
+            Pauli::I => (false, false),
+            Pauli::X => (true, false),
+            Pauli::Y => (true, true),
+            Pauli::Z => (false, true),
+        }
+    }
+}
@@ -15,7 +15,7 @@ use crate::types::pauli::pauli_string::Pauli;
 ///    PauliTerm { op: Pauli::Y, qubit: 3 },
 /// ]));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PauliTerm {
     pub op: Pauli,
     pub qubit: usize,
@@ -59,3 +59,117 @@ fn test_gate_api_duplicate_qubit_indices() {
         Err(Error::DuplicateQubitIndices(index)) if index == 1
     ));
 }
+
+#[test]
+fn test_from_clifford_circuit_traced() {
+    use stabilizer_ch_form_rust::circuit::CliffordCircuit;
+
+    let circuit = CliffordCircuit::random_clifford(3, Some([7; 32]));
+    let mut traced_gates = Vec::new();
+    let mut snapshots = Vec::new();
+
+    let ch_form = StabilizerCHForm::from_clifford_circuit_traced(&circuit, &mut |gate, form| {
+        traced_gates.push(gate.clone());
+        snapshots.push(form.to_statevector().unwrap());
+    })
+    .unwrap();
+
+    // The callback is invoked exactly once per gate, in order.
+    assert_eq!(traced_gates, circuit.gates);
+
+    // Each snapshot matches the state after the corresponding prefix of the circuit.
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        let mut prefix = CliffordCircuit::new(circuit.num_qubits);
+        prefix.add_gates(circuit.gates[..=i].to_vec());
+        assert_eq_complex_array1(snapshot, &get_reference_statevector(&prefix));
+    }
+    assert_eq_complex_array1(
+        &ch_form.to_statevector().unwrap(),
+        &get_reference_statevector(&circuit),
+    );
+}
+
+#[test]
+fn test_stabilizers_as_pauli_strings() {
+    use stabilizer_ch_form_rust::circuit::CliffordCircuit;
+
+    // |+> is stabilized by +X, and |-> = Z|+> by -X.
+    let mut ch_form = StabilizerCHForm::new(1).unwrap();
+    ch_form.apply_h(0).unwrap();
+    let generators = ch_form.stabilizers_as_pauli_strings();
+    assert!(!generators[0].0);
+    assert_eq!(generators[0].1.to_string(), "X");
+
+    ch_form.apply_z(0).unwrap();
+    let generators = ch_form.stabilizers_as_pauli_strings();
+    assert!(generators[0].0);
+    assert_eq!(generators[0].1.to_string(), "X");
+
+    // Each generator of random Clifford states satisfies (-1)^negative · P |φ> = |φ>.
+    for seed in 0..10 {
+        let circuit = CliffordCircuit::random_clifford(4, Some([seed; 32]));
+        let ch_form = StabilizerCHForm::from_clifford_circuit(&circuit).unwrap();
+        let generators = ch_form.stabilizers_as_pauli_strings();
+        assert_eq!(generators.len(), 4);
+        for (negative, pauli) in &generators {
+            let sign = if *negative { -1.0 } else { 1.0 };
+            let mut evolved = ch_form.clone();
+            evolved.apply_pauli(pauli).unwrap();
+            let overlap = ch_form.inner_product(&evolved).unwrap();
+            assert!(
+                (overlap * sign - 1.0).norm() < 1e-8,
+                "generator {}{} does not stabilize the state (seed {})",
+                if *negative { "-" } else { "+" },
+                pauli,
+                seed
+            );
+        }
+    }
+}
+
+#[test]
+fn test_circuit_inverse() {
+    use ndarray::Array1;
+    use num_complex::Complex64;
+    use rand::prelude::*;
+    use stabilizer_ch_form_rust::circuit::{CliffordCircuit, CliffordGate};
+
+    let num_qubits = 4;
+    let mut rng = StdRng::seed_from_u64(17);
+    for seed in 0..10u8 {
+        // A random circuit over all gate types, followed by a random Clifford circuit
+        let mut circuit = CliffordCircuit::new(num_qubits);
+        for _ in 0..40 {
+            let q = rng.gen_range(0..num_qubits);
+            let r = (q + rng.gen_range(1..num_qubits)) % num_qubits;
+            circuit.add_gate(match rng.gen_range(0..11) {
+                0 => CliffordGate::H(q),
+                1 => CliffordGate::X(q),
+                2 => CliffordGate::Y(q),
+                3 => CliffordGate::Z(q),
+                4 => CliffordGate::S(q),
+                5 => CliffordGate::Sdg(q),
+                6 => CliffordGate::SqrtX(q),
+                7 => CliffordGate::SqrtXdg(q),
+                8 => CliffordGate::CX(q, r),
+                9 => CliffordGate::CZ(q, r),
+                _ => CliffordGate::Swap(q, r),
+            });
+        }
+        circuit.append(&CliffordCircuit::random_clifford(
+            num_qubits,
+            Some([seed; 32]),
+        ));
+
+        let inverse = circuit.inverse();
+        assert_eq!(inverse.num_qubits, num_qubits);
+        assert_eq!(inverse.gates.len(), circuit.gates.len());
+
+        let mut round_trip = circuit.clone();
+        round_trip.append(&inverse);
+        let ch_form = StabilizerCHForm::from_clifford_circuit(&round_trip).unwrap();
+        let mut expected = Array1::zeros(1 << num_qubits);
+        expected[0] = Complex64::new(1.0, 0.0);
+        assert_eq_complex_array1(&ch_form.to_statevector().unwrap(), &expected);
+    }
+}
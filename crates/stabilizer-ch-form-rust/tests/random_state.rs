@@ -89,3 +89,32 @@ fn test_random_circuit_vs_matrix_reference() {
     // Assert consistency between simulator and reference
     common::assert_eq_complex_array1(&sim_sv, &ref_sv);
 }
+
+#[test]
+fn test_support_matches_statevector() {
+    for i in 0..20u8 {
+        let num_qubits = 1 + i as usize % 7;
+        let circuit = CliffordCircuit::random_clifford(num_qubits, Some([i; 32]));
+        let state = StabilizerCHForm::from_clifford_circuit(&circuit).unwrap();
+        let statevector = state.to_statevector().unwrap();
+
+        let mut support: Vec<usize> = state
+            .support()
+            .iter()
+            .map(|bits| {
+                bits.iter()
+                    .enumerate()
+                    .fold(0, |acc, (q, &b)| acc | ((b as usize) << q))
+            })
+            .collect();
+        support.sort_unstable();
+        let expected: Vec<usize> = (0..statevector.len())
+            .filter(|&index| statevector[index].norm() > 1e-8)
+            .collect();
+        assert_eq!(support, expected);
+
+        for bits in state.support() {
+            assert!(state.amplitude(&bits).unwrap().norm() > 1e-8);
+        }
+    }
+}
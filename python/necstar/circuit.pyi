@@ -1,8 +1,9 @@
 from __future__ import annotations
 
-from typing import List, Optional
+from typing import Dict, List, Optional, Tuple
 
 from .gate import QuantumGate
+from .pauli_string import PauliString
 
 class QuantumCircuit:
     """
@@ -33,6 +34,60 @@ class QuantumCircuit:
         the circuit."""
         ...
 
+    @property
+    def measurements(self) -> List[Tuple[int, int]]:
+        """The ``(qubit, clbit)`` pairs of the measurements read from a QASM program, in
+        program order. They are not simulated."""
+        ...
+
+    @property
+    def depth(self) -> int:
+        """The depth of the circuit, i.e. the number of layers."""
+        ...
+
+    def draw(self) -> str:
+        """Renders the circuit as a text diagram with one wire per qubit.
+
+        Returns:
+            str: The diagram, with controls drawn as ``●`` and swapped qubits as ``×``.
+        """
+        ...
+
+    def layers(self) -> List[List[QuantumGate]]:
+        """Groups the gates into layers of gates acting on disjoint qubits.
+
+        Each gate is placed in the earliest layer after all preceding gates acting
+        on the same qubits.
+
+        Returns:
+            List[List[QuantumGate]]: The gates of each layer.
+        """
+        ...
+
+    def iter_with_layers(self) -> List[Tuple[int, QuantumGate]]:
+        """Returns the gates in circuit order, each tagged with its layer index.
+
+        Returns:
+            List[Tuple[int, QuantumGate]]: Pairs of (layer index, gate).
+        """
+        ...
+
+    def t_count(self) -> int:
+        """Returns the number of T-type gates in the circuit.
+
+        Composite gates are counted by their decomposition, e.g. `rz(q, 1)`
+        contributes one T-type gate and `cs` three.
+        """
+        ...
+
+    def count_ops(self) -> Dict[str, int]:
+        """Counts the gates in the circuit by their name (e.g., 'H', 'CX').
+
+        Returns:
+            Dict[str, int]: The number of gates for each gate name.
+        """
+        ...
+
     @staticmethod
     def from_qasm_file(path: str) -> QuantumCircuit:
         """Parses an OpenQASM 2.0 file into a :class:`~necstar.QuantumCircuit` object.
@@ -66,6 +121,27 @@ class QuantumCircuit:
         """
         ...
 
+    @staticmethod
+    def from_qasm3_str(qasm: str) -> QuantumCircuit:
+        """Parses an OpenQASM 3.0 string into a :class:`~necstar.QuantumCircuit` object.
+
+        Only the basic subset is supported: a single ``qubit[n]`` declaration and the
+        standard gates on indexed qubits. Classical bit declarations are skipped and
+        measurements are ignored.
+
+        Args:
+            qasm (str): A string containing the OpenQASM 3.0 circuit description.
+
+        Returns:
+            QuantumCircuit: A :class:`~necstar.QuantumCircuit` object representing the
+            parsed circuit.
+
+        Raises:
+            ValueError: If the QASM string is invalid or uses unsupported constructs such
+                as control flow or ``gate`` definitions.
+        """
+        ...
+
     def to_qasm_str(self, reg_name: str) -> str:
         """Generates the OpenQASM 2.0 string representation of the circuit.
 
@@ -86,6 +162,21 @@ class QuantumCircuit:
         """
         ...
 
+    @staticmethod
+    def graph_state(adjacency: List[Tuple[int, int]], n: int) -> QuantumCircuit:
+        """Generates a circuit preparing the graph state of the given graph, i.e. H on
+        every qubit followed by CZ on each edge.
+
+        Args:
+            adjacency (List[Tuple[int, int]]): The edges of the graph as pairs of qubit
+                indices.
+            n (int): The number of qubits (vertices).
+
+        Returns:
+            QuantumCircuit: A :class:`~necstar.QuantumCircuit` preparing the graph state.
+        """
+        ...
+
     @staticmethod
     def random_clifford(n: int, seed: Optional[int] = None) -> QuantumCircuit:
         """Generates a uniformly random `n`-qubit Clifford circuit.
@@ -111,6 +202,30 @@ class QuantumCircuit:
         """
         ...
 
+    @staticmethod
+    def random_clifford_t(
+        n_qubits: int, clifford_count: int, t_count: int, seed: Optional[int] = None
+    ) -> QuantumCircuit:
+        """Generates a random Clifford+T circuit.
+
+        The circuit consists of `clifford_count` Clifford gates drawn uniformly from
+        H, X, Y, Z, S, Sdg, SqrtX, SqrtXdg (and CX, CZ, Swap for two or more qubits)
+        and `t_count` gates drawn from T and Tdg, shuffled into a random order and
+        acting on random qubits.
+
+        Args:
+            n_qubits (int): The number of qubits. Must be greater than 0.
+            clifford_count (int): The number of Clifford gates.
+            t_count (int): The number of T-type gates.
+            seed (Optional[int]): An optional seed for the random number generator
+                for reproducibility. Defaults to None (uses system entropy).
+
+        Returns:
+            QuantumCircuit: A :class:`~necstar.QuantumCircuit` object representing the
+            random Clifford+T circuit.
+        """
+        ...
+
     def append(self, other: QuantumCircuit) -> None:
         """Appends the gates from another :class:`~necstar.QuantumCircuit` to this one.
 
@@ -120,6 +235,92 @@ class QuantumCircuit:
         """
         ...
 
+    def compose(self, other: QuantumCircuit, qubits: List[int]) -> None:
+        """Appends the gates from another :class:`~necstar.QuantumCircuit` to this one,
+        mapping qubit ``j`` of ``other`` to qubit ``qubits[j]`` of this circuit.
+
+        Args:
+            other (QuantumCircuit): The circuit whose gates will be appended.
+            qubits (List[int]): The qubits of this circuit that the qubits of
+                ``other`` are mapped to. Its length must equal ``other.num_qubits``.
+
+        Raises:
+            ValueError: If the mapping has the wrong length, an out-of-range index
+                or a repeated index.
+        """
+        ...
+
+    def power(self, n: int) -> QuantumCircuit:
+        """Creates the circuit that repeats the gates of this circuit ``n`` times.
+
+        Args:
+            n (int): The number of repetitions. For 0, the circuit has no gates.
+
+        Returns:
+            QuantumCircuit: The repeated circuit with the same number of qubits.
+        """
+        ...
+
+    def control(self, control: int) -> QuantumCircuit:
+        """Creates the circuit that applies this circuit controlled on the qubit
+        ``control``.
+
+        Supported gates are ``X``, ``Y``, ``Z``, ``H``, ``S``, ``Sdg``, ``CX``,
+        ``CZ`` and ``Swap``. If ``control`` is not a qubit of this circuit, the new
+        circuit is extended to ``control + 1`` qubits.
+
+        Args:
+            control (int): The control qubit index.
+
+        Returns:
+            QuantumCircuit: The controlled circuit.
+
+        Raises:
+            ValueError: If a gate acts on ``control`` or has no supported
+                controlled version.
+        """
+        ...
+
+    def inverse(self) -> QuantumCircuit:
+        """Creates the inverse of this circuit, which applies the inverses of the
+        gates in the reverse order.
+
+        Returns:
+            QuantumCircuit: The inverse circuit with the same number of qubits.
+        """
+        ...
+
+    def conjugate_pauli(self, pauli_op: PauliString) -> Tuple[PauliString, int]:
+        """Conjugates a Pauli operator by the circuit in the Heisenberg picture, i.e.
+        computes U^dagger P U for the (Clifford) unitary U of the circuit.
+
+        Args:
+            pauli_op (PauliString): The Pauli operator P.
+
+        Returns:
+            Tuple[PauliString, int]: The conjugated Pauli string on all qubits of the
+            circuit and its sign (1 or -1).
+
+        Raises:
+            ValueError: If the circuit contains a non-Clifford gate or the Pauli
+                string acts on qubits outside the circuit.
+        """
+        ...
+
+    def simplify(self) -> QuantumCircuit:
+        """Creates a simplified copy of the circuit by applying local rewrite rules
+        until no rule applies anymore.
+
+        A gate followed by its inverse is removed (e.g. X X, H H, CX CX, S Sdg),
+        S S and Sdg Sdg are merged into Z, T T into S and Tdg Tdg into Sdg, and
+        identity gates are dropped. The rules only apply to pairs of gates with no
+        gate in between acting on their qubits.
+
+        Returns:
+            QuantumCircuit: A circuit implementing the same unitary.
+        """
+        ...
+
     def tensor(self, other: QuantumCircuit) -> QuantumCircuit:
         """Creates a new circuit by taking the tensor product of this circuit and
         another.
@@ -144,8 +345,8 @@ class QuantumCircuit:
         Args:
             gate_name (str): The name of the gate (case-insensitive).
                 Supported names include: `'h'`, `'x'`, `'y'`, `'z'`, `'s'`, `'sdg'`,
-                `'sqrtx'`, `'sqrtxdg'`, `'t'`, `'tdg'`, `'cx'`, `'cz'`, `'swap'`,
-                `'ccx'`.
+                `'sqrtx'`, `'sqrtxdg'`, `'t'`, `'tdg'`, `'cx'`, `'cz'`, `'swap'`, `'iswap'`,
+                `'cs'`, `'ccx'`, `'ccz'`, `'cswap'`.
             qubits (List[int]): A list of qubit indices the gate acts upon.
                 The number of indices must match the gate's arity
                 (e.g., 1 for `'h'`, 2 for `'cx'`, 3 for `'ccx'`).
@@ -238,6 +439,85 @@ class QuantumCircuit:
         """
         ...
 
+    def apply_iswap(self, qarg1: int, qarg2: int) -> None:
+        """Applies an iSWAP gate, which swaps the qubits and multiplies |01> and |10>
+        by i.
+
+        Args:
+            qarg1 (int): The first qubit index.
+            qarg2 (int): The second qubit index.
+        """
+        ...
+
+    def apply_iswapdg(self, qarg1: int, qarg2: int) -> None:
+        """Applies the inverse of an iSWAP gate.
+
+        Args:
+            qarg1 (int): The first qubit index.
+            qarg2 (int): The second qubit index.
+        """
+        ...
+
+    def apply_clifford1(self, target: int, idx: int) -> None:
+        """Applies the single-qubit Clifford gate with the index `idx` in `range(24)`.
+
+        The index `idx = 4 * c + p` selects one of the representatives I, H, S, HS, SH
+        and HSH (`c`, in the order of application) of the Cliffords modulo Paulis,
+        followed by the Pauli I, X, Y or Z (`p`).
+
+        Args:
+            target (int): The target qubit index.
+            idx (int): The index of the single-qubit Clifford gate.
+
+        Raises:
+            ValueError: If `idx` is not less than 24.
+        """
+        ...
+
+    def apply_phase(self, target: int, k: int) -> None:
+        """Applies a phase gate diag(1, exp(i k pi/4)).
+
+        The gate is decomposed exactly into Z, S, Sdg, T and Tdg gates during
+        compilation, e.g. k=1 is T and k=4 is Z.
+
+        Args:
+            target (int): The target qubit index.
+            k (int): The phase angle in units of pi/4.
+        """
+        ...
+
+    def apply_rz(self, target: int, k: int) -> None:
+        """Applies a rotation around the Z axis by the angle k*pi/4.
+
+        The rotation equals exp(-i k pi/8 Z) up to a global phase and is decomposed
+        into Z, S, Sdg, T and Tdg gates during compilation.
+
+        Args:
+            target (int): The target qubit index.
+            k (int): The rotation angle in units of pi/4.
+        """
+        ...
+
+    def apply_rx(self, target: int, k: int) -> None:
+        """Applies a rotation around the X axis by the angle k*pi/4 (up to a global
+        phase).
+
+        Args:
+            target (int): The target qubit index.
+            k (int): The rotation angle in units of pi/4.
+        """
+        ...
+
+    def apply_ry(self, target: int, k: int) -> None:
+        """Applies a rotation around the Y axis by the angle k*pi/4 (up to a global
+        phase).
+
+        Args:
+            target (int): The target qubit index.
+            k (int): The rotation angle in units of pi/4.
+        """
+        ...
+
     def apply_cx(self, control: int, target: int) -> None:
         """Applies a CNOT (Controlled-X) gate.
 
@@ -265,6 +545,27 @@ class QuantumCircuit:
         """
         ...
 
+    def apply_cs(self, qarg1: int, qarg2: int) -> None:
+        """Applies a controlled-S (CS) gate diag(1, 1, 1, i).
+
+        CS is not a Clifford gate. It is decomposed into T, Tdg and CX gates during
+        compilation, i.e. it costs three T-type gates.
+
+        Args:
+            qarg1 (int): The first qubit index.
+            qarg2 (int): The second qubit index.
+        """
+        ...
+
+    def apply_csdg(self, qarg1: int, qarg2: int) -> None:
+        """Applies a controlled-S-dagger gate diag(1, 1, 1, -i).
+
+        Args:
+            qarg1 (int): The first qubit index.
+            qarg2 (int): The second qubit index.
+        """
+        ...
+
     def apply_ccx(self, control1: int, control2: int, target: int) -> None:
         """Applies a Toffoli (CCX) gate.
 
@@ -275,6 +576,32 @@ class QuantumCircuit:
         """
         ...
 
+    def apply_ccz(self, qarg1: int, qarg2: int, qarg3: int) -> None:
+        """Applies a controlled-controlled-Z (CCZ) gate.
+
+        The gate is symmetric in its three qubits and is simulated by injecting the
+        magic state CCZ|+++>.
+
+        Args:
+            qarg1 (int): The first qubit index.
+            qarg2 (int): The second qubit index.
+            qarg3 (int): The third qubit index.
+        """
+        ...
+
+    def apply_cswap(self, control: int, target1: int, target2: int) -> None:
+        """Applies a Fredkin (CSWAP) gate, which swaps the target qubits if the control
+        qubit is |1>.
+
+        The gate is decomposed into a Toffoli gate and two CX gates during compilation.
+
+        Args:
+            control (int): The index of the control qubit.
+            target1 (int): The index of the first target qubit.
+            target2 (int): The index of the second target qubit.
+        """
+        ...
+
     def __str__(self) -> str:
         """Returns a string representation of the circuit summary."""
         ...
@@ -76,12 +76,74 @@ class QuantumGate:
         """Creates a T-dagger gate acting on the specified qubit."""
         ...
 
+    @staticmethod
+    def iswap(qarg1: int, qarg2: int) -> QuantumGate:
+        """Creates an iSWAP gate acting on the specified qubits."""
+        ...
+
+    @staticmethod
+    def iswapdg(qarg1: int, qarg2: int) -> QuantumGate:
+        """Creates the inverse of an iSWAP gate acting on the specified qubits."""
+        ...
+
+    @staticmethod
+    def clifford1(qubit: int, idx: int) -> QuantumGate:
+        """Creates the single-qubit Clifford gate with the index `idx` in `range(24)`.
+
+        Raises:
+            ValueError: If `idx` is not less than 24.
+        """
+        ...
+
+    @staticmethod
+    def phase(qubit: int, k: int) -> QuantumGate:
+        """Creates a phase gate diag(1, exp(i k pi/4))."""
+        ...
+
+    @staticmethod
+    def rz(qubit: int, k: int) -> QuantumGate:
+        """Creates a rotation around the Z axis by the angle k*pi/4."""
+        ...
+
+    @staticmethod
+    def rx(qubit: int, k: int) -> QuantumGate:
+        """Creates a rotation around the X axis by the angle k*pi/4."""
+        ...
+
+    @staticmethod
+    def ry(qubit: int, k: int) -> QuantumGate:
+        """Creates a rotation around the Y axis by the angle k*pi/4."""
+        ...
+
+    @staticmethod
+    def cs(qarg1: int, qarg2: int) -> QuantumGate:
+        """Creates a controlled-S (CS) gate diag(1, 1, 1, i) acting on the specified
+        qubits."""
+        ...
+
+    @staticmethod
+    def csdg(qarg1: int, qarg2: int) -> QuantumGate:
+        """Creates a controlled-S-dagger gate diag(1, 1, 1, -i) acting on the specified
+        qubits."""
+        ...
+
     @staticmethod
     def ccx(control1: int, control2: int, target: int) -> QuantumGate:
         """Creates a Toffoli (CCX) gate acting on the specified control and target
         qubits."""
         ...
 
+    @staticmethod
+    def ccz(qarg1: int, qarg2: int, qarg3: int) -> QuantumGate:
+        """Creates a controlled-controlled-Z (CCZ) gate acting on the specified qubits."""
+        ...
+
+    @staticmethod
+    def cswap(control: int, target1: int, target2: int) -> QuantumGate:
+        """Creates a Fredkin (CSWAP) gate acting on the specified control and target
+        qubits."""
+        ...
+
     @property
     def name(self) -> str:
         """The name of the quantum gate (e.g., 'H', 'CX', 'T')."""
@@ -100,6 +162,10 @@ class QuantumGate:
         """Returns True if the gate is a T or Tdg gate, False otherwise."""
         ...
 
+    def inverse(self) -> QuantumGate:
+        """Returns the inverse of the gate (e.g., S for Sdg, RZ(q, -k) for RZ(q, k))."""
+        ...
+
     def __str__(self) -> str:
         """Returns a string representation of the gate (e.g., 'H(0)', 'CX(1, 2)')."""
         ...
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from typing import List, Tuple
+
 class PauliString:
     """
     Represents a multi-qubit Pauli operator.
@@ -13,6 +15,12 @@ class PauliString:
         """Checks if the Pauli string represents the identity operator."""
         ...
 
+    @property
+    def is_hermitian(self) -> bool:
+        """Checks if the Pauli string represents a Hermitian operator. Phase prefixes
+        such as "iXY" are rejected by :meth:`from_str`, so this is always ``True``."""
+        ...
+
     @staticmethod
     def from_str(s: str) -> PauliString:
         """Creates a :class:`~necstar.PauliString` object from a string representation.
@@ -30,7 +38,58 @@ class PauliString:
             PauliString: The corresponding PauliString object.
 
         Raises:
-            ValueError: If the string format is invalid.
+            ValueError: If the string format is invalid or has a phase prefix
+                (e.g., "iXY" or "-XY").
+        """
+        ...
+
+    @staticmethod
+    def from_symplectic(x: List[bool], z: List[bool]) -> PauliString:
+        """Creates a dense :class:`~necstar.PauliString` from its symplectic
+        representation, where qubit ``i`` carries ``X^x[i] Z^z[i]`` up to phase
+        (``Y`` if both are set).
+
+        Args:
+            x (List[bool]): The X part, where the ``i``-th element corresponds to qubit ``i``.
+            z (List[bool]): The Z part, of the same length as ``x``.
+
+        Returns:
+            PauliString: The dense Pauli string, e.g. "Y" for ``[True]`` and ``[True]``.
+
+        Raises:
+            ValueError: If ``x`` and ``z`` have different lengths.
+        """
+        ...
+
+    def to_symplectic(self, num_qubits: int) -> Tuple[List[bool], List[bool]]:
+        """Returns the symplectic representation ``(x, z)`` of the Pauli string on
+        `num_qubits` qubits, the inverse of :meth:`from_symplectic`.
+
+        Args:
+            num_qubits (int): The number of qubits of the representation.
+
+        Returns:
+            Tuple[List[bool], List[bool]]: The X and Z parts.
+
+        Raises:
+            ValueError: If a non-identity operator acts on a qubit index
+                out of range.
+        """
+        ...
+
+    def padded_to(self, num_qubits: int) -> PauliString:
+        """Converts the Pauli string to a dense representation of exactly
+        `num_qubits` qubits, padding the remaining qubits with identities.
+
+        Args:
+            num_qubits (int): The number of qubits of the resulting Pauli string.
+
+        Returns:
+            PauliString: The padded dense Pauli string, e.g. "IZII" for "Z2" and 4.
+
+        Raises:
+            ValueError: If a non-identity operator acts on a qubit index
+                out of range.
         """
         ...
 
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Dict, List, Optional
+from typing import Dict, List, Optional, Tuple
 
 from .circuit import QuantumCircuit
 from .gate import QuantumGate
@@ -21,6 +21,34 @@ class QuantumState:
         """The stabilizer rank χ (number of stabilizer states in the decomposition)."""
         ...
 
+    def coefficient_magnitudes(self) -> List[float]:
+        """Returns the magnitudes ``|c_i|`` of the coefficients of the decomposition
+        ``|psi> = sum_i c_i |phi_i>``.
+
+        Their sum bounds the norm of the state from above.
+
+        Returns:
+            List[float]: One magnitude per stabilizer component.
+        """
+        ...
+
+    def term_amplitudes(self, bitstring: List[bool]) -> List[complex]:
+        """Returns the contribution ``c_i <bitstring|phi_i>`` of each stabilizer component to
+        the amplitude ``<bitstring|psi>``.
+
+        Args:
+            bitstring (List[bool]): The basis state, where the ``i``-th element
+                corresponds to qubit ``i``.
+
+        Returns:
+            List[complex]: One contribution per stabilizer component, summing to
+            :meth:`amplitude`.
+
+        Raises:
+            ValueError: If the length of the bitstring differs from the number of qubits.
+        """
+        ...
+
     @staticmethod
     def from_circuit(circuit: QuantumCircuit) -> QuantumState:
         """Creates a new :class:`~necstar.QuantumState` by compiling a
@@ -38,6 +66,24 @@ class QuantumState:
         """
         ...
 
+    @staticmethod
+    def graph_state(adjacency: List[Tuple[int, int]], n: int) -> QuantumState:
+        """Creates the graph state of the given graph. Graph states are stabilizer
+        states, so the resulting stabilizer rank is 1.
+
+        Args:
+            adjacency (List[Tuple[int, int]]): The edges of the graph as pairs of qubit
+                indices.
+            n (int): The number of qubits (vertices).
+
+        Returns:
+            QuantumState: The graph state.
+
+        Raises:
+            ValueError: If an edge refers to an invalid qubit index.
+        """
+        ...
+
     def to_statevector(self) -> List[complex]:
         """Returns the statevector as a list of complex number tuples (real, imag).
 
@@ -56,6 +102,71 @@ class QuantumState:
         """
         ...
 
+    def to_sparse_statevector(self) -> List[Tuple[int, complex]]:
+        """Returns the nonzero amplitudes of the state as ``(index, amplitude)`` pairs.
+
+        Only the supports of the stabilizer components are enumerated, so states
+        with few nonzero amplitudes are handled efficiently. The pairs are sorted by
+        the little-endian index (like Qiskit).
+
+        Returns:
+            List[Tuple[int, complex]]: The nonzero amplitudes.
+
+        Raises:
+            ValueError: If the state has more than 128 qubits.
+        """
+        ...
+
+    def amplitude(self, bitstring: List[bool]) -> complex:
+        """Returns the amplitude <bitstring|psi> of a computational basis state, without
+        computing the full statevector.
+
+        Args:
+            bitstring (List[bool]): The basis state, where the ``i``-th element
+                corresponds to qubit ``i``.
+
+        Returns:
+            complex: The amplitude of the basis state.
+
+        Raises:
+            ValueError: If the length of the bitstring differs from the number of qubits.
+        """
+        ...
+
+    def amplitude_at(self, index: int) -> complex:
+        """Returns the amplitude of the computational basis state with the given integer
+        index, without computing the full statevector.
+
+        Args:
+            index (int): The basis state index in ``0..2**n`` in little-endian order,
+                i.e. bit ``i`` corresponds to qubit ``i``.
+
+        Returns:
+            complex: The amplitude, equal to ``to_statevector()[index]``.
+
+        Raises:
+            ValueError: If the index is out of range.
+        """
+        ...
+
+    def tensor(self, other: QuantumState) -> QuantumState:
+        """Returns the tensor product of this state and another.
+
+        The qubits of this state come first, followed by those of ``other``. The
+        stabilizer rank of the result is the product of the two ranks.
+
+        Args:
+            other (QuantumState): The state on the subsequent qubits.
+
+        Returns:
+            QuantumState: The combined state on
+            ``self.num_qubits + other.num_qubits`` qubits.
+
+        Raises:
+            ValueError: If the combination fails.
+        """
+        ...
+
     def inner_product(self, other: QuantumState) -> complex:
         """Computes the inner product <self|other> between this state and another.
 
@@ -72,6 +183,58 @@ class QuantumState:
         """
         ...
 
+    def fidelity(self, other: QuantumState) -> float:
+        """Computes the fidelity |<self|other>|^2 between this state and another.
+
+        Both states are normalized internally.
+
+        Args:
+            other (QuantumState): The other quantum state. Must have the same
+                number of qubits.
+
+        Returns:
+            float: The fidelity in [0, 1].
+
+        Raises:
+            ValueError: If the qubit counts differ or either state has zero norm.
+        """
+        ...
+
+    def approx_eq(self, other: QuantumState, tol: float = 1e-8) -> bool:
+        """Checks whether this state equals another state up to a global phase and
+        normalization.
+
+        Args:
+            other (QuantumState): The other quantum state. Must have the same
+                number of qubits.
+            tol (float): The numerical tolerance.
+
+        Returns:
+            bool: True if |<self|other>| / (||self|| ||other||) is 1 within `tol`.
+
+        Raises:
+            ValueError: If the comparison fails (e.g., qubit count mismatch).
+        """
+        ...
+
+    def approx_eq_with_phase(self, other: QuantumState, tol: float = 1e-8) -> bool:
+        """Checks whether this state equals another state as a vector up to
+        normalization, including the global phase. Unlike `approx_eq`, this
+        distinguishes |psi> from -|psi>.
+
+        Args:
+            other (QuantumState): The other quantum state. Must have the same
+                number of qubits.
+            tol (float): The numerical tolerance.
+
+        Returns:
+            bool: True if <self|other> / (||self|| ||other||) is 1 within `tol`.
+
+        Raises:
+            ValueError: If the comparison fails (e.g., qubit count mismatch).
+        """
+        ...
+
     def measure(self, qargs: List[int], seed: Optional[int] = None) -> List[bool]:
         """Measures the specified qubits in the computational basis. The state
         collapses according to the measurement results.
@@ -107,6 +270,94 @@ class QuantumState:
         """
         ...
 
+    def measure_all_int(self, seed: Optional[int] = None) -> int:
+        """Measures all qubits in the computational basis and returns the outcome as an
+        integer whose i-th bit is the result of qubit i.
+
+        The state collapses according to the measurement results.
+
+        Args:
+            seed (Optional[int]): An optional seed for the random number generator
+            to ensure reproducibility. Defaults to None.
+
+        Returns:
+            int: The packed measurement outcome.
+
+        Raises:
+            ValueError: If the state has more than 128 qubits or measurement fails.
+        """
+        ...
+
+    def measure_observable(self, pauli_op: PauliString, seed: Optional[int] = None) -> int:
+        """Measures a Pauli observable and collapses the state onto the corresponding
+        eigenspace.
+
+        Args:
+            pauli_op (PauliString): The Pauli observable to measure.
+            seed (Optional[int]): An optional seed for the random number generator
+            to ensure reproducibility. Defaults to None.
+
+        Returns:
+            int: The measured eigenvalue, either 1 or -1.
+
+        Raises:
+            ValueError: If the Pauli string does not match the number of qubits.
+        """
+        ...
+
+    def reset(self, qubit: int, seed: Optional[int] = None) -> None:
+        """Resets a qubit to |0> regardless of its current state.
+
+        The qubit is measured in the computational basis and flipped back if the
+        outcome was 1, so the rest of the state is conditioned on the outcome.
+
+        Args:
+            qubit (int): The index of the qubit to reset.
+            seed (Optional[int]): An optional seed for the random number generator
+            used by the measurement. Defaults to None.
+
+        Raises:
+            ValueError: If the qubit index is invalid.
+        """
+        ...
+
+    def measure_and_reset(self, qubit: int, seed: Optional[int] = None) -> bool:
+        """Measures a qubit in the computational basis, returns the outcome, and resets
+        the qubit to |0>.
+
+        Args:
+            qubit (int): The index of the qubit to measure and reset.
+            seed (Optional[int]): An optional seed for the random number generator
+            used by the measurement. Defaults to None.
+
+        Returns:
+            bool: The measurement outcome (False for 0, True for 1).
+
+        Raises:
+            ValueError: If the qubit index is invalid.
+        """
+        ...
+
+    def measured_branches(self, qubit: int) -> List[Tuple[bool, float, QuantumState]]:
+        """Enumerates the possible outcomes of measuring a qubit without collapsing the
+        state.
+
+        Outcomes with zero probability are omitted, so a deterministic qubit yields a
+        single branch.
+
+        Args:
+            qubit (int): The index of the qubit to measure.
+
+        Returns:
+            List[Tuple[bool, float, QuantumState]]: A list of
+            ``(outcome, probability, post_measurement_state)`` tuples, where each
+            post-measurement state is normalized.
+
+        Raises:
+            ValueError: If the qubit index is out of bounds.
+        """
+        ...
+
     def sample(
         self, qargs: List[int], shots: int, seed: Optional[int] = None
     ) -> Dict[str, int]:
@@ -132,6 +383,78 @@ class QuantumState:
         """
         ...
 
+    def sample_one(self, qargs: List[int], seed: Optional[int] = None) -> List[bool]:
+        """Draws a single measurement outcome of the specified qubits without
+        collapsing the state.
+
+        Args:
+            qargs (List[int]): A list of qubit indices to sample.
+            seed (Optional[int]): An optional seed for the random number generator
+                for reproducibility. Defaults to None.
+
+        Returns:
+            List[bool]: The outcome, where the ``i``-th element corresponds to the
+            qubit at ``qargs[i]``.
+
+        Raises:
+            ValueError: If sampling fails (e.g., invalid qubit index).
+        """
+        ...
+
+    def probability_vector(self, qargs: List[int]) -> List[float]:
+        """Computes the exact measurement probabilities of the specified qubits as a
+        dense vector.
+
+        Args:
+            qargs (List[int]): A list of qubit indices whose outcome probabilities are
+                computed. At most 24 qubits are supported.
+
+        Returns:
+            List[float]: A list of length ``2**len(qargs)`` indexed by the integer
+            outcome in little-endian order, i.e. the ``i``-th bit of the index
+            corresponds to ``qargs[i]``.
+
+        Raises:
+            ValueError: If the qubit indices are invalid or too many qubits are given.
+        """
+        ...
+
+    def probabilities(self, qargs: List[int]) -> List[Tuple[List[bool], float]]:
+        """Computes the exact distribution of the measurement outcomes of the
+        specified qubits, restricted to the outcomes with non-zero probability.
+
+        Args:
+            qargs (List[int]): A list of qubit indices whose outcome distribution is
+                computed. At most 128 qubits are supported.
+
+        Returns:
+            List[Tuple[List[bool], float]]: Pairs of outcomes and probabilities. The
+            ``i``-th element of each outcome corresponds to ``qargs[i]``, and the
+            outcomes are sorted by their integer value in little-endian order.
+
+        Raises:
+            ValueError: If the qubit indices are invalid or too many qubits are given.
+        """
+        ...
+
+    def most_probable(self, qargs: List[int], k: int) -> List[Tuple[List[bool], float]]:
+        """Returns the ``k`` most probable measurement outcomes of the specified qubits
+        with their exact probabilities, without enumerating the full distribution.
+
+        Args:
+            qargs (List[int]): A list of qubit indices to measure.
+            k (int): The number of outcomes to return.
+
+        Returns:
+            List[Tuple[List[bool], float]]: Up to ``k`` pairs of outcomes and
+            probabilities in descending order of probability. The ``i``-th element of
+            each outcome corresponds to ``qargs[i]``.
+
+        Raises:
+            ValueError: If the qubit indices are invalid.
+        """
+        ...
+
     def exp_value(self, pauli_string: PauliString) -> float:
         """Calculates the expectation value of a given Pauli observable.
 
@@ -147,6 +470,119 @@ class QuantumState:
         """
         ...
 
+    def exp_value_with_variance(self, pauli_op: PauliString) -> Tuple[float, float]:
+        """Calculates the expectation value of a Pauli observable together with its
+        variance. Since P^2 = I for a Pauli string P, the variance is 1 - <P>^2.
+
+        Args:
+            pauli_op (PauliString): The Pauli string representing the observable.
+
+        Returns:
+            Tuple[float, float]: The expectation value and the variance.
+
+        Raises:
+            ValueError: If the Pauli string is invalid or the calculation fails.
+        """
+        ...
+
+    def marginal_expectation(
+        self, qargs: List[int], observable: PauliString
+    ) -> Tuple[float, List[Tuple[List[bool], float]]]:
+        """Calculates the expectation value of a Pauli observable acting only on the
+        qubits ``qargs``, together with the distribution of their measurement outcomes.
+
+        For observables consisting of ``Z`` operators only, the expectation value is
+        read off the distribution.
+
+        Args:
+            qargs (List[int]): A list of qubit indices whose outcome distribution is
+                computed.
+            observable (PauliString): The observable, indexed by the qubits of the full
+                register. It must act as the identity outside ``qargs``.
+
+        Returns:
+            Tuple[float, List[Tuple[List[bool], float]]]: The expectation value and the
+            distribution as returned by :meth:`probabilities`.
+
+        Raises:
+            ValueError: If the observable acts outside ``qargs`` or the qubit indices
+                are invalid.
+        """
+        ...
+
+    def exp_value_sum(self, observable: List[Tuple[float, PauliString]]) -> float:
+        """Calculates the expectation value of a weighted sum of Pauli strings, e.g. a
+        Hamiltonian :math:`H = \\sum_k w_k P_k`.
+
+        Each stabilizer component is evolved by every Pauli string only once, which is
+        faster than summing :meth:`exp_value` over the terms.
+
+        Args:
+            observable (List[Tuple[float, PauliString]]): The pairs of weights and
+                Pauli strings.
+
+        Returns:
+            float: The expectation value :math:`\\sum_k w_k \\langle\\psi|P_k|\\psi\\rangle`.
+
+        Raises:
+            ValueError: If any Pauli string is invalid or the calculation fails.
+        """
+        ...
+
+    def exp_value_batch(self, paulis: List[PauliString]) -> List[float]:
+        """Calculates the expectation values of several observables.
+
+        This is equivalent to calling :meth:`exp_value` for each Pauli string, but the
+        pairs of stabilizer components are traversed only once.
+
+        Args:
+            paulis (List[PauliString]): The observables.
+
+        Returns:
+            List[float]: The expectation values, in the same order as ``paulis``.
+
+        Raises:
+            ValueError: If any Pauli string is invalid or the calculation fails.
+        """
+        ...
+
+    def reduced_density_matrix(self, qubits: List[int]) -> List[List[complex]]:
+        """Calculates the reduced density matrix over the given qubits by tracing out the
+        rest.
+
+        The matrix is built from Pauli expectation values, so at most 8 qubits are
+        supported. The ``i``-th bit (little-endian) of the row and column indices
+        corresponds to ``qubits[i]``.
+
+        Args:
+            qubits (List[int]): The qubit indices of the subsystem to keep.
+
+        Returns:
+            List[List[complex]]: The :math:`2^k \times 2^k` density matrix as a list of rows.
+
+        Raises:
+            ValueError: If the qubit indices are invalid or there are more than 8.
+        """
+        ...
+
+    def entanglement_entropy(self, qubits: List[int]) -> float:
+        """Calculates the von Neumann entropy of the reduced state on the given qubits.
+
+        The reduced density matrix of the smaller of the subsystem and its complement is
+        built from Pauli expectation values, so one of them must have at most 8 qubits.
+
+        Args:
+            qubits (List[int]): The qubit indices of the subsystem.
+
+        Returns:
+            float: The entanglement entropy in nats, e.g. ``ln 2`` for one qubit of a
+            Bell pair.
+
+        Raises:
+            ValueError: If the qubit indices are invalid or the subsystem is too large.
+        """
+        ...
+
     def project_normalized(self, qubit: int, outcome: bool) -> None:
         """Projects the state onto a computational basis state for a specific qubit and
         normalizes.
@@ -183,6 +619,42 @@ class QuantumState:
         """
         ...
 
+    def project_multi(
+        self, outcomes: List[Tuple[int, bool]], normalize: bool = True
+    ) -> None:
+        """Projects several qubits onto computational basis states at once.
+
+        The state is modified in place and, if ``normalize`` is True, normalized once at
+        the end instead of after every projection.
+
+        Args:
+            outcomes (List[Tuple[int, bool]]): Pairs of a distinct qubit index and the
+                desired basis state (False for :math:`|0\\rangle`, True for
+                :math:`|1\\rangle`).
+            normalize (bool): Whether to normalize the projected state. Defaults to True.
+
+        Raises:
+            ValueError: If an index is out of bounds or duplicated, or if ``normalize`` is
+                True and the projection is impossible.
+        """
+        ...
+
+    def postselection_probability(self, conditions: List[Tuple[int, bool]]) -> float:
+        """Computes the probability that post-selecting the given qubits onto the
+        given values succeeds. The state is not modified.
+
+        Args:
+            conditions (List[Tuple[int, bool]]): Pairs of (qubit, outcome) to
+                post-select on. True represents outcome 1.
+
+        Returns:
+            float: The success probability.
+
+        Raises:
+            ValueError: If the qubit indices are invalid or duplicated.
+        """
+        ...
+
     def discard(self, qubit: int) -> None:
         """Discards a qubit from the quantum state by tracing it out.
 
@@ -205,6 +677,18 @@ class QuantumState:
 
     # --- Gate Applications ---
 
+    def add_qubits(self, count: int) -> None:
+        """Appends fresh ancilla qubits in the ``|0>`` state. This is the counterpart of
+        :meth:`discard`; the new qubits get the indices ``num_qubits..num_qubits + count``.
+
+        Args:
+            count (int): The number of qubits to append.
+
+        Raises:
+            ValueError: If the operation fails.
+        """
+        ...
+
     def apply_gate(self, gate: QuantumGate) -> None:
         """Applies a :class:`~necstar.QuantumGate` directly to the quantum state.
 
@@ -220,6 +704,35 @@ class QuantumState:
         """
         ...
 
+    def apply_circuit(self, circuit: QuantumCircuit) -> None:
+        """Applies the gates of a Clifford :class:`~necstar.QuantumCircuit` to the
+        quantum state in place. All gates are checked before the state is modified.
+
+        Args:
+            circuit (QuantumCircuit): A Clifford circuit on the same number of qubits.
+
+        Raises:
+            ValueError: If the qubit counts differ or the circuit contains a
+                non-Clifford gate.
+        """
+        ...
+
+    def apply_gate_if(self, gate: QuantumGate, condition: bool) -> None:
+        """Applies a :class:`~necstar.QuantumGate` directly to the quantum state only if
+        `condition` is True, e.g. a correction conditioned on a measurement outcome.
+
+        Note:
+            Only Clifford gates are supported for direct application.
+
+        Args:
+            gate (QuantumGate): The quantum gate to apply.
+            condition (bool): Whether the gate is applied.
+
+        Raises:
+            ValueError: If the gate application fails (e.g., unsupported gate).
+        """
+        ...
+
     def apply_gates(self, gates: List[QuantumGate]) -> None:
         """Applies a list of :class:`~necstar.QuantumGate` s directly to the quantum
         state.
@@ -236,6 +749,28 @@ class QuantumState:
         """
         ...
 
+    def permute_qubits(self, perm: List[int]) -> None:
+        """Relabels the qubits so that the new qubit i is the old qubit `perm[i]`.
+
+        Args:
+            perm (List[int]): A permutation of `[0, 1, ..., num_qubits - 1]`.
+
+        Raises:
+            ValueError: If `perm` has the wrong length or is not a permutation.
+        """
+        ...
+
+    def apply_pauli_string(self, pauli_op: PauliString) -> None:
+        """Applies the Pauli operator represented by a Pauli string to the state.
+
+        Args:
+            pauli_op (PauliString): The Pauli operator to apply.
+
+        Raises:
+            ValueError: If the Pauli string does not match the number of qubits.
+        """
+        ...
+
     def apply_x(self, qubit: int) -> None:
         """Applies a Pauli-X gate directly to the state.
 
@@ -374,6 +909,129 @@ class QuantumState:
         """
         ...
 
+    def purity(self) -> float:
+        """Calculates the purity Tr(rho^2) of the normalized state as a sanity check.
+
+        The simulation is exact, so this should be close to 1.0.
+
+        Returns:
+            float: The purity of the state.
+
+        Raises:
+            ValueError: If the state has zero norm.
+        """
+        ...
+
+    def global_phase(self) -> complex:
+        """Returns the global phase of the state as a unit complex number.
+
+        The phase is that of the amplitude of a reference basis state, chosen by fixing
+        the qubits from the highest one to their more likely outcome (``0`` on ties), and
+        is consistent with :meth:`to_statevector`.
+
+        Returns:
+            complex: The global phase.
+
+        Raises:
+            ValueError: If the state has zero norm.
+        """
+        ...
+
+    def set_global_phase(self, phase: complex) -> None:
+        """Multiplies the state by a phase so that :meth:`global_phase` returns ``phase``.
+
+        Args:
+            phase (complex): A unit complex number representing the desired global phase.
+
+        Raises:
+            ValueError: If ``phase`` is not a unit complex number or the state has zero
+                norm.
+        """
+        ...
+
+    def normalize(self) -> None:
+        """Normalizes the state in place, e.g. after :meth:`project_unnormalized`.
+
+        Raises:
+            ValueError: If the state has zero norm.
+        """
+        ...
+
+    def is_stabilizer_state(self, tol: float = 1e-8) -> bool:
+        """Checks whether the state equals a single stabilizer state up to global phase,
+        even if its stabilizer rank is larger than 1.
+
+        The stabilizer rank is first reduced with the Gram matrix of the components.
+        The statevector is only computed if several components remain, which is
+        limited to 28 qubits.
+
+        Args:
+            tol (float): The numerical tolerance used when comparing amplitudes.
+
+        Returns:
+            bool: True if the state is a stabilizer state.
+
+        Raises:
+            ValueError: If the statevector required for the check is too large.
+        """
+        ...
+
+    def is_stabilizer(self) -> bool:
+        """Checks whether the stabilizer decomposition consists of a single stabilizer
+        state after merging the components equal up to global phase. Unlike
+        `is_stabilizer_state`, this does not compute the statevector.
+
+        Returns:
+            bool: True if the compressed decomposition has a single component.
+        """
+        ...
+
+    def stabilizer_generators(self) -> List[Tuple[bool, PauliString]]:
+        """Returns the n stabilizer generators of the state in a canonical form.
+
+        Returns:
+            List[Tuple[bool, PauliString]]: Pairs `(negative, pauli)`, each representing
+                the stabilizer `(-1)^negative * pauli`.
+
+        Raises:
+            ValueError: If the state is not a single stabilizer state after compression.
+        """
+        ...
+
+    def compress(self) -> None:
+        """Compresses the stabilizer decomposition in place by merging the stabilizer
+        components that are equal up to global phase and dropping the components with
+        zero coefficients. The represented state is unchanged.
+
+        Raises:
+            ValueError: If the compression fails.
+        """
+        ...
+
+    def recompress(self) -> None:
+        """Recompresses the stabilizer decomposition in place by re-expressing the state
+        exactly in a linearly independent subset of its stabilizer components.
+
+        Unlike :meth:`compress`, this also removes components that are linear
+        combinations of the others, at the cost of :math:`O(\\chi^2)` inner products.
+        The represented state is unchanged.
+
+        Raises:
+            ValueError: If the recompression fails.
+        """
+        ...
+
+    def summary(self) -> str:
+        """Returns a one-line summary of the state with the number of qubits, the
+        stabilizer rank, the norm and the magnitudes of the first few coefficients.
+        The statevector is not computed, but the norm takes O(χ²) inner products,
+        so `str(state)` only shows the number of qubits and the stabilizer rank.
+
+        Returns:
+            str: The summary.
+        """
+        ...
+
     def __str__(self) -> str:
-        """Returns a string representation of the quantum state summary."""
+        """Returns the number of qubits and the stabilizer rank of the state."""
         ...
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use pyo3::exceptions::{PyFileNotFoundError, PyValueError};
 use pyo3::prelude::*;
 
 use necstar_core::circuit::{QuantumCircuit as RustQuantumCircuit, QuantumGate as RustQuantumGate};
 
 use crate::gate::PyQuantumGate;
+use crate::pauli_string::PyPauliString;
 use crate::utils::parse_py_seed;
 
 #[pyclass(name = "QuantumCircuit")]
@@ -40,6 +43,56 @@ impl PyQuantumCircuit {
             .collect()
     }
 
+    #[getter]
+    fn measurements(&self) -> Vec<(usize, usize)> {
+        self.inner.measurements.clone()
+    }
+
+    #[getter]
+    fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    fn draw(&self) -> String {
+        self.inner.draw()
+    }
+
+    fn layers(&self) -> Vec<Vec<PyQuantumGate>> {
+        self.inner
+            .layers()
+            .into_iter()
+            .map(|layer| {
+                layer
+                    .into_iter()
+                    .cloned()
+                    .map(|g| PyQuantumGate { internal: g })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn iter_with_layers(&self) -> Vec<(usize, PyQuantumGate)> {
+        self.inner
+            .iter_with_layers()
+            .map(|(layer, g)| {
+                (
+                    layer,
+                    PyQuantumGate {
+                        internal: g.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn t_count(&self) -> usize {
+        self.inner.t_count()
+    }
+
+    fn count_ops(&self) -> HashMap<&'static str, usize> {
+        self.inner.count_ops()
+    }
+
     #[staticmethod]
     fn from_qasm_file(path: String) -> PyResult<Self> {
         let rust_circuit = RustQuantumCircuit::from_qasm_file(&path).map_err(|e| {
@@ -59,6 +112,15 @@ impl PyQuantumCircuit {
         })
     }
 
+    #[staticmethod]
+    fn from_qasm3_str(qasm: String) -> PyResult<Self> {
+        let rust_circuit = RustQuantumCircuit::from_qasm3_str(&qasm)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse QASM string: {}", e)))?;
+        Ok(PyQuantumCircuit {
+            inner: rust_circuit,
+        })
+    }
+
     fn to_qasm_str(&self, reg_name: String) -> String {
         self.inner.to_qasm_str(&reg_name)
     }
@@ -69,6 +131,13 @@ impl PyQuantumCircuit {
             .map_err(|e| PyValueError::new_err(format!("Failed to write QASM file: {}", e)))
     }
 
+    #[staticmethod]
+    fn graph_state(adjacency: Vec<(usize, usize)>, n: usize) -> Self {
+        PyQuantumCircuit {
+            inner: RustQuantumCircuit::graph_state(&adjacency, n),
+        }
+    }
+
     #[staticmethod]
     fn random_clifford(n: usize, seed: Option<Bound<'_, PyAny>>) -> PyResult<Self> {
         let rust_seed = parse_py_seed(seed)?;
@@ -78,10 +147,65 @@ impl PyQuantumCircuit {
         })
     }
 
+    #[staticmethod]
+    fn random_clifford_t(
+        n_qubits: usize,
+        clifford_count: usize,
+        t_count: usize,
+        seed: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        let rust_seed = parse_py_seed(seed)?;
+        let rust_circuit =
+            RustQuantumCircuit::random_clifford_t(n_qubits, clifford_count, t_count, rust_seed);
+        Ok(PyQuantumCircuit {
+            inner: rust_circuit,
+        })
+    }
+
     fn append(&mut self, other: &PyQuantumCircuit) {
         self.inner.append(&other.inner);
     }
 
+    fn compose(&mut self, other: &PyQuantumCircuit, qubits: Vec<usize>) -> PyResult<()> {
+        self.inner
+            .compose(&other.inner, &qubits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn power(&self, n: usize) -> PyQuantumCircuit {
+        PyQuantumCircuit {
+            inner: self.inner.power(n),
+        }
+    }
+
+    fn control(&self, control: usize) -> PyResult<PyQuantumCircuit> {
+        let inner = self
+            .inner
+            .control(control)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyQuantumCircuit { inner })
+    }
+
+    fn inverse(&self) -> PyQuantumCircuit {
+        PyQuantumCircuit {
+            inner: self.inner.inverse(),
+        }
+    }
+
+    fn conjugate_pauli(&self, pauli_op: &PyPauliString) -> PyResult<(PyPauliString, i8)> {
+        let (pauli, sign) = self
+            .inner
+            .conjugate_pauli(&pauli_op.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok((PyPauliString { inner: pauli }, sign))
+    }
+
+    fn simplify(&self) -> PyQuantumCircuit {
+        PyQuantumCircuit {
+            inner: self.inner.simplify(),
+        }
+    }
+
     fn tensor(&self, other: &PyQuantumCircuit) -> PyQuantumCircuit {
         let new_circuit = self.inner.tensor(&other.inner);
         PyQuantumCircuit { inner: new_circuit }
@@ -174,6 +298,44 @@ impl PyQuantumCircuit {
                 }
                 Ok(RustQuantumGate::Swap(qubits[0], qubits[1]))
             }
+            "iswap" => {
+                if qubits.len() != 2 {
+                    return Err(PyValueError::new_err(
+                        "iSWAP gate requires 2 qubit indices.",
+                    ));
+                }
+                Ok(RustQuantumGate::ISwap(qubits[0], qubits[1]))
+            }
+            "cs" => {
+                if qubits.len() != 2 {
+                    return Err(PyValueError::new_err("CS gate requires 2 qubit indices."));
+                }
+                Ok(RustQuantumGate::CS(qubits[0], qubits[1]))
+            }
+
+            // --- Three-qubit gates ---
+            "ccx" | "toffoli" => {
+                if qubits.len() != 3 {
+                    return Err(PyValueError::new_err(
+                        "CCX gate requires 3 qubit indices (control1, control2, target).",
+                    ));
+                }
+                Ok(RustQuantumGate::CCX(qubits[0], qubits[1], qubits[2]))
+            }
+            "ccz" => {
+                if qubits.len() != 3 {
+                    return Err(PyValueError::new_err("CCZ gate requires 3 qubit indices."));
+                }
+                Ok(RustQuantumGate::CCZ(qubits[0], qubits[1], qubits[2]))
+            }
+            "cswap" | "fredkin" => {
+                if qubits.len() != 3 {
+                    return Err(PyValueError::new_err(
+                        "CSWAP gate requires 3 qubit indices (control, target1, target2).",
+                    ));
+                }
+                Ok(RustQuantumGate::CSwap(qubits[0], qubits[1], qubits[2]))
+            }
 
             // --- Unrecognized gate ---
             _ => Err(PyValueError::new_err(format!(
@@ -216,6 +378,29 @@ impl PyQuantumCircuit {
     fn apply_tdg(&mut self, target: usize) {
         self.inner.apply_tdg(target);
     }
+    fn apply_iswap(&mut self, qarg1: usize, qarg2: usize) {
+        self.inner.apply_iswap(qarg1, qarg2);
+    }
+    fn apply_iswapdg(&mut self, qarg1: usize, qarg2: usize) {
+        self.inner.apply_iswapdg(qarg1, qarg2);
+    }
+    fn apply_clifford1(&mut self, target: usize, idx: u8) -> PyResult<()> {
+        self.inner
+            .apply_clifford1(target, idx)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+    fn apply_phase(&mut self, target: usize, k: i64) {
+        self.inner.apply_phase(target, k);
+    }
+    fn apply_rz(&mut self, target: usize, k: i64) {
+        self.inner.apply_rz(target, k);
+    }
+    fn apply_rx(&mut self, target: usize, k: i64) {
+        self.inner.apply_rx(target, k);
+    }
+    fn apply_ry(&mut self, target: usize, k: i64) {
+        self.inner.apply_ry(target, k);
+    }
     fn apply_cx(&mut self, control: usize, target: usize) {
         self.inner.apply_cx(control, target);
     }
@@ -225,9 +410,21 @@ impl PyQuantumCircuit {
     fn apply_swap(&mut self, qarg1: usize, qarg2: usize) {
         self.inner.apply_swap(qarg1, qarg2);
     }
+    fn apply_cs(&mut self, qarg1: usize, qarg2: usize) {
+        self.inner.apply_cs(qarg1, qarg2);
+    }
+    fn apply_csdg(&mut self, qarg1: usize, qarg2: usize) {
+        self.inner.apply_csdg(qarg1, qarg2);
+    }
     fn apply_ccx(&mut self, control1: usize, control2: usize, target: usize) {
         self.inner.apply_ccx(control1, control2, target);
     }
+    fn apply_ccz(&mut self, qarg1: usize, qarg2: usize, qarg3: usize) {
+        self.inner.apply_ccz(qarg1, qarg2, qarg3);
+    }
+    fn apply_cswap(&mut self, control: usize, target1: usize, target2: usize) {
+        self.inner.apply_cswap(control, target1, target2);
+    }
 
     fn __str__(&self) -> String {
         format!("{}", self.inner)
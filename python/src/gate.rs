@@ -1,4 +1,6 @@
+use necstar_core::circuit::Clifford1Index;
 use necstar_core::circuit::QuantumGate as RustQuantumGate;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 #[pyclass(name = "QuantumGate")]
@@ -76,6 +78,25 @@ impl PyQuantumGate {
         }
     }
     #[staticmethod]
+    fn iswap(qarg1: usize, qarg2: usize) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::ISwap(qarg1, qarg2),
+        }
+    }
+    #[staticmethod]
+    fn iswapdg(qarg1: usize, qarg2: usize) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::ISwapdg(qarg1, qarg2),
+        }
+    }
+    #[staticmethod]
+    fn clifford1(target: usize, idx: u8) -> PyResult<Self> {
+        let idx = Clifford1Index::new(idx).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyQuantumGate {
+            internal: RustQuantumGate::Clifford1(target, idx),
+        })
+    }
+    #[staticmethod]
     fn t(target: usize) -> Self {
         PyQuantumGate {
             internal: RustQuantumGate::T(target),
@@ -88,11 +109,59 @@ impl PyQuantumGate {
         }
     }
     #[staticmethod]
+    fn phase(target: usize, k: i64) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::Phase(target, k),
+        }
+    }
+    #[staticmethod]
+    fn rz(target: usize, k: i64) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::RZ(target, k),
+        }
+    }
+    #[staticmethod]
+    fn rx(target: usize, k: i64) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::RX(target, k),
+        }
+    }
+    #[staticmethod]
+    fn ry(target: usize, k: i64) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::RY(target, k),
+        }
+    }
+    #[staticmethod]
+    fn cs(qarg1: usize, qarg2: usize) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::CS(qarg1, qarg2),
+        }
+    }
+    #[staticmethod]
+    fn csdg(qarg1: usize, qarg2: usize) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::CSdg(qarg1, qarg2),
+        }
+    }
+    #[staticmethod]
     fn ccx(control1: usize, control2: usize, target: usize) -> Self {
         PyQuantumGate {
             internal: RustQuantumGate::CCX(control1, control2, target),
         }
     }
+    #[staticmethod]
+    fn ccz(qarg1: usize, qarg2: usize, qarg3: usize) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::CCZ(qarg1, qarg2, qarg3),
+        }
+    }
+    #[staticmethod]
+    fn cswap(control: usize, target1: usize, target2: usize) -> Self {
+        PyQuantumGate {
+            internal: RustQuantumGate::CSwap(control, target1, target2),
+        }
+    }
 
     #[getter]
     fn name(&self) -> &'static str {
@@ -119,4 +188,10 @@ impl PyQuantumGate {
     pub fn is_t_type(&self) -> bool {
         self.internal.is_t_type_gate()
     }
+
+    pub fn inverse(&self) -> PyQuantumGate {
+        PyQuantumGate {
+            internal: self.internal.inverse(),
+        }
+    }
 }
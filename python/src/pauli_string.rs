@@ -20,6 +20,19 @@ impl PyPauliString {
         Ok(PyPauliString { inner: rust_pauli })
     }
 
+    #[staticmethod]
+    fn from_symplectic(x: Vec<bool>, z: Vec<bool>) -> PyResult<Self> {
+        let rust_pauli = RustPauliString::from_symplectic(&x, &z)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyPauliString { inner: rust_pauli })
+    }
+
+    fn to_symplectic(&self, num_qubits: usize) -> PyResult<(Vec<bool>, Vec<bool>)> {
+        self.inner
+            .to_symplectic(num_qubits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -28,8 +41,21 @@ impl PyPauliString {
         format!("PauliString('{}')", self.inner)
     }
 
+    fn padded_to(&self, num_qubits: usize) -> PyResult<Self> {
+        let padded = self
+            .inner
+            .padded_to(num_qubits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyPauliString { inner: padded })
+    }
+
     #[getter]
     fn is_identity(&self) -> bool {
         self.inner.is_identity()
     }
+
+    #[getter]
+    fn is_hermitian(&self) -> bool {
+        self.inner.is_hermitian()
+    }
 }
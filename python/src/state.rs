@@ -4,6 +4,7 @@ use pyo3::prelude::*;
 use std::collections::HashMap;
 
 use necstar_core::prelude::{QuantumGate, QuantumState as RustQuantumState};
+use necstar_core::types::shot_count::Distribution;
 
 use crate::gate::PyQuantumGate;
 use crate::pauli_string::PyPauliString;
@@ -23,6 +24,21 @@ impl PyQuantumState {
         Ok(PyQuantumState { inner: state })
     }
 
+    #[staticmethod]
+    fn graph_state(adjacency: Vec<(usize, usize)>, n: usize) -> PyResult<Self> {
+        let state = RustQuantumState::graph_state(&adjacency, n)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyQuantumState { inner: state })
+    }
+
+    fn tensor(&self, other: &PyQuantumState) -> PyResult<Self> {
+        let state = self
+            .inner
+            .tensor(&other.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyQuantumState { inner: state })
+    }
+
     fn to_statevector(&self) -> PyResult<Vec<Complex64>> {
         let sv = self
             .inner
@@ -31,6 +47,24 @@ impl PyQuantumState {
         Ok(sv.into_iter().map(|c| Complex64::new(c.re, c.im)).collect())
     }
 
+    fn to_sparse_statevector(&self) -> PyResult<Vec<(u128, Complex64)>> {
+        self.inner
+            .to_sparse_statevector()
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn amplitude(&self, bitstring: Vec<bool>) -> PyResult<Complex64> {
+        self.inner
+            .amplitude(&bitstring)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn amplitude_at(&self, index: usize) -> PyResult<Complex64> {
+        self.inner
+            .amplitude_at(index)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn inner_product(&self, other: &PyQuantumState) -> PyResult<Complex64> {
         let ip = self
             .inner
@@ -39,6 +73,26 @@ impl PyQuantumState {
         Ok(ip)
     }
 
+    fn fidelity(&self, other: &PyQuantumState) -> PyResult<f64> {
+        self.inner
+            .fidelity(&other.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(signature = (other, tol=1e-8))]
+    fn approx_eq(&self, other: &PyQuantumState, tol: f64) -> PyResult<bool> {
+        self.inner
+            .approx_eq(&other.inner, tol)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(signature = (other, tol=1e-8))]
+    fn approx_eq_with_phase(&self, other: &PyQuantumState, tol: f64) -> PyResult<bool> {
+        self.inner
+            .approx_eq_with_phase(&other.inner, tol)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn measure(
         &mut self,
         qargs: Vec<usize>,
@@ -61,6 +115,55 @@ impl PyQuantumState {
         Ok(results)
     }
 
+    fn measure_all_int(&mut self, seed: Option<Bound<'_, PyAny>>) -> PyResult<u128> {
+        let rust_seed = parse_py_seed(seed)?;
+        self.inner
+            .measure_all_int(rust_seed)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn measure_observable(
+        &mut self,
+        pauli_op: &PyPauliString,
+        seed: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<i8> {
+        let rust_seed = parse_py_seed(seed)?;
+        self.inner
+            .measure_observable(&pauli_op.inner, rust_seed)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn reset(&mut self, qubit: usize, seed: Option<Bound<'_, PyAny>>) -> PyResult<()> {
+        let rust_seed = parse_py_seed(seed)?;
+        self.inner
+            .reset(qubit, rust_seed)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn measure_and_reset(
+        &mut self,
+        qubit: usize,
+        seed: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        let rust_seed = parse_py_seed(seed)?;
+        self.inner
+            .measure_and_reset(qubit, rust_seed)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn measured_branches(&self, qubit: usize) -> PyResult<Vec<(bool, f64, PyQuantumState)>> {
+        let branches = self
+            .inner
+            .measured_branches(qubit)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(branches
+            .into_iter()
+            .map(|(outcome, probability, state)| {
+                (outcome, probability, PyQuantumState { inner: state })
+            })
+            .collect())
+    }
+
     fn sample(
         &self,
         qargs: Vec<usize>,
@@ -89,6 +192,31 @@ impl PyQuantumState {
         Ok(py_shot_count)
     }
 
+    fn sample_one(&self, qargs: Vec<usize>, seed: Option<Bound<'_, PyAny>>) -> PyResult<Vec<bool>> {
+        let rust_seed = parse_py_seed(seed)?;
+        self.inner
+            .sample_one(&qargs, rust_seed)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn probability_vector(&self, qargs: Vec<usize>) -> PyResult<Vec<f64>> {
+        self.inner
+            .probability_vector(&qargs)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn probabilities(&self, qargs: Vec<usize>) -> PyResult<Vec<(Vec<bool>, f64)>> {
+        self.inner
+            .probabilities(&qargs)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn most_probable(&self, qargs: Vec<usize>, k: usize) -> PyResult<Vec<(Vec<bool>, f64)>> {
+        self.inner
+            .most_probable(&qargs, k)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn exp_value(&self, pauli_op: &PyPauliString) -> PyResult<f64> {
         let exp_val = self
             .inner
@@ -97,6 +225,57 @@ impl PyQuantumState {
         Ok(exp_val)
     }
 
+    fn exp_value_with_variance(&self, pauli_op: &PyPauliString) -> PyResult<(f64, f64)> {
+        self.inner
+            .exp_value_with_variance(&pauli_op.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn marginal_expectation(
+        &self,
+        qargs: Vec<usize>,
+        observable: &PyPauliString,
+    ) -> PyResult<(f64, Distribution)> {
+        self.inner
+            .marginal_expectation(&qargs, &observable.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn exp_value_sum(&self, observable: Vec<(f64, PyRef<PyPauliString>)>) -> PyResult<f64> {
+        let observable: Vec<_> = observable
+            .iter()
+            .map(|(weight, pauli_op)| (*weight, pauli_op.inner.clone()))
+            .collect();
+        self.inner
+            .exp_value_sum(&observable)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn exp_value_batch(&self, paulis: Vec<PyRef<PyPauliString>>) -> PyResult<Vec<f64>> {
+        let paulis: Vec<_> = paulis.iter().map(|p| p.inner.clone()).collect();
+        self.inner
+            .exp_value_batch(&paulis)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn reduced_density_matrix(&self, qubits: Vec<usize>) -> PyResult<Vec<Vec<Complex64>>> {
+        let rho = self
+            .inner
+            .reduced_density_matrix(&qubits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(rho
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(|c| Complex64::new(c.re, c.im)).collect())
+            .collect())
+    }
+
+    fn entanglement_entropy(&self, qubits: Vec<usize>) -> PyResult<f64> {
+        self.inner
+            .entanglement_entropy(&qubits)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn project_normalized(&mut self, qubit: usize, outcome: bool) -> PyResult<()> {
         self.inner
             .project_normalized(qubit, outcome)
@@ -109,18 +288,49 @@ impl PyQuantumState {
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    #[pyo3(signature = (outcomes, normalize=true))]
+    fn project_multi(&mut self, outcomes: Vec<(usize, bool)>, normalize: bool) -> PyResult<()> {
+        self.inner
+            .project_multi(&outcomes, normalize)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn postselection_probability(&self, conditions: Vec<(usize, bool)>) -> PyResult<f64> {
+        self.inner
+            .postselection_probability(&conditions)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn discard(&mut self, qubit: usize) -> PyResult<()> {
         self.inner
             .discard(qubit)
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    fn add_qubits(&mut self, count: usize) -> PyResult<()> {
+        self.inner
+            .add_qubits(count)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn apply_gate(&mut self, gate: &PyQuantumGate) -> PyResult<()> {
         self.inner
             .apply_gate(&gate.internal)
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    fn apply_circuit(&mut self, circuit: &crate::circuit::PyQuantumCircuit) -> PyResult<()> {
+        self.inner
+            .apply_circuit(&circuit.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn apply_gate_if(&mut self, gate: &PyQuantumGate, condition: bool) -> PyResult<()> {
+        self.inner
+            .apply_gate_if(&gate.internal, condition)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn apply_gates(&mut self, gates: Vec<PyQuantumGate>) -> PyResult<()> {
         let rust_gates: Vec<QuantumGate> = gates.into_iter().map(|g| g.internal).collect();
         self.inner
@@ -128,6 +338,18 @@ impl PyQuantumState {
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    fn permute_qubits(&mut self, perm: Vec<usize>) -> PyResult<()> {
+        self.inner
+            .permute_qubits(&perm)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn apply_pauli_string(&mut self, pauli_op: &PyPauliString) -> PyResult<()> {
+        self.inner
+            .apply_pauli_string(&pauli_op.inner)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     fn apply_x(&mut self, qubit: usize) -> PyResult<()> {
         self.inner
             .apply_x(qubit)
@@ -202,21 +424,89 @@ impl PyQuantumState {
         Ok(norm)
     }
 
+    fn purity(&self) -> PyResult<f64> {
+        self.inner
+            .purity()
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn global_phase(&self) -> PyResult<Complex64> {
+        self.inner
+            .global_phase()
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn set_global_phase(&mut self, phase: Complex64) -> PyResult<()> {
+        self.inner
+            .set_global_phase(phase)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn normalize(&mut self) -> PyResult<()> {
+        self.inner
+            .normalize()
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[pyo3(signature = (tol=1e-8))]
+    fn is_stabilizer_state(&self, tol: f64) -> PyResult<bool> {
+        self.inner
+            .is_stabilizer_state(tol)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn is_stabilizer(&self) -> bool {
+        self.inner.is_stabilizer()
+    }
+
+    fn stabilizer_generators(&self) -> PyResult<Vec<(bool, PyPauliString)>> {
+        let generators = self
+            .inner
+            .stabilizer_generators()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(generators
+            .into_iter()
+            .map(|(negative, pauli)| (negative, PyPauliString { inner: pauli }))
+            .collect())
+    }
+
+    fn compress(&mut self) -> PyResult<()> {
+        self.inner
+            .compress()
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn recompress(&mut self) -> PyResult<()> {
+        self.inner
+            .recompress()
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     #[getter]
     fn stabilizer_rank(&self) -> usize {
         self.inner.stabilizer_rank()
     }
 
+    fn coefficient_magnitudes(&self) -> Vec<f64> {
+        self.inner.coefficient_magnitudes()
+    }
+
+    fn term_amplitudes(&self, bitstring: Vec<bool>) -> PyResult<Vec<Complex64>> {
+        self.inner
+            .term_amplitudes(&bitstring)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
     #[getter]
     fn num_qubits(&self) -> usize {
         self.inner.num_qubits()
     }
 
+    fn summary(&self) -> String {
+        self.inner.summary()
+    }
+
     fn __str__(&self) -> String {
-        format!(
-            "QuantumState(num_qubits={}, stabilizer_rank={})",
-            self.num_qubits(),
-            self.stabilizer_rank()
-        )
+        self.inner.to_string()
     }
 }
@@ -1,3 +1,4 @@
+use necstar_core::types::seed_from_u64;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 
@@ -7,9 +8,7 @@ pub fn parse_py_seed(seed: Option<Bound<'_, PyAny>>) -> PyResult<Option<[u8; 32]
         Some(obj) => {
             if let Ok(val) = obj.extract::<u64>() {
                 // Handle small integers efficiently
-                let mut s = [0u8; 32];
-                s[0..8].copy_from_slice(&val.to_le_bytes());
-                Ok(Some(s))
+                Ok(Some(seed_from_u64(val)))
             } else if obj.get_type().name()? == "int" {
                 // Handle large Python integers using to_bytes method
                 let bytes_obj = obj.call_method1("to_bytes", (32, "little"))?;